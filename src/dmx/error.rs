@@ -6,6 +6,12 @@ pub enum DmxError {
     InvalidStartCode(u8),
     InvalidChannelCount(u16),
     ChannelOutOfBounds,
+    InvalidAddress(u16),
+    ChannelValueMismatch {
+        channel: u16,
+        expected: u8,
+        actual: u8,
+    },
     FailedToAllocate,
 }
 
@@ -18,6 +24,18 @@ impl fmt::Display for DmxError {
                 write!(f, "Invalid channel count: {}", channel_count)
             }
             Self::ChannelOutOfBounds => write!(f, "Channel out of bounds"),
+            Self::InvalidAddress(address) => {
+                write!(f, "Invalid address: {}, must be >= 1 and <= 512", address)
+            }
+            Self::ChannelValueMismatch {
+                channel,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Channel {} value mismatch, expected: {}, actual: {}",
+                channel, expected, actual
+            ),
             Self::FailedToAllocate => write!(f, "Failed to allocate memory"),
         }
     }
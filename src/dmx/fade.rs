@@ -0,0 +1,111 @@
+//! Stepped fades between two DMX universes
+//!
+//! ```rust
+//! use dmx512_rdm_protocol::dmx::{fade::Fade, DmxUniverse};
+//!
+//! let from = DmxUniverse::default();
+//! let mut to = DmxUniverse::default();
+//! to.set_channel_value(0, 255).unwrap();
+//!
+//! let mut fade = Fade::new(from, to.clone(), 4).unwrap();
+//!
+//! assert_eq!(fade.step().unwrap().get_channel_value(0).unwrap(), 64);
+//! assert_eq!(fade.step().unwrap().get_channel_value(0).unwrap(), 128);
+//! assert_eq!(fade.step().unwrap().get_channel_value(0).unwrap(), 191);
+//! assert_eq!(fade.step(), Some(to));
+//! assert_eq!(fade.step(), None);
+//! ```
+
+use super::{error::DmxError, DmxUniverse};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fade {
+    from: DmxUniverse,
+    to: DmxUniverse,
+    steps: u16,
+    step: u16,
+}
+
+impl Fade {
+    /// Creates a fade yielding `steps` frames, the last of which is `to`. A fade of 0 steps
+    /// snaps straight to `to`.
+    pub fn new(from: DmxUniverse, to: DmxUniverse, steps: u16) -> Result<Self, DmxError> {
+        if to.as_slice().len() != from.as_slice().len() {
+            return Err(DmxError::InvalidChannelCount(to.as_slice().len() as u16));
+        }
+
+        Ok(Self {
+            from,
+            to,
+            steps: steps.max(1),
+            step: 0,
+        })
+    }
+
+    pub fn steps(&self) -> u16 {
+        self.steps
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.step >= self.steps
+    }
+
+    /// Returns the next interpolated frame, or `None` once the fade is complete
+    pub fn step(&mut self) -> Option<DmxUniverse> {
+        if self.is_complete() {
+            return None;
+        }
+
+        self.step += 1;
+
+        // Both universes were checked to have the same channel count on creation
+        self.from.lerp(&self.to, self.step, self.steps).ok()
+    }
+}
+
+impl Iterator for Fade {
+    type Item = DmxUniverse;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.step()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_yield_each_step_ending_on_target() {
+        let from = DmxUniverse::default();
+        let mut to = DmxUniverse::default();
+
+        to.set_channel_value(0, 0xff).unwrap();
+
+        let fade = Fade::new(from, to.clone(), 3).unwrap();
+
+        let mut count = 0;
+        let mut last = None;
+
+        for frame in fade {
+            count += 1;
+            last = Some(frame);
+        }
+
+        assert_eq!(count, 3);
+        assert_eq!(last, Some(to));
+    }
+
+    #[test]
+    fn should_snap_to_target_with_zero_steps() {
+        let mut to = DmxUniverse::default();
+
+        to.set_channel_value(511, 0x80).unwrap();
+
+        let mut fade = Fade::new(DmxUniverse::default(), to.clone(), 0).unwrap();
+
+        assert_eq!(fade.step(), Some(to));
+        assert!(fade.is_complete());
+        assert_eq!(fade.step(), None);
+    }
+}
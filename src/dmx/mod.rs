@@ -27,28 +27,84 @@
 //! ```
 
 pub mod error;
+pub mod fade;
 pub const DMX_START_CODE: u8 = 0;
 #[cfg(feature = "alloc")]
 pub const MAXIMUM_CHANNEL_COUNT: u16 = 512;
 #[cfg(not(feature = "alloc"))]
 pub const MAXIMUM_CHANNEL_COUNT: usize = 512;
 
-use core::ops::{Index, IndexMut, RangeInclusive};
+use core::{
+    ops::{Index, IndexMut, RangeInclusive},
+    slice::{Chunks, ChunksMut},
+};
 use error::DmxError;
 
 #[cfg(not(feature = "alloc"))]
 use heapless::Vec;
 
+#[cfg(feature = "std")]
+use std::io::{self, Read, Write};
+
+/// One bit per channel, channel `n` is bit `n % 8` of byte `n / 8`
+pub type ChannelMask = [u8; 64];
+
+/// A 1-based DMX address (1..=512), as presented in console and fixture UIs
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DmxAddress(u16);
+
+impl DmxAddress {
+    pub const fn new(address: u16) -> Result<Self, DmxError> {
+        if address == 0 || address > 512 {
+            return Err(DmxError::InvalidAddress(address));
+        }
+
+        Ok(Self(address))
+    }
+
+    pub const fn from_index(index: u16) -> Result<Self, DmxError> {
+        if index >= 512 {
+            return Err(DmxError::ChannelOutOfBounds);
+        }
+
+        Ok(Self(index + 1))
+    }
+
+    pub const fn get(&self) -> u16 {
+        self.0
+    }
+
+    /// The 0-based channel index used by `DmxUniverse`
+    pub const fn to_index(&self) -> u16 {
+        self.0 - 1
+    }
+}
+
+impl TryFrom<u16> for DmxAddress {
+    type Error = DmxError;
+
+    fn try_from(address: u16) -> Result<Self, Self::Error> {
+        Self::new(address)
+    }
+}
+
+impl From<DmxAddress> for u16 {
+    fn from(address: DmxAddress) -> Self {
+        address.0
+    }
+}
+
 #[cfg(feature = "alloc")]
 #[derive(Clone, Debug, PartialEq)]
 pub struct DmxUniverse {
     pub channel_count: u16,
     channels: Vec<u8>,
+    start_code: u8,
 }
 
 #[cfg(not(feature = "alloc"))]
 #[derive(Clone, Debug, PartialEq)]
-pub struct DmxUniverse(Vec<u8, MAXIMUM_CHANNEL_COUNT>);
+pub struct DmxUniverse(Vec<u8, MAXIMUM_CHANNEL_COUNT>, u8);
 
 impl DmxUniverse {
     #[cfg(feature = "alloc")]
@@ -60,11 +116,33 @@ impl DmxUniverse {
         Ok(Self {
             channel_count,
             channels: vec![0; channel_count as usize],
+            start_code: DMX_START_CODE,
         })
     }
     #[cfg(not(feature = "alloc"))]
     pub fn new() -> Self {
-        Self(Vec::from_slice(&[0; MAXIMUM_CHANNEL_COUNT]).unwrap())
+        Self(
+            Vec::from_slice(&[0; MAXIMUM_CHANNEL_COUNT]).unwrap(),
+            DMX_START_CODE,
+        )
+    }
+
+    /// The number of active channels
+    pub fn len(&self) -> u16 {
+        self.as_slice().len() as u16
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+
+    /// The start code sent before the channel values, `DMX_START_CODE` unless constructed from a
+    /// `(start_code, channels)` tuple
+    pub fn start_code(&self) -> u8 {
+        #[cfg(feature = "alloc")]
+        return self.start_code;
+        #[cfg(not(feature = "alloc"))]
+        self.1
     }
 
     pub fn reset(&mut self) {
@@ -113,6 +191,32 @@ impl DmxUniverse {
         }
     }
 
+    /// Up to `len` channel values from `start`, fewer or none when the range passes the end of
+    /// the universe
+    pub fn get_channel_values_clamped(&self, start: u16, len: u16) -> &[u8] {
+        let channels = self.as_slice();
+        let start = (start as usize).min(channels.len());
+        let end = (start + len as usize).min(channels.len());
+
+        &channels[start..end]
+    }
+
+    /// Checks the value of a channel, for assertions on lighting state in tests. The error
+    /// describes the channel with the expected and actual values.
+    pub fn assert_channel(&self, channel: u16, expected: u8) -> Result<(), DmxError> {
+        let actual = self.get_channel_value(channel)?;
+
+        if actual != expected {
+            return Err(DmxError::ChannelValueMismatch {
+                channel,
+                expected,
+                actual,
+            });
+        }
+
+        Ok(())
+    }
+
     #[cfg(feature = "alloc")]
     pub fn set_channel_value(&mut self, channel: u16, value: u8) -> Result<(), DmxError> {
         if channel < self.channel_count {
@@ -156,6 +260,50 @@ impl DmxUniverse {
         }
     }
 
+    /// Sets consecutive channel values from `channel`, wrapping around to channel 0 past the last channel
+    pub fn set_channel_values_wrapping(
+        &mut self,
+        channel: u16,
+        values: &[u8],
+    ) -> Result<(), DmxError> {
+        #[cfg(feature = "alloc")]
+        let channels = &mut self.channels;
+        #[cfg(not(feature = "alloc"))]
+        let channels = &mut self.0;
+
+        if channel as usize >= channels.len() {
+            return Err(DmxError::ChannelOutOfBounds);
+        }
+
+        let channel_count = channels.len();
+
+        for (i, &value) in values.iter().enumerate() {
+            channels[(channel as usize + i) % channel_count] = value;
+        }
+
+        Ok(())
+    }
+
+    pub fn get_address(&self, address: DmxAddress) -> Result<u8, DmxError> {
+        self.get_channel_value(address.to_index())
+    }
+
+    pub fn set_address(&mut self, address: DmxAddress, value: u8) -> Result<(), DmxError> {
+        self.set_channel_value(address.to_index(), value)
+    }
+
+    /// Writes `values` to consecutive channels beginning at the 1-based `start_address`, e.g. a
+    /// fixture's channels at its DMX start address
+    pub fn patch(&mut self, start_address: u16, values: &[u8]) -> Result<(), DmxError> {
+        let address = DmxAddress::new(start_address)?;
+
+        if values.len() > self.len() as usize {
+            return Err(DmxError::ChannelOutOfBounds);
+        }
+
+        self.set_channel_values(address.to_index(), values)
+    }
+
     pub fn set_all_channel_values(&mut self, value: u8) {
         #[cfg(feature = "alloc")]
         self.channels.fill(value);
@@ -163,6 +311,56 @@ impl DmxUniverse {
         self.0.fill(value);
     }
 
+    /// Replaces each channel value `v` with `255 - v`
+    pub fn invert(&mut self) {
+        #[cfg(feature = "alloc")]
+        let channels = self.channels.iter_mut();
+        #[cfg(not(feature = "alloc"))]
+        let channels = self.0.iter_mut();
+
+        for value in channels {
+            *value = u8::MAX - *value;
+        }
+    }
+
+    pub fn inverted(&self) -> Self {
+        let mut universe = self.clone();
+
+        universe.invert();
+
+        universe
+    }
+
+    /// Rotates the channel values `n` channels towards channel 0, wrapping around the active
+    /// channels
+    pub fn rotate_left(&mut self, n: u16) {
+        #[cfg(feature = "alloc")]
+        let channels = self.channels.as_mut_slice();
+        #[cfg(not(feature = "alloc"))]
+        let channels = self.0.as_mut_slice();
+
+        if !channels.is_empty() {
+            let n = n as usize % channels.len();
+
+            channels.rotate_left(n);
+        }
+    }
+
+    /// Rotates the channel values `n` channels away from channel 0, wrapping around the active
+    /// channels
+    pub fn rotate_right(&mut self, n: u16) {
+        #[cfg(feature = "alloc")]
+        let channels = self.channels.as_mut_slice();
+        #[cfg(not(feature = "alloc"))]
+        let channels = self.0.as_mut_slice();
+
+        if !channels.is_empty() {
+            let n = n as usize % channels.len();
+
+            channels.rotate_right(n);
+        }
+    }
+
     pub fn as_slice(&self) -> &[u8] {
         #[cfg(feature = "alloc")]
         return self.channels.as_slice();
@@ -170,6 +368,137 @@ impl DmxUniverse {
         self.0.as_slice()
     }
 
+    /// Splits the channels into non-overlapping blocks of `size`, the last block may be shorter.
+    ///
+    /// Panics if `size` is 0, as with `slice::chunks`.
+    pub fn chunks(&self, size: u16) -> Chunks<'_, u8> {
+        self.as_slice().chunks(size as usize)
+    }
+
+    /// Splits the channels into non-overlapping mutable blocks of `size`, the last block may be shorter.
+    ///
+    /// Panics if `size` is 0, as with `slice::chunks_mut`.
+    pub fn chunks_mut(&mut self, size: u16) -> ChunksMut<'_, u8> {
+        #[cfg(feature = "alloc")]
+        return self.channels.chunks_mut(size as usize);
+        #[cfg(not(feature = "alloc"))]
+        self.0.chunks_mut(size as usize)
+    }
+
+    /// Replaces each channel value with `blend(value, other_value)`, e.g. `u8::max` for HTP
+    pub fn blend_with<F: Fn(u8, u8) -> u8>(
+        &mut self,
+        other: &DmxUniverse,
+        blend: F,
+    ) -> Result<(), DmxError> {
+        if other.len() != self.len() {
+            return Err(DmxError::InvalidChannelCount(other.len()));
+        }
+
+        #[cfg(feature = "alloc")]
+        let channels = self.channels.iter_mut();
+        #[cfg(not(feature = "alloc"))]
+        let channels = self.0.iter_mut();
+
+        for (value, &other_value) in channels.zip(other.as_slice()) {
+            *value = blend(*value, other_value);
+        }
+
+        Ok(())
+    }
+
+    /// Copies the channel values of `other` into this universe, treating zero as transparent
+    pub fn overlay(&mut self, other: &DmxUniverse) -> Result<(), DmxError> {
+        self.blend_with(
+            other,
+            |value, other_value| {
+                if other_value != 0 {
+                    other_value
+                } else {
+                    value
+                }
+            },
+        )
+    }
+
+    /// Returns a mask with a bit set for each channel whose value differs in `other`
+    pub fn changed_mask(&self, other: &DmxUniverse) -> Result<ChannelMask, DmxError> {
+        let (channels, other_channels) = (self.as_slice(), other.as_slice());
+
+        if channels.len() != other_channels.len() {
+            return Err(DmxError::InvalidChannelCount(other_channels.len() as u16));
+        }
+
+        let mut mask = [0; 64];
+
+        for (index, _) in channels
+            .iter()
+            .zip(other_channels)
+            .enumerate()
+            .filter(|(_, (value, other_value))| value != other_value)
+        {
+            mask[index / 8] |= 1 << (index % 8);
+        }
+
+        Ok(mask)
+    }
+
+    /// Returns the smallest range of channels covering every value that differs from `prev`, or
+    /// `None` if nothing changed. Channels missing from `prev` are treated as changed.
+    pub fn min_changed_range(&self, prev: &DmxUniverse) -> Option<RangeInclusive<u16>> {
+        let channels = self.as_slice();
+        let prev_channels = prev.as_slice();
+
+        let is_changed =
+            |(channel, value): &(usize, &u8)| prev_channels.get(*channel) != Some(value);
+
+        let (first, _) = channels.iter().enumerate().find(is_changed)?;
+        let (last, _) = channels.iter().enumerate().rev().find(is_changed)?;
+
+        Some(first as u16..=last as u16)
+    }
+
+    /// Interpolates each channel `step / steps` of the way from this universe to `to`, rounding
+    /// to the nearest value. A `step` at or beyond `steps` returns the values of `to`.
+    pub fn lerp(&self, to: &DmxUniverse, step: u16, steps: u16) -> Result<Self, DmxError> {
+        if to.as_slice().len() != self.as_slice().len() {
+            return Err(DmxError::InvalidChannelCount(to.as_slice().len() as u16));
+        }
+
+        if step >= steps {
+            return Ok(to.clone());
+        }
+
+        let mut universe = self.clone();
+
+        for (channel, (&from, &to)) in self.as_slice().iter().zip(to.as_slice()).enumerate() {
+            let delta = (to as i32 - from as i32) * step as i32;
+            let rounded = (delta + delta.signum() * steps as i32 / 2) / steps as i32;
+
+            universe[channel as u16] = (from as i32 + rounded) as u8;
+        }
+
+        Ok(universe)
+    }
+
+    /// 16-bit wrapping sum of the channel values excluding the start code, as used for the RDM
+    /// checksum and displayed by some DMX analysers
+    pub fn additive_checksum(&self) -> u16 {
+        self.as_slice()
+            .iter()
+            .fold(0u16, |sum, &byte| sum.wrapping_add(byte as u16))
+    }
+
+    /// 64-bit FNV-1a hash of the channel values, useful for cheaply detecting changes between frames
+    pub fn content_hash(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+        const FNV_PRIME: u64 = 0x00000100000001b3;
+
+        self.as_slice().iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ *byte as u64).wrapping_mul(FNV_PRIME)
+        })
+    }
+
     #[cfg(not(feature = "alloc"))]
     pub fn from_slice(bytes: &[u8]) -> Result<Self, DmxError> {
         if bytes.len() > MAXIMUM_CHANNEL_COUNT {
@@ -183,6 +512,21 @@ impl DmxUniverse {
         Ok(universe)
     }
 
+    /// Wraps a full universe of channel values, e.g. a `static` blackout or home-position scene
+    #[cfg(feature = "alloc")]
+    pub fn from_array(channels: &[u8; MAXIMUM_CHANNEL_COUNT as usize]) -> Self {
+        Self {
+            channel_count: MAXIMUM_CHANNEL_COUNT,
+            channels: channels.to_vec(),
+            start_code: DMX_START_CODE,
+        }
+    }
+    /// Wraps a full universe of channel values, e.g. a `static` blackout or home-position scene
+    #[cfg(not(feature = "alloc"))]
+    pub fn from_array(channels: &[u8; MAXIMUM_CHANNEL_COUNT]) -> Self {
+        Self(Vec::from_slice(channels).unwrap(), DMX_START_CODE)
+    }
+
     #[cfg(feature = "alloc")]
     pub fn extend(&mut self, values: &[u8]) -> Result<(), DmxError> {
         if self.channel_count as usize + values.len() > MAXIMUM_CHANNEL_COUNT as usize {
@@ -197,9 +541,11 @@ impl DmxUniverse {
         Ok(())
     }
 
+    /// Decodes a frame of the start code followed by the channel values. A frame of only the
+    /// start code decodes to a universe with no channels, as encoded from `DmxUniverse::new(0)`.
     #[cfg(feature = "alloc")]
     pub fn decode(bytes: &[u8]) -> Result<Self, DmxError> {
-        if bytes.len() < 2 || bytes.len() > MAXIMUM_CHANNEL_COUNT as usize + 1 {
+        if bytes.is_empty() || bytes.len() > MAXIMUM_CHANNEL_COUNT as usize + 1 {
             return Err(DmxError::InvalidFrameLength(bytes.len() as u16));
         }
 
@@ -210,11 +556,14 @@ impl DmxUniverse {
         Ok(Self {
             channel_count: (bytes.len() - 1) as u16,
             channels: bytes[1..].to_vec(),
+            start_code: DMX_START_CODE,
         })
     }
+    /// Decodes a frame of the start code followed by the channel values, channels missing from
+    /// a short frame are zero
     #[cfg(not(feature = "alloc"))]
     pub fn decode(bytes: &[u8]) -> Result<Self, DmxError> {
-        if bytes.len() < 2 || bytes.len() > MAXIMUM_CHANNEL_COUNT + 1 {
+        if bytes.is_empty() || bytes.len() > MAXIMUM_CHANNEL_COUNT + 1 {
             return Err(DmxError::InvalidFrameLength(bytes.len() as u16));
         }
 
@@ -225,11 +574,28 @@ impl DmxUniverse {
         Self::from_slice(&bytes[1..])
     }
 
+    /// Decodes a frame without failing, for display pipelines fed by unreliable sources. Channels
+    /// beyond `MAXIMUM_CHANNEL_COUNT` are dropped, the start code is kept as received and an empty
+    /// frame decodes as if it were only `DMX_START_CODE`.
+    pub fn decode_lossy(bytes: &[u8]) -> Self {
+        let (start_code, channels) = match bytes.split_first() {
+            Some((&start_code, channels)) => (start_code, channels),
+            None => (DMX_START_CODE, &[][..]),
+        };
+
+        #[cfg(feature = "alloc")]
+        let channel_count = channels.len().min(MAXIMUM_CHANNEL_COUNT as usize);
+        #[cfg(not(feature = "alloc"))]
+        let channel_count = channels.len().min(MAXIMUM_CHANNEL_COUNT);
+
+        Self::try_from((start_code, &channels[..channel_count])).unwrap()
+    }
+
     #[cfg(feature = "alloc")]
     pub fn encode(&self) -> Vec<u8> {
         let mut frame: Vec<u8> = Vec::with_capacity(self.channel_count as usize + 1);
 
-        frame.push(DMX_START_CODE);
+        frame.push(self.start_code);
         frame.extend(self.channels.iter());
 
         frame
@@ -238,11 +604,163 @@ impl DmxUniverse {
     pub fn encode(&self) -> Vec<u8, 513> {
         let mut frame = Vec::<u8, 513>::new();
 
-        frame.push(DMX_START_CODE).unwrap();
+        frame.push(self.1).unwrap();
         frame.extend_from_slice(&self.0[..]).unwrap();
 
         frame
     }
+
+    /// Reads a single raw frame, the start code followed by at most 512 channel values, stopping
+    /// early at the end of the reader, e.g. a capture file holding one frame. Any start code is
+    /// accepted. Decoding errors are returned as `io::ErrorKind::InvalidData`.
+    ///
+    /// Raw frames carry no length, use `read_prefixed_frame` to read consecutive frames.
+    #[cfg(feature = "std")]
+    pub fn read_frame<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut frame = Vec::with_capacity(MAXIMUM_CHANNEL_COUNT as usize + 1);
+
+        reader
+            .take(MAXIMUM_CHANNEL_COUNT as u64 + 1)
+            .read_to_end(&mut frame)?;
+
+        let Some((&start_code, channels)) = frame.split_first() else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                DmxError::InvalidFrameLength(0),
+            ));
+        };
+
+        Self::try_from((start_code, channels))
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    /// Writes the raw encoded frame, start code followed by the channel values
+    #[cfg(feature = "std")]
+    pub fn write_frame<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.encode())
+    }
+
+    /// Reads a single frame written by `write_prefixed_frame`, a 2 byte big-endian length followed
+    /// by the start code and channel values, so consecutive frames can be read one at a time from
+    /// a file or socket. Any start code is accepted. Invalid lengths and decoding errors are
+    /// returned as `io::ErrorKind::InvalidData`.
+    #[cfg(feature = "std")]
+    pub fn read_prefixed_frame<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut length = [0; 2];
+
+        reader.read_exact(&mut length)?;
+
+        let length = u16::from_be_bytes(length);
+
+        if length == 0 || length > MAXIMUM_CHANNEL_COUNT + 1 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                DmxError::InvalidFrameLength(length),
+            ));
+        }
+
+        let mut frame = vec![0; length as usize];
+
+        reader.read_exact(&mut frame)?;
+
+        Self::try_from((frame[0], &frame[1..]))
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    /// Writes the encoded frame prefixed with its 2 byte big-endian length, see
+    /// `read_prefixed_frame`
+    #[cfg(feature = "std")]
+    pub fn write_prefixed_frame<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let frame = self.encode();
+
+        writer.write_all(&(frame.len() as u16).to_be_bytes())?;
+        writer.write_all(&frame)
+    }
+
+    fn runs(&self) -> impl Iterator<Item = (u8, u8)> + '_ {
+        self.as_slice()
+            .chunk_by(|a, b| a == b)
+            .flat_map(|run| run.chunks(u8::MAX as usize))
+            .map(|run| (run.len() as u8, run[0]))
+    }
+
+    /// Returns `(address, value)` pairs for every non-zero channel, addresses are 1-based
+    #[cfg(feature = "alloc")]
+    pub fn active_map(&self) -> Vec<(u16, u8)> {
+        self.active_channels().collect()
+    }
+    /// Returns `(address, value)` pairs for every non-zero channel, addresses are 1-based
+    #[cfg(not(feature = "alloc"))]
+    pub fn active_map(&self) -> Vec<(u16, u8), MAXIMUM_CHANNEL_COUNT> {
+        self.active_channels().collect()
+    }
+
+    fn active_channels(&self) -> impl Iterator<Item = (u16, u8)> + '_ {
+        self.as_slice()
+            .iter()
+            .enumerate()
+            .filter(|(_, &value)| value != 0)
+            .map(|(index, &value)| (index as u16 + 1, value))
+    }
+
+    /// Run-length encodes the channel values as `(count, value)` pairs of at most 255 channels
+    #[cfg(feature = "alloc")]
+    pub fn encode_rle(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+
+        for (count, value) in self.runs() {
+            buf.extend([count, value]);
+        }
+
+        buf
+    }
+    /// Run-length encodes the channel values as `(count, value)` pairs of at most 255 channels
+    #[cfg(not(feature = "alloc"))]
+    pub fn encode_rle(&self) -> Vec<u8, 1024> {
+        let mut buf = Vec::new();
+
+        for (count, value) in self.runs() {
+            buf.extend_from_slice(&[count, value]).unwrap();
+        }
+
+        buf
+    }
+
+    /// Decodes channel values run-length encoded by `encode_rle`
+    pub fn decode_rle(bytes: &[u8]) -> Result<Self, DmxError> {
+        if bytes.len() % 2 != 0 {
+            return Err(DmxError::InvalidFrameLength(bytes.len() as u16));
+        }
+
+        let mut channels = [0; 512];
+        let mut channel_count = 0;
+
+        for run in bytes.chunks(2) {
+            let (count, value) = (run[0] as usize, run[1]);
+
+            if count == 0 {
+                return Err(DmxError::InvalidFrameLength(bytes.len() as u16));
+            }
+
+            if channel_count + count > channels.len() {
+                return Err(DmxError::InvalidChannelCount(
+                    (channel_count + count) as u16,
+                ));
+            }
+
+            channels[channel_count..channel_count + count].fill(value);
+            channel_count += count;
+        }
+
+        #[cfg(feature = "alloc")]
+        return Ok(Self {
+            channel_count: channel_count as u16,
+            channels: channels[..channel_count].to_vec(),
+            start_code: DMX_START_CODE,
+        });
+        #[cfg(not(feature = "alloc"))]
+        Self::from_slice(&channels[..channel_count])
+    }
 }
 
 impl Default for DmxUniverse {
@@ -251,6 +769,7 @@ impl Default for DmxUniverse {
         Self {
             channel_count: MAXIMUM_CHANNEL_COUNT,
             channels: vec![0; MAXIMUM_CHANNEL_COUNT as usize],
+            start_code: DMX_START_CODE,
         }
     }
     #[cfg(not(feature = "alloc"))]
@@ -295,6 +814,7 @@ impl TryFrom<&[u8]> for DmxUniverse {
         Ok(DmxUniverse {
             channel_count: bytes.len() as u16,
             channels: bytes.to_vec(),
+            start_code: DMX_START_CODE,
         })
     }
     #[cfg(not(feature = "alloc"))]
@@ -303,6 +823,27 @@ impl TryFrom<&[u8]> for DmxUniverse {
     }
 }
 
+/// Constructs a universe sent with an alternate start code, e.g. for text or manufacturer specific
+/// packets
+impl TryFrom<(u8, &[u8])> for DmxUniverse {
+    type Error = DmxError;
+
+    fn try_from((start_code, channels): (u8, &[u8])) -> Result<Self, Self::Error> {
+        let mut universe = Self::try_from(channels)?;
+
+        #[cfg(feature = "alloc")]
+        {
+            universe.start_code = start_code;
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            universe.1 = start_code;
+        }
+
+        Ok(universe)
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl TryFrom<Vec<u8>> for DmxUniverse {
     type Error = DmxError;
@@ -315,6 +856,7 @@ impl TryFrom<Vec<u8>> for DmxUniverse {
         Ok(DmxUniverse {
             channel_count: bytes.len() as u16,
             channels: bytes,
+            start_code: DMX_START_CODE,
         })
     }
 }
@@ -326,6 +868,25 @@ impl From<DmxUniverse> for Vec<u8> {
     }
 }
 
+/// Average refresh rate in millihertz of frames received at the given intervals in
+/// microseconds, integer arithmetic keeps it available in `no_std` environments.
+///
+/// Returns `None` when there are no intervals or they sum to zero.
+pub fn estimate_refresh_millihertz(intervals_us: &[u32]) -> Option<u32> {
+    let total_us = intervals_us
+        .iter()
+        .map(|&interval| interval as u64)
+        .sum::<u64>();
+
+    if total_us == 0 {
+        return None;
+    }
+
+    let millihertz = intervals_us.len() as u64 * 1_000_000_000 / total_us;
+
+    Some(millihertz.min(u32::MAX as u64) as u32)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -345,6 +906,20 @@ mod tests {
         assert_eq!(universe.0, Vec::<u8, 512>::from_slice(&[0; 512]).unwrap());
     }
 
+    #[test]
+    fn should_create_dmx_universe_from_array() {
+        static HOME_POSITION: [u8; 512] = {
+            let mut channels = [0; 512];
+            channels[0] = 0x80;
+            channels[511] = 0xff;
+            channels
+        };
+
+        let universe = DmxUniverse::from_array(&HOME_POSITION);
+
+        assert_eq!(universe.as_slice(), &HOME_POSITION[..]);
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn should_create_new_dmx_universe_from_byte_slice() {
@@ -409,6 +984,7 @@ mod tests {
         let universe = DmxUniverse {
             channel_count: 4,
             channels: vec![0x40, 0x80, 0xc0, 0xff],
+            start_code: DMX_START_CODE,
         };
 
         assert_eq!(Vec::from(universe.clone()), vec![0x40, 0x80, 0xc0, 0xff]);
@@ -430,6 +1006,7 @@ mod tests {
         let expected = DmxUniverse {
             channel_count: 4,
             channels: vec![0x40, 0x80, 0xc0, 0xff],
+            start_code: DMX_START_CODE,
         };
 
         assert_eq!(decoded, expected);
@@ -445,7 +1022,10 @@ mod tests {
 
         let decoded = DmxUniverse::decode(&[0x00, 0x40, 0x80, 0xc0, 0xff]).unwrap();
 
-        let mut expected = DmxUniverse(Vec::<u8, 512>::from_slice(&[0; 512]).unwrap());
+        let mut expected = DmxUniverse(
+            Vec::<u8, 512>::from_slice(&[0; 512]).unwrap(),
+            DMX_START_CODE,
+        );
         expected.0[0..4].copy_from_slice(&[0x40, 0x80, 0xc0, 0xff]);
 
         assert_eq!(decoded, expected);
@@ -457,6 +1037,7 @@ mod tests {
         let encoded = DmxUniverse {
             channel_count: 4,
             channels: vec![0x40, 0x80, 0xc0, 0xff],
+            start_code: DMX_START_CODE,
         }
         .encode();
 
@@ -479,12 +1060,47 @@ mod tests {
         assert_eq!(encoded, expected);
     }
 
+    #[test]
+    fn should_decode_lossy_dmx_frame() {
+        let mut frame = [0x55; 600];
+        frame[0] = 0x17;
+
+        let universe = DmxUniverse::decode_lossy(&frame);
+
+        assert_eq!(universe.start_code(), 0x17);
+        assert_eq!(universe.as_slice(), &[0x55; 512]);
+
+        let universe = DmxUniverse::decode_lossy(&[]);
+
+        assert_eq!(universe.start_code(), DMX_START_CODE);
+        #[cfg(feature = "alloc")]
+        assert!(universe.is_empty());
+        #[cfg(not(feature = "alloc"))]
+        assert_eq!(universe.as_slice(), &[0; 512]);
+    }
+
+    #[test]
+    fn should_encode_dmx_universe_with_alternate_start_code() {
+        let universe = DmxUniverse::try_from((0x17, &[0x48, 0x69][..])).unwrap();
+
+        assert_eq!(universe.start_code(), 0x17);
+        assert_eq!(&universe.encode()[..3], &[0x17, 0x48, 0x69]);
+
+        assert_eq!(
+            DmxUniverse::try_from(&[0x48, 0x69][..])
+                .unwrap()
+                .start_code(),
+            DMX_START_CODE
+        );
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn should_reset_dmx_universe() {
         let mut universe = DmxUniverse {
             channel_count: 4,
             channels: vec![255; 4],
+            start_code: DMX_START_CODE,
         };
 
         universe.reset();
@@ -496,7 +1112,10 @@ mod tests {
     #[cfg(not(feature = "alloc"))]
     #[test]
     fn should_reset_dmx_universe() {
-        let mut universe = DmxUniverse(Vec::<u8, 512>::from_slice(&[255; 512]).unwrap());
+        let mut universe = DmxUniverse(
+            Vec::<u8, 512>::from_slice(&[255; 512]).unwrap(),
+            DMX_START_CODE,
+        );
 
         universe.reset();
 
@@ -509,6 +1128,7 @@ mod tests {
         let universe = DmxUniverse {
             channel_count: 4,
             channels: vec![0x40, 0x80, 0xc0, 0xff],
+            start_code: DMX_START_CODE,
         };
 
         assert_eq!(universe.get_channel_value(2).unwrap(), 192);
@@ -539,6 +1159,7 @@ mod tests {
         let universe = DmxUniverse {
             channel_count: 4,
             channels: vec![0x40, 0x80, 0xc0, 0xff],
+            start_code: DMX_START_CODE,
         };
 
         assert_eq!(universe.get_channel_values(2..=3).unwrap(), &[192, 255]);
@@ -565,12 +1186,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_assert_channel_value() {
+        let universe = DmxUniverse::try_from(&[0x40, 0x80][..]).unwrap();
+
+        assert_eq!(universe.assert_channel(1, 0x80), Ok(()));
+        assert_eq!(
+            universe.assert_channel(0, 0xff),
+            Err(DmxError::ChannelValueMismatch {
+                channel: 0,
+                expected: 0xff,
+                actual: 0x40,
+            })
+        );
+        assert_eq!(
+            universe.assert_channel(512, 0x00),
+            Err(DmxError::ChannelOutOfBounds)
+        );
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn should_set_channel_value() {
         let mut universe = DmxUniverse {
             channel_count: 4,
             channels: vec![0; 4],
+            start_code: DMX_START_CODE,
         };
 
         universe.set_channel_value(2, 0xff).unwrap();
@@ -602,6 +1243,7 @@ mod tests {
         let mut universe = DmxUniverse {
             channel_count: 4,
             channels: vec![0; 4],
+            start_code: DMX_START_CODE,
         };
 
         universe.set_channel_values(0, &[0x40, 0x80, 0xc0]).unwrap();
@@ -641,6 +1283,7 @@ mod tests {
         let mut universe = DmxUniverse {
             channel_count: 4,
             channels: vec![0; 4],
+            start_code: DMX_START_CODE,
         };
 
         universe.set_all_channel_values(0xff);
@@ -654,17 +1297,466 @@ mod tests {
         let universe = DmxUniverse {
             channel_count: 4,
             channels: vec![255; 4],
+            start_code: DMX_START_CODE,
         };
 
         assert_eq!(universe.as_slice(), &[0xff, 0xff, 0xff, 0xff]);
     }
 
+    #[test]
+    fn should_convert_dmx_address_to_index() {
+        assert_eq!(DmxAddress::new(1).unwrap().to_index(), 0);
+        assert_eq!(DmxAddress::new(512).unwrap().to_index(), 511);
+        assert_eq!(DmxAddress::from_index(0), DmxAddress::new(1));
+        assert_eq!(DmxAddress::new(0), Err(DmxError::InvalidAddress(0)));
+        assert_eq!(DmxAddress::new(513), Err(DmxError::InvalidAddress(513)));
+        assert_eq!(
+            DmxAddress::from_index(512),
+            Err(DmxError::ChannelOutOfBounds)
+        );
+    }
+
+    #[test]
+    fn should_get_and_set_channel_value_by_address() {
+        let mut universe = DmxUniverse::default();
+
+        universe
+            .set_address(DmxAddress::new(1).unwrap(), 0xff)
+            .unwrap();
+
+        assert_eq!(universe.get_channel_value(0).unwrap(), 0xff);
+        assert_eq!(
+            universe.get_address(DmxAddress::new(1).unwrap()).unwrap(),
+            0xff
+        );
+        assert_eq!(
+            universe.get_address(DmxAddress::new(512).unwrap()).unwrap(),
+            0x00
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_split_channels_into_chunks() {
+        let mut universe = DmxUniverse {
+            channel_count: 5,
+            channels: vec![0x01, 0x02, 0x03, 0x04, 0x05],
+            start_code: DMX_START_CODE,
+        };
+
+        let mut chunks = universe.chunks(2);
+
+        assert_eq!(chunks.next(), Some(&[0x01, 0x02][..]));
+        assert_eq!(chunks.next(), Some(&[0x03, 0x04][..]));
+        assert_eq!(chunks.next(), Some(&[0x05][..]));
+        assert_eq!(chunks.next(), None);
+
+        for chunk in universe.chunks_mut(2) {
+            chunk[0] = 0xff;
+        }
+
+        assert_eq!(universe.channels, vec![0xff, 0x02, 0xff, 0x04, 0xff]);
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    fn should_split_channels_into_chunks() {
+        let mut universe = DmxUniverse::from_slice(&[0x01, 0x02, 0x03, 0x04, 0x05]).unwrap();
+
+        let mut chunks = universe.chunks(200);
+
+        assert_eq!(
+            &chunks.next().unwrap()[..5],
+            &[0x01, 0x02, 0x03, 0x04, 0x05]
+        );
+        assert_eq!(chunks.next().unwrap().len(), 200);
+        assert_eq!(chunks.next().unwrap().len(), 112);
+        assert_eq!(chunks.next(), None);
+
+        for chunk in universe.chunks_mut(2) {
+            chunk[0] = 0xff;
+        }
+
+        assert_eq!(&universe.0[..5], &[0xff, 0x02, 0xff, 0x04, 0xff]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_overlay_non_zero_channel_values() {
+        let mut universe = DmxUniverse {
+            channel_count: 4,
+            channels: vec![0x10, 0x20, 0x30, 0x40],
+            start_code: DMX_START_CODE,
+        };
+
+        let other = DmxUniverse {
+            channel_count: 4,
+            channels: vec![0x00, 0xff, 0x00, 0x80],
+            start_code: DMX_START_CODE,
+        };
+
+        universe.overlay(&other).unwrap();
+
+        assert_eq!(universe.channels, vec![0x10, 0xff, 0x30, 0x80]);
+        assert_eq!(
+            universe.overlay(&DmxUniverse::new(8).unwrap()),
+            Err(DmxError::InvalidChannelCount(8))
+        );
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    fn should_overlay_non_zero_channel_values() {
+        let mut universe = DmxUniverse::from_slice(&[0x10, 0x20, 0x30, 0x40]).unwrap();
+        let other = DmxUniverse::from_slice(&[0x00, 0xff, 0x00, 0x80]).unwrap();
+
+        universe.overlay(&other).unwrap();
+
+        assert_eq!(&universe.0[..4], &[0x10, 0xff, 0x30, 0x80]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_hash_channel_values() {
+        let mut universe = DmxUniverse {
+            channel_count: 4,
+            channels: vec![0; 4],
+            start_code: DMX_START_CODE,
+        };
+
+        let hash = universe.content_hash();
+
+        assert_eq!(
+            DmxUniverse::new(0).unwrap().content_hash(),
+            0xcbf29ce484222325
+        );
+        assert_eq!(hash, universe.clone().content_hash());
+
+        universe.set_channel_value(3, 0xff).unwrap();
+
+        assert_ne!(universe.content_hash(), hash);
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    fn should_hash_channel_values() {
+        let mut universe = DmxUniverse::new();
+
+        let hash = universe.content_hash();
+
+        assert_eq!(hash, universe.clone().content_hash());
+
+        universe.set_channel_value(511, 0xff).unwrap();
+
+        assert_ne!(universe.content_hash(), hash);
+    }
+
+    #[test]
+    fn should_mask_changed_channels() {
+        let universe = DmxUniverse::default();
+        let mut other = DmxUniverse::default();
+
+        other.set_channel_value(0, 0xff).unwrap();
+        other.set_channel_value(9, 0x01).unwrap();
+        other.set_channel_value(511, 0x80).unwrap();
+
+        let mask = universe.changed_mask(&other).unwrap();
+
+        assert_eq!(mask[0], 0b0000_0001);
+        assert_eq!(mask[1], 0b0000_0010);
+        assert_eq!(mask[63], 0b1000_0000);
+        assert_eq!(mask.iter().map(|byte| byte.count_ones()).sum::<u32>(), 3);
+        assert_eq!(universe.changed_mask(&universe), Ok([0; 64]));
+    }
+
+    #[test]
+    fn should_return_minimal_changed_channel_range() {
+        let prev = DmxUniverse::default();
+        let mut universe = DmxUniverse::default();
+
+        assert_eq!(universe.min_changed_range(&prev), None);
+
+        universe.set_channel_value(12, 0xff).unwrap();
+
+        assert_eq!(universe.min_changed_range(&prev), Some(12..=12));
+
+        universe.set_channel_value(3, 0x01).unwrap();
+        universe.set_channel_value(511, 0x80).unwrap();
+
+        let range = universe.min_changed_range(&prev).unwrap();
+
+        assert_eq!(range, 3..=511);
+        assert_eq!(universe.get_channel_values(range).unwrap().len(), 509);
+    }
+
+    #[test]
+    fn should_map_active_channels_by_address() {
+        let mut universe = DmxUniverse::default();
+
+        assert!(universe.active_map().is_empty());
+
+        universe.set_channel_value(0, 0xff).unwrap();
+        universe.set_channel_value(511, 0x10).unwrap();
+
+        assert_eq!(&universe.active_map()[..], &[(1, 0xff), (512, 0x10)]);
+    }
+
+    #[test]
+    fn should_invert_channel_values() {
+        let mut universe = DmxUniverse::default();
+
+        universe.set_channel_values(0, &[0x00, 0x40, 0xff]).unwrap();
+
+        let inverted = universe.inverted();
+
+        assert_eq!(&inverted.as_slice()[..4], &[0xff, 0xbf, 0x00, 0xff]);
+
+        universe.invert();
+
+        assert_eq!(universe, inverted);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn should_write_and_read_frame() {
+        let mut universe = DmxUniverse::new(4).unwrap();
+
+        universe.set_channel_values(0, &[255, 128, 64, 0]).unwrap();
+
+        let mut buf = Vec::new();
+
+        universe.write_frame(&mut buf).unwrap();
+
+        assert_eq!(buf, &[0, 255, 128, 64, 0]);
+        assert_eq!(
+            DmxUniverse::read_frame(&mut io::Cursor::new(buf)).unwrap(),
+            universe
+        );
+        assert_eq!(
+            DmxUniverse::read_frame(&mut io::Cursor::new([0x17, 1, 2])).unwrap(),
+            DmxUniverse::try_from((0x17, &[1, 2][..])).unwrap()
+        );
+        assert_eq!(
+            DmxUniverse::read_frame(&mut io::Cursor::new([]))
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn should_write_and_read_consecutive_prefixed_frames() {
+        let mut first = DmxUniverse::new(2).unwrap();
+        first.set_channel_values(0, &[1, 2]).unwrap();
+
+        let second = DmxUniverse::try_from((0x17, &[3, 4, 5][..])).unwrap();
+
+        let mut buf = Vec::new();
+
+        first.write_prefixed_frame(&mut buf).unwrap();
+        second.write_prefixed_frame(&mut buf).unwrap();
+
+        assert_eq!(buf, &[0x00, 0x03, 0, 1, 2, 0x00, 0x04, 0x17, 3, 4, 5]);
+
+        let mut reader = io::Cursor::new(buf);
+
+        assert_eq!(
+            DmxUniverse::read_prefixed_frame(&mut reader).unwrap(),
+            first
+        );
+        assert_eq!(
+            DmxUniverse::read_prefixed_frame(&mut reader).unwrap(),
+            second
+        );
+        assert_eq!(
+            DmxUniverse::read_prefixed_frame(&mut reader)
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+        assert_eq!(
+            DmxUniverse::read_prefixed_frame(&mut io::Cursor::new([0x02, 0x02, 0x00]))
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::InvalidData
+        );
+        assert_eq!(
+            DmxUniverse::read_prefixed_frame(&mut io::Cursor::new([0x00, 0x03, 0x00]))
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+    }
+
+    #[test]
+    fn should_patch_values_at_start_address() {
+        let mut universe = DmxUniverse::default();
+
+        universe.patch(1, &[255, 128]).unwrap();
+        universe.patch(510, &[1, 2, 3]).unwrap();
+
+        assert_eq!(&universe.as_slice()[..3], &[255, 128, 0]);
+        assert_eq!(&universe.as_slice()[509..], &[1, 2, 3]);
+        assert_eq!(universe.patch(0, &[1]), Err(DmxError::InvalidAddress(0)));
+        assert_eq!(
+            universe.patch(511, &[1, 2, 3]),
+            Err(DmxError::ChannelOutOfBounds)
+        );
+        assert_eq!(
+            universe.patch(1, &[0; 513]),
+            Err(DmxError::ChannelOutOfBounds)
+        );
+    }
+
+    #[test]
+    fn should_rotate_channel_values() {
+        let mut universe = DmxUniverse::default();
+
+        universe.set_channel_values(0, &[1, 2, 3]).unwrap();
+        universe.rotate_right(2);
+
+        assert_eq!(&universe.as_slice()[..5], &[0, 0, 1, 2, 3]);
+
+        universe.rotate_left(3);
+
+        assert_eq!(&universe.as_slice()[..3], &[2, 3, 0]);
+        assert_eq!(universe.get_channel_value(511), Ok(1));
+
+        universe.rotate_left(512 + 1);
+
+        assert_eq!(&universe.as_slice()[..2], &[3, 0]);
+        assert_eq!(universe.get_channel_value(510), Ok(1));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_rotate_within_active_channels() {
+        let mut universe = DmxUniverse::decode(&[0, 1, 2, 3, 4]).unwrap();
+
+        universe.rotate_left(5);
+
+        assert_eq!(universe.as_slice(), &[2, 3, 4, 1]);
+
+        DmxUniverse::new(0).unwrap().rotate_right(1);
+    }
+
+    #[test]
+    fn should_compute_additive_checksum() {
+        let mut universe = DmxUniverse::default();
+
+        assert_eq!(universe.additive_checksum(), 0);
+
+        universe.set_channel_values(0, &[0x01, 0x02, 0xff]).unwrap();
+
+        assert_eq!(universe.additive_checksum(), 0x0102);
+
+        universe.set_all_channel_values(0xff);
+
+        assert_eq!(universe.additive_checksum(), (512 * 0xff) as u16);
+    }
+
+    #[test]
+    fn should_blend_with_custom_function() {
+        let mut universe = DmxUniverse::default();
+        let mut other = DmxUniverse::default();
+
+        universe.set_channel_values(0, &[10, 200, 128]).unwrap();
+        other.set_channel_values(0, &[20, 100, 255]).unwrap();
+
+        let mut htp = universe.clone();
+
+        htp.blend_with(&other, u8::max).unwrap();
+
+        assert_eq!(&htp.as_slice()[..3], &[20, 200, 255]);
+
+        universe
+            .blend_with(&other, |value, other_value| {
+                ((value as u16 * other_value as u16) / 255) as u8
+            })
+            .unwrap();
+
+        assert_eq!(&universe.as_slice()[..3], &[0, 78, 128]);
+    }
+
+    #[test]
+    fn should_estimate_refresh_rate() {
+        assert_eq!(estimate_refresh_millihertz(&[22_727; 3]), Some(44_000));
+        assert_eq!(estimate_refresh_millihertz(&[25_000, 15_000]), Some(50_000));
+        assert_eq!(estimate_refresh_millihertz(&[]), None);
+        assert_eq!(estimate_refresh_millihertz(&[0, 0]), None);
+    }
+
+    #[test]
+    fn should_get_clamped_channel_values() {
+        let mut universe = DmxUniverse::default();
+
+        universe.set_channel_values(508, &[1, 2, 3, 4]).unwrap();
+
+        assert_eq!(universe.get_channel_values_clamped(506, 4), &[0, 0, 1, 2]);
+        assert_eq!(universe.get_channel_values_clamped(508, 16), &[1, 2, 3, 4]);
+        assert_eq!(universe.get_channel_values_clamped(512, 16), &[] as &[u8]);
+        assert_eq!(
+            universe.get_channel_values_clamped(u16::MAX, u16::MAX),
+            &[] as &[u8]
+        );
+    }
+
+    #[test]
+    fn should_return_channel_count() {
+        let universe = DmxUniverse::default();
+
+        assert_eq!(universe.len(), 512);
+        assert!(!universe.is_empty());
+
+        let universe = DmxUniverse::decode(&[0, 255, 255, 0]).unwrap();
+
+        #[cfg(feature = "alloc")]
+        assert_eq!(universe.len(), 3);
+        #[cfg(not(feature = "alloc"))]
+        assert_eq!(universe.len(), 512);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_round_trip_universe_without_channels() {
+        let universe = DmxUniverse::new(0).unwrap();
+
+        assert!(universe.is_empty());
+        assert_eq!(universe.encode(), vec![DMX_START_CODE]);
+        assert_eq!(DmxUniverse::decode(&universe.encode()), Ok(universe));
+        assert_eq!(
+            DmxUniverse::decode(&[]),
+            Err(DmxError::InvalidFrameLength(0))
+        );
+    }
+
+    #[test]
+    fn should_interpolate_between_universes() {
+        let from = DmxUniverse::default();
+        let mut to = DmxUniverse::default();
+
+        to.set_channel_values(0, &[0xff, 0x0a]).unwrap();
+
+        let halfway = from.lerp(&to, 1, 2).unwrap();
+
+        assert_eq!(&halfway.as_slice()[..3], &[0x80, 0x05, 0x00]);
+        assert_eq!(
+            &to.lerp(&from, 1, 3).unwrap().as_slice()[..2],
+            &[0xaa, 0x07]
+        );
+        assert_eq!(from.lerp(&to, 0, 2).unwrap(), from);
+        assert_eq!(from.lerp(&to, 2, 2).unwrap(), to);
+        assert_eq!(from.lerp(&to, 0, 0).unwrap(), to);
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn should_extend_channels_with_byte_slice() {
         let mut universe = DmxUniverse {
             channel_count: 4,
             channels: vec![255; 4],
+            start_code: DMX_START_CODE,
         };
 
         universe.extend(&[0, 0, 0, 0]).unwrap();
@@ -680,4 +1772,78 @@ mod tests {
             Err(DmxError::InvalidChannelCount(520))
         );
     }
+
+    #[test]
+    fn should_round_trip_rle_encoded_universe() {
+        let mut sparse = [0; 512];
+        sparse[0] = 255;
+        sparse[100..104].copy_from_slice(&[128, 128, 128, 64]);
+
+        let universe = DmxUniverse::from_array(&sparse);
+        let encoded = universe.encode_rle();
+
+        assert_eq!(
+            &encoded[..],
+            &[1, 255, 99, 0, 3, 128, 1, 64, 255, 0, 153, 0]
+        );
+        assert_eq!(DmxUniverse::decode_rle(&encoded), Ok(universe));
+
+        let mut dense = [0; 512];
+        for (index, value) in dense.iter_mut().enumerate() {
+            *value = index as u8;
+        }
+
+        let universe = DmxUniverse::from_array(&dense);
+        let encoded = universe.encode_rle();
+
+        assert_eq!(encoded.len(), 1024);
+        assert_eq!(DmxUniverse::decode_rle(&encoded), Ok(universe));
+    }
+
+    #[test]
+    fn should_not_decode_invalid_rle_universe() {
+        assert_eq!(
+            DmxUniverse::decode_rle(&[1, 255, 2]),
+            Err(DmxError::InvalidFrameLength(3))
+        );
+        assert_eq!(
+            DmxUniverse::decode_rle(&[0, 255]),
+            Err(DmxError::InvalidFrameLength(2))
+        );
+        assert_eq!(
+            DmxUniverse::decode_rle(&[255, 0, 255, 0, 3, 0]),
+            Err(DmxError::InvalidChannelCount(513))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_set_channel_values_wrapping() {
+        let mut universe = DmxUniverse::new(4).unwrap();
+
+        universe.set_channel_values_wrapping(2, &[1, 2, 3]).unwrap();
+
+        assert_eq!(universe.as_slice(), &[3, 0, 1, 2]);
+        assert_eq!(
+            universe.set_channel_values_wrapping(4, &[1]),
+            Err(DmxError::ChannelOutOfBounds)
+        );
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    fn should_set_channel_values_wrapping() {
+        let mut universe = DmxUniverse::new();
+
+        universe
+            .set_channel_values_wrapping(510, &[1, 2, 3])
+            .unwrap();
+
+        assert_eq!(&universe.as_slice()[..2], &[3, 0]);
+        assert_eq!(&universe.as_slice()[510..], &[1, 2]);
+        assert_eq!(
+            universe.set_channel_values_wrapping(512, &[1]),
+            Err(DmxError::ChannelOutOfBounds)
+        );
+    }
 }
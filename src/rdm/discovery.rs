@@ -0,0 +1,922 @@
+//! Helpers for enumerating devices once they have been discovered
+//!
+//! ```rust
+//! use dmx512_rdm_protocol::rdm::{discovery::required_get_requests, DeviceUID, SubDeviceId};
+//!
+//! let requests = required_get_requests(
+//!     DeviceUID::new(0x0102, 0x03040506),
+//!     DeviceUID::new(0x0605, 0x04030201),
+//!     SubDeviceId::RootDevice,
+//! );
+//!
+//! assert_eq!(requests.len(), 5);
+//! ```
+
+use super::{
+    request::{RdmRequest, RequestParameter},
+    DeviceUID, SubDeviceId,
+};
+
+#[cfg(feature = "alloc")]
+use super::{
+    parameter::{
+        ParameterDescription, ParameterId, Personality, ProductCategory, ProtocolVersion,
+        SensorDefinition, SensorValue, SlotInfo,
+    },
+    response::{RdmFrameResponse, ResponseParameterData, SlotDescriptionCollector},
+    CommandClass,
+};
+#[cfg(not(feature = "alloc"))]
+use heapless::Vec;
+#[cfg(feature = "alloc")]
+use std::collections::{HashMap, VecDeque};
+
+// E1.20 2025 Table A-3, the required parameters which can be read with a GET
+pub const REQUIRED_PARAMETERS: [RequestParameter; 5] = [
+    RequestParameter::GetSupportedParameters,
+    RequestParameter::GetDeviceInfo,
+    RequestParameter::GetSoftwareVersionLabel,
+    RequestParameter::GetDmxStartAddress,
+    RequestParameter::GetIdentifyDevice,
+];
+
+/// Builds a GET request for each of the `REQUIRED_PARAMETERS`, numbered with consecutive
+/// transaction numbers from 0
+#[cfg(feature = "alloc")]
+pub fn required_get_requests(
+    destination_uid: DeviceUID,
+    source_uid: DeviceUID,
+    sub_device_id: SubDeviceId,
+) -> Vec<RdmRequest> {
+    required_get_request_iter(destination_uid, source_uid, sub_device_id).collect()
+}
+/// Builds a GET request for each of the `REQUIRED_PARAMETERS`, numbered with consecutive
+/// transaction numbers from 0
+#[cfg(not(feature = "alloc"))]
+pub fn required_get_requests(
+    destination_uid: DeviceUID,
+    source_uid: DeviceUID,
+    sub_device_id: SubDeviceId,
+) -> Vec<RdmRequest, 5> {
+    required_get_request_iter(destination_uid, source_uid, sub_device_id).collect()
+}
+
+fn required_get_request_iter(
+    destination_uid: DeviceUID,
+    source_uid: DeviceUID,
+    sub_device_id: SubDeviceId,
+) -> impl Iterator<Item = RdmRequest> {
+    REQUIRED_PARAMETERS
+        .into_iter()
+        .enumerate()
+        .map(move |(transaction_number, parameter)| {
+            RdmRequest::template(destination_uid, source_uid)
+                .transaction_number(transaction_number as u8)
+                .sub_device_id(sub_device_id)
+                .parameter(parameter)
+        })
+}
+
+/// Produces the ordered GET requests needed to fully characterise a discovered device.
+///
+/// DEVICE_INFO and SUPPORTED_PARAMETERS are requested first, followed by the descriptions of any
+/// personalities, sensors, slots, curves and manufacturer specific parameters the device
+/// supports. Sub-devices reported by the root DEVICE_INFO are assumed to be numbered
+/// sequentially from 1, and are characterised in the same way once the root device is complete.
+///
+/// Only ACK responses from the device to the previous request expand the plan. An ACK_OVERFLOW
+/// re-issues the request until the final ACK, accumulating the SUPPORTED_PARAMETERS list, and an
+/// ACK_TIMER re-issues the request up to `MAX_ACK_TIMER_RETRIES` times, the caller should wait
+/// for the estimated response time before calling `next`. Any other response (or none at all)
+/// moves on to the next request.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct EnumerationPlan {
+    destination_uid: DeviceUID,
+    source_uid: DeviceUID,
+    transaction_number: u8,
+    pending: VecDeque<(SubDeviceId, RequestParameter)>,
+    outstanding: Option<(u8, SubDeviceId, RequestParameter)>,
+    ack_timer_retries: u8,
+    footprint: u16,
+    personality_count: u8,
+    sensor_count: u8,
+    supported_parameters: Vec<u16>,
+    overflowed_parameters: Vec<u16>,
+}
+
+#[cfg(feature = "alloc")]
+impl EnumerationPlan {
+    pub const MAX_ACK_TIMER_RETRIES: u8 = 3;
+
+    pub fn new(destination_uid: DeviceUID, source_uid: DeviceUID) -> Self {
+        Self {
+            destination_uid,
+            source_uid,
+            transaction_number: 0,
+            pending: VecDeque::from([
+                (SubDeviceId::RootDevice, RequestParameter::GetDeviceInfo),
+                (
+                    SubDeviceId::RootDevice,
+                    RequestParameter::GetSupportedParameters,
+                ),
+            ]),
+            outstanding: None,
+            ack_timer_retries: 0,
+            footprint: 0,
+            personality_count: 0,
+            sensor_count: 0,
+            supported_parameters: Vec::new(),
+            overflowed_parameters: Vec::new(),
+        }
+    }
+
+    /// Returns the next request to send, taking into account the response to the previous
+    /// request, or `None` once the device has been fully characterised
+    pub fn next(&mut self, last_response: Option<&RdmFrameResponse>) -> Option<RdmRequest> {
+        let outstanding = self.outstanding.take();
+        let mut is_reissued = false;
+
+        if let (Some(response), Some((transaction_number, sub_device_id, parameter))) =
+            (last_response, outstanding)
+        {
+            if response.source_uid == self.destination_uid
+                && response.command_class == CommandClass::GetCommandResponse
+                && response.transaction_number == transaction_number
+                && response.sub_device_id == sub_device_id
+                && response.parameter_id == parameter.parameter_id()
+            {
+                let parameter_data = response.parameter_data.parameter_data();
+
+                if response.is_ack_overflow() {
+                    if let Some(ResponseParameterData::GetSupportedParameters(parameter_ids)) =
+                        parameter_data
+                    {
+                        self.overflowed_parameters.extend(parameter_ids);
+                    }
+
+                    self.pending.push_front((sub_device_id, parameter));
+                    is_reissued = true;
+                } else if response.is_ack_timer()
+                    && self.ack_timer_retries < Self::MAX_ACK_TIMER_RETRIES
+                {
+                    self.ack_timer_retries += 1;
+                    self.pending.push_front((sub_device_id, parameter));
+                    is_reissued = true;
+                } else if response.is_ack() {
+                    match parameter_data {
+                        Some(parameter_data) => self.expand(sub_device_id, parameter_data),
+                        // The final ACK of an overflowed list may carry no further parameters
+                        None if matches!(parameter, RequestParameter::GetSupportedParameters) => {
+                            self.expand(
+                                sub_device_id,
+                                &ResponseParameterData::GetSupportedParameters(Vec::new()),
+                            )
+                        }
+                        None => {}
+                    }
+                }
+            }
+        }
+
+        if !is_reissued {
+            self.ack_timer_retries = 0;
+            self.overflowed_parameters.clear();
+        }
+
+        let (sub_device_id, parameter) = self.pending.pop_front()?;
+
+        // Counts are only known for the device whose DEVICE_INFO was last acknowledged, they
+        // must not carry over to a sub-device whose DEVICE_INFO fails
+        if matches!(parameter, RequestParameter::GetDeviceInfo) {
+            self.footprint = 0;
+            self.personality_count = 0;
+            self.sensor_count = 0;
+            self.supported_parameters.clear();
+        }
+
+        let request = RdmRequest::template(self.destination_uid, self.source_uid)
+            .transaction_number(self.transaction_number)
+            .sub_device_id(sub_device_id)
+            .parameter(parameter);
+
+        self.outstanding = Some((
+            self.transaction_number,
+            sub_device_id,
+            request.parameter.clone(),
+        ));
+        self.transaction_number = self.transaction_number.wrapping_add(1);
+
+        Some(request)
+    }
+
+    fn is_supported(&self, parameter_id: ParameterId) -> bool {
+        self.supported_parameters.contains(&u16::from(parameter_id))
+    }
+
+    fn expand(&mut self, sub_device_id: SubDeviceId, parameter_data: &ResponseParameterData) {
+        let mut requests = Vec::new();
+
+        match parameter_data {
+            ResponseParameterData::GetDeviceInfo {
+                footprint,
+                personality_count,
+                sub_device_count,
+                sensor_count,
+                ..
+            } => {
+                self.footprint = *footprint;
+                self.personality_count = *personality_count;
+                self.sensor_count = *sensor_count;
+
+                if sub_device_id == SubDeviceId::RootDevice {
+                    for id in 1..=(*sub_device_count).min(SubDeviceId::MAX_SUB_DEVICES) {
+                        self.pending
+                            .push_back((SubDeviceId::Id(id), RequestParameter::GetDeviceInfo));
+                        self.pending.push_back((
+                            SubDeviceId::Id(id),
+                            RequestParameter::GetSupportedParameters,
+                        ));
+                    }
+                }
+            }
+            ResponseParameterData::GetSupportedParameters(parameter_ids) => {
+                let mut supported_parameters = core::mem::take(&mut self.overflowed_parameters);
+                supported_parameters.extend(parameter_ids);
+
+                for &parameter_id in &supported_parameters {
+                    match ParameterId::from(parameter_id) {
+                        ParameterId::DmxPersonalityDescription => {
+                            requests.extend((1..=self.personality_count).map(|personality| {
+                                RequestParameter::GetDmxPersonalityDescription { personality }
+                            }))
+                        }
+                        ParameterId::SensorDefinition => {
+                            requests.extend((0..self.sensor_count).map(|sensor_id| {
+                                RequestParameter::GetSensorDefinition { sensor_id }
+                            }))
+                        }
+                        ParameterId::SlotInfo => requests.push(RequestParameter::GetSlotInfo),
+                        ParameterId::SlotDescription => requests.extend(
+                            (0..self.footprint)
+                                .map(|slot_id| RequestParameter::GetSlotDescription { slot_id }),
+                        ),
+                        ParameterId::Curve => requests.push(RequestParameter::GetCurve),
+                        ParameterId::ManufacturerSpecific(parameter_id) => requests
+                            .push(RequestParameter::GetParameterDescription { parameter_id }),
+                        _ => {}
+                    }
+                }
+
+                self.supported_parameters = supported_parameters;
+            }
+            ResponseParameterData::GetCurve { curve_count, .. }
+                if self.is_supported(ParameterId::CurveDescription) =>
+            {
+                requests.extend(
+                    (1..=*curve_count)
+                        .map(|curve_id| RequestParameter::GetCurveDescription { curve_id }),
+                );
+            }
+            _ => {}
+        }
+
+        for parameter in requests.into_iter().rev() {
+            self.pending.push_front((sub_device_id, parameter));
+        }
+    }
+}
+
+/// The state of a device built up from the responses to GET requests, such as those produced by
+/// `EnumerationPlan`.
+///
+/// Fields are `None` (or empty) until a response carrying them has been applied.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeviceModel {
+    pub uid: DeviceUID,
+    pub sub_device_id: SubDeviceId,
+    pub protocol_version: Option<ProtocolVersion>,
+    pub model_id: Option<u16>,
+    pub product_category: Option<ProductCategory>,
+    pub software_version_id: Option<u32>,
+    pub footprint: Option<u16>,
+    pub current_personality: Option<u8>,
+    pub personality_count: Option<u8>,
+    pub start_address: Option<u16>,
+    pub sub_device_count: Option<u16>,
+    pub sensor_count: Option<u8>,
+    pub supported_parameters: Vec<u16>,
+    pub model_description: Option<String>,
+    pub manufacturer_label: Option<String>,
+    pub label: Option<String>,
+    pub software_version_label: Option<String>,
+    pub identify: Option<bool>,
+    pub device_hours: Option<u32>,
+    pub lamp_hours: Option<u32>,
+    pub personalities: HashMap<u8, Personality>,
+    pub sensor_definitions: HashMap<u8, SensorDefinition>,
+    pub sensor_values: HashMap<u8, SensorValue>,
+    pub slot_info: Vec<SlotInfo>,
+    pub slot_descriptions: SlotDescriptionCollector,
+    pub parameter_descriptions: HashMap<u16, ParameterDescription>,
+}
+
+#[cfg(feature = "alloc")]
+impl DeviceModel {
+    pub fn new(uid: DeviceUID, sub_device_id: SubDeviceId) -> Self {
+        Self {
+            uid,
+            sub_device_id,
+            protocol_version: None,
+            model_id: None,
+            product_category: None,
+            software_version_id: None,
+            footprint: None,
+            current_personality: None,
+            personality_count: None,
+            start_address: None,
+            sub_device_count: None,
+            sensor_count: None,
+            supported_parameters: Vec::new(),
+            model_description: None,
+            manufacturer_label: None,
+            label: None,
+            software_version_label: None,
+            identify: None,
+            device_hours: None,
+            lamp_hours: None,
+            personalities: HashMap::new(),
+            sensor_definitions: HashMap::new(),
+            sensor_values: HashMap::new(),
+            slot_info: Vec::new(),
+            slot_descriptions: SlotDescriptionCollector::new(),
+            parameter_descriptions: HashMap::new(),
+        }
+    }
+
+    /// Updates the field carried by `response`.
+    ///
+    /// Returns `false`, leaving the model unchanged, if the response is not an ACK from this
+    /// device and sub-device, or carries parameter data the model does not record.
+    pub fn apply(&mut self, response: &RdmFrameResponse) -> bool {
+        if !response.is_ack()
+            || response.source_uid != self.uid
+            || response.sub_device_id != self.sub_device_id
+        {
+            return false;
+        }
+
+        let Some(parameter_data) = response.parameter_data.parameter_data() else {
+            return false;
+        };
+
+        match parameter_data {
+            ResponseParameterData::GetDeviceInfo {
+                protocol_version,
+                model_id,
+                product_category,
+                software_version_id,
+                footprint,
+                current_personality,
+                personality_count,
+                start_address,
+                sub_device_count,
+                sensor_count,
+            } => {
+                self.protocol_version = Some(*protocol_version);
+                self.model_id = Some(*model_id);
+                self.product_category = Some(*product_category);
+                self.software_version_id = Some(*software_version_id);
+                self.footprint = Some(*footprint);
+                self.current_personality = Some(*current_personality);
+                self.personality_count = Some(*personality_count);
+                self.start_address = Some(*start_address);
+                self.sub_device_count = Some(*sub_device_count);
+                self.sensor_count = Some(*sensor_count);
+            }
+            ResponseParameterData::GetSupportedParameters(parameters) => {
+                self.supported_parameters = parameters.clone();
+            }
+            ResponseParameterData::GetParameterDescription(description) => {
+                self.parameter_descriptions
+                    .insert(description.parameter_id, description.clone());
+            }
+            ResponseParameterData::GetDeviceModelDescription(description) => {
+                self.model_description = Some(description.clone());
+            }
+            ResponseParameterData::GetManufacturerLabel(label) => {
+                self.manufacturer_label = Some(label.clone());
+            }
+            ResponseParameterData::GetDeviceLabel(label) => {
+                self.label = Some(label.clone());
+            }
+            ResponseParameterData::GetSoftwareVersionLabel(label) => {
+                self.software_version_label = Some(label.clone());
+            }
+            ResponseParameterData::GetDmxPersonality {
+                current_personality,
+                personality_count,
+            } => {
+                self.current_personality = Some(*current_personality);
+                self.personality_count = Some(*personality_count);
+            }
+            data @ ResponseParameterData::GetDmxPersonalityDescription { id, .. } => {
+                if let Some(personality) = data.personality() {
+                    self.personalities.insert(*id, personality);
+                }
+            }
+            ResponseParameterData::GetDmxStartAddress(start_address) => {
+                self.start_address = Some(*start_address);
+            }
+            ResponseParameterData::GetSlotInfo(slot_info) => {
+                self.slot_info = slot_info.clone();
+            }
+            data @ ResponseParameterData::GetSlotDescription { .. } => {
+                self.slot_descriptions.ingest(data);
+            }
+            ResponseParameterData::GetSensorDefinition(definition) => {
+                self.sensor_definitions
+                    .insert(definition.id, definition.clone());
+            }
+            ResponseParameterData::GetSensorValue(value)
+            | ResponseParameterData::SetSensorValue(value) => {
+                self.sensor_values.insert(value.sensor_id, *value);
+            }
+            ResponseParameterData::GetDeviceHours(hours) => {
+                self.device_hours = Some(*hours);
+            }
+            ResponseParameterData::GetLampHours(hours) => {
+                self.lamp_hours = Some(*hours);
+            }
+            ResponseParameterData::GetIdentifyDevice(identify) => {
+                self.identify = Some(*identify);
+            }
+            _ => return false,
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use crate::rdm::{
+        parameter::{ProductCategory, ProtocolVersion},
+        response::{ResponseData, ResponseType},
+    };
+
+    #[test]
+    fn should_build_required_get_requests() {
+        let destination_uid = DeviceUID::new(0x0102, 0x03040506);
+
+        let requests = required_get_requests(
+            destination_uid,
+            DeviceUID::new(0x0605, 0x04030201),
+            SubDeviceId::Id(0x0001),
+        );
+
+        assert_eq!(requests.len(), REQUIRED_PARAMETERS.len());
+
+        for (index, request) in requests.iter().enumerate() {
+            assert_eq!(request.destination_uid, destination_uid);
+            assert_eq!(request.transaction_number, index as u8);
+            assert_eq!(request.sub_device_id, SubDeviceId::Id(0x0001));
+            assert_eq!(request.parameter, REQUIRED_PARAMETERS[index]);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_expand_enumeration_plan_from_responses() {
+        let destination_uid = DeviceUID::new(0x0102, 0x03040506);
+
+        let ack = |request: &RdmRequest, parameter_data: Option<ResponseParameterData>| {
+            RdmFrameResponse {
+                destination_uid: request.source_uid,
+                source_uid: request.destination_uid,
+                transaction_number: request.transaction_number,
+                response_type: ResponseType::Ack,
+                message_count: 0x00,
+                sub_device_id: request.sub_device_id,
+                command_class: CommandClass::GetCommandResponse,
+                parameter_id: request.parameter_id(),
+                parameter_data: ResponseData::ParameterData(parameter_data),
+            }
+        };
+
+        let device_info = |sub_device_count| ResponseParameterData::GetDeviceInfo {
+            protocol_version: ProtocolVersion::new(1, 0),
+            model_id: 0x0001,
+            product_category: ProductCategory::Fixture,
+            software_version_id: 0x00000001,
+            footprint: 0,
+            current_personality: 1,
+            personality_count: 2,
+            start_address: 1,
+            sub_device_count,
+            sensor_count: 1,
+        };
+
+        let mut plan = EnumerationPlan::new(destination_uid, DeviceUID::new(0x0605, 0x04030201));
+        let mut requests = Vec::new();
+        let mut response = None;
+
+        while let Some(request) = plan.next(response.as_ref()) {
+            let parameter_data = match (request.sub_device_id, &request.parameter) {
+                (SubDeviceId::RootDevice, RequestParameter::GetDeviceInfo) => Some(device_info(1)),
+                (SubDeviceId::RootDevice, RequestParameter::GetSupportedParameters) => {
+                    Some(ResponseParameterData::GetSupportedParameters(vec![
+                        0x00e1, 0x0200, 0x0343, 0x0344, 0x8001,
+                    ]))
+                }
+                (_, RequestParameter::GetCurve) => Some(ResponseParameterData::GetCurve {
+                    curve_id: 1,
+                    curve_count: 2,
+                }),
+                _ => None,
+            };
+
+            response = Some(ack(&request, parameter_data));
+            requests.push((request.sub_device_id, request.parameter));
+        }
+
+        assert_eq!(
+            requests,
+            vec![
+                (SubDeviceId::RootDevice, RequestParameter::GetDeviceInfo),
+                (
+                    SubDeviceId::RootDevice,
+                    RequestParameter::GetSupportedParameters
+                ),
+                (
+                    SubDeviceId::RootDevice,
+                    RequestParameter::GetDmxPersonalityDescription { personality: 1 }
+                ),
+                (
+                    SubDeviceId::RootDevice,
+                    RequestParameter::GetDmxPersonalityDescription { personality: 2 }
+                ),
+                (
+                    SubDeviceId::RootDevice,
+                    RequestParameter::GetSensorDefinition { sensor_id: 0 }
+                ),
+                (SubDeviceId::RootDevice, RequestParameter::GetCurve),
+                (
+                    SubDeviceId::RootDevice,
+                    RequestParameter::GetCurveDescription { curve_id: 1 }
+                ),
+                (
+                    SubDeviceId::RootDevice,
+                    RequestParameter::GetCurveDescription { curve_id: 2 }
+                ),
+                (
+                    SubDeviceId::RootDevice,
+                    RequestParameter::GetParameterDescription {
+                        parameter_id: 0x8001
+                    }
+                ),
+                (SubDeviceId::Id(1), RequestParameter::GetDeviceInfo),
+                (SubDeviceId::Id(1), RequestParameter::GetSupportedParameters),
+            ]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_not_reuse_counts_when_sub_device_info_fails() {
+        let mut plan = EnumerationPlan::new(
+            DeviceUID::new(0x0102, 0x03040506),
+            DeviceUID::new(0x0605, 0x04030201),
+        );
+        let mut requests = Vec::new();
+        let mut response = None;
+
+        while let Some(request) = plan.next(response.as_ref()) {
+            let parameter_data = match (request.sub_device_id, &request.parameter) {
+                (SubDeviceId::RootDevice, RequestParameter::GetDeviceInfo) => {
+                    ResponseParameterData::GetDeviceInfo {
+                        protocol_version: ProtocolVersion::new(1, 0),
+                        model_id: 0x0001,
+                        product_category: ProductCategory::Fixture,
+                        software_version_id: 0x00000001,
+                        footprint: 2,
+                        current_personality: 1,
+                        personality_count: 1,
+                        start_address: 1,
+                        sub_device_count: 1,
+                        sensor_count: 1,
+                    }
+                }
+                // The sub-device DEVICE_INFO times out
+                (SubDeviceId::Id(1), RequestParameter::GetDeviceInfo) => {
+                    requests.push((request.sub_device_id, request.parameter));
+                    response = None;
+
+                    continue;
+                }
+                (_, RequestParameter::GetSupportedParameters) => {
+                    ResponseParameterData::GetSupportedParameters(vec![0x00e1, 0x0121, 0x0200])
+                }
+                _ => ResponseParameterData::GetIdentifyDevice(false),
+            };
+
+            response = Some(RdmFrameResponse {
+                destination_uid: request.source_uid,
+                source_uid: request.destination_uid,
+                transaction_number: request.transaction_number,
+                response_type: ResponseType::Ack,
+                message_count: 0x00,
+                sub_device_id: request.sub_device_id,
+                command_class: CommandClass::GetCommandResponse,
+                parameter_id: request.parameter_id(),
+                parameter_data: ResponseData::ParameterData(Some(parameter_data)),
+            });
+            requests.push((request.sub_device_id, request.parameter));
+        }
+
+        assert_eq!(
+            requests,
+            vec![
+                (SubDeviceId::RootDevice, RequestParameter::GetDeviceInfo),
+                (
+                    SubDeviceId::RootDevice,
+                    RequestParameter::GetSupportedParameters
+                ),
+                (
+                    SubDeviceId::RootDevice,
+                    RequestParameter::GetDmxPersonalityDescription { personality: 1 }
+                ),
+                (
+                    SubDeviceId::RootDevice,
+                    RequestParameter::GetSlotDescription { slot_id: 0 }
+                ),
+                (
+                    SubDeviceId::RootDevice,
+                    RequestParameter::GetSlotDescription { slot_id: 1 }
+                ),
+                (
+                    SubDeviceId::RootDevice,
+                    RequestParameter::GetSensorDefinition { sensor_id: 0 }
+                ),
+                (SubDeviceId::Id(1), RequestParameter::GetDeviceInfo),
+                (SubDeviceId::Id(1), RequestParameter::GetSupportedParameters),
+            ]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_reissue_requests_on_ack_overflow_and_ack_timer() {
+        let mut plan = EnumerationPlan::new(
+            DeviceUID::new(0x0102, 0x03040506),
+            DeviceUID::new(0x0605, 0x04030201),
+        );
+        let mut requests = Vec::new();
+        let mut response: Option<RdmFrameResponse> = None;
+
+        while let Some(request) = plan.next(response.as_ref()) {
+            let is_repeat = requests
+                .last()
+                .is_some_and(|(_, parameter)| parameter == &request.parameter);
+
+            let (response_type, parameter_data) = match (&request.parameter, is_repeat) {
+                (RequestParameter::GetDeviceInfo, false) => (
+                    ResponseType::AckTimer,
+                    ResponseData::EstimateResponseTime(10),
+                ),
+                (RequestParameter::GetDeviceInfo, true) => (
+                    ResponseType::Ack,
+                    ResponseData::ParameterData(Some(ResponseParameterData::GetDeviceInfo {
+                        protocol_version: ProtocolVersion::new(1, 0),
+                        model_id: 0x0001,
+                        product_category: ProductCategory::Fixture,
+                        software_version_id: 0x00000001,
+                        footprint: 0,
+                        current_personality: 1,
+                        personality_count: 1,
+                        start_address: 1,
+                        sub_device_count: 0,
+                        sensor_count: 0,
+                    })),
+                ),
+                (RequestParameter::GetSupportedParameters, false) => (
+                    ResponseType::AckOverflow,
+                    ResponseData::ParameterData(Some(
+                        ResponseParameterData::GetSupportedParameters(vec![0x00e1]),
+                    )),
+                ),
+                (RequestParameter::GetSupportedParameters, true) => (
+                    ResponseType::Ack,
+                    ResponseData::ParameterData(Some(
+                        ResponseParameterData::GetSupportedParameters(vec![0x8001]),
+                    )),
+                ),
+                _ => (ResponseType::Ack, ResponseData::ParameterData(None)),
+            };
+
+            response = Some(RdmFrameResponse {
+                destination_uid: request.source_uid,
+                source_uid: request.destination_uid,
+                transaction_number: request.transaction_number,
+                response_type,
+                message_count: 0x00,
+                sub_device_id: request.sub_device_id,
+                command_class: CommandClass::GetCommandResponse,
+                parameter_id: request.parameter_id(),
+                parameter_data,
+            });
+            requests.push((request.sub_device_id, request.parameter));
+        }
+
+        assert_eq!(
+            requests,
+            vec![
+                (SubDeviceId::RootDevice, RequestParameter::GetDeviceInfo),
+                (SubDeviceId::RootDevice, RequestParameter::GetDeviceInfo),
+                (
+                    SubDeviceId::RootDevice,
+                    RequestParameter::GetSupportedParameters
+                ),
+                (
+                    SubDeviceId::RootDevice,
+                    RequestParameter::GetSupportedParameters
+                ),
+                (
+                    SubDeviceId::RootDevice,
+                    RequestParameter::GetDmxPersonalityDescription { personality: 1 }
+                ),
+                (
+                    SubDeviceId::RootDevice,
+                    RequestParameter::GetParameterDescription {
+                        parameter_id: 0x8001
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_limit_ack_timer_retries() {
+        let mut plan = EnumerationPlan::new(
+            DeviceUID::new(0x0102, 0x03040506),
+            DeviceUID::new(0x0605, 0x04030201),
+        );
+        let mut requests = Vec::new();
+        let mut response = None;
+
+        while let Some(request) = plan.next(response.as_ref()) {
+            response = Some(RdmFrameResponse {
+                destination_uid: request.source_uid,
+                source_uid: request.destination_uid,
+                transaction_number: request.transaction_number,
+                response_type: ResponseType::AckTimer,
+                message_count: 0x00,
+                sub_device_id: request.sub_device_id,
+                command_class: CommandClass::GetCommandResponse,
+                parameter_id: request.parameter_id(),
+                parameter_data: ResponseData::EstimateResponseTime(10),
+            });
+            requests.push(request.parameter);
+        }
+
+        let attempts = 1 + EnumerationPlan::MAX_ACK_TIMER_RETRIES as usize;
+
+        assert_eq!(requests.len(), 2 * attempts);
+        assert!(requests[..attempts]
+            .iter()
+            .all(|parameter| parameter == &RequestParameter::GetDeviceInfo));
+        assert!(requests[attempts..]
+            .iter()
+            .all(|parameter| parameter == &RequestParameter::GetSupportedParameters));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_not_expand_enumeration_plan_from_other_devices() {
+        let destination_uid = DeviceUID::new(0x0102, 0x03040506);
+        let mut plan = EnumerationPlan::new(destination_uid, DeviceUID::new(0x0605, 0x04030201));
+
+        let request = plan.next(None).unwrap();
+
+        let response = RdmFrameResponse {
+            destination_uid: request.source_uid,
+            source_uid: DeviceUID::new(0x0102, 0x0a0b0c0d),
+            transaction_number: request.transaction_number,
+            response_type: ResponseType::Ack,
+            message_count: 0x00,
+            sub_device_id: request.sub_device_id,
+            command_class: CommandClass::GetCommandResponse,
+            parameter_id: request.parameter_id(),
+            parameter_data: ResponseData::ParameterData(Some(
+                ResponseParameterData::GetDeviceInfo {
+                    protocol_version: ProtocolVersion::new(1, 0),
+                    model_id: 0x0001,
+                    product_category: ProductCategory::Fixture,
+                    software_version_id: 0x00000001,
+                    footprint: 0,
+                    current_personality: 1,
+                    personality_count: 1,
+                    start_address: 1,
+                    sub_device_count: 1,
+                    sensor_count: 0,
+                },
+            )),
+        };
+
+        assert_eq!(
+            plan.next(Some(&response)).map(|request| request.parameter),
+            Some(RequestParameter::GetSupportedParameters)
+        );
+        assert_eq!(plan.next(None), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_apply_responses_to_device_model() {
+        let uid = DeviceUID::new(0x0102, 0x03040506);
+
+        let response = |source_uid, response_type, parameter_data| RdmFrameResponse {
+            destination_uid: DeviceUID::new(0x0605, 0x04030201),
+            source_uid,
+            transaction_number: 0x00,
+            response_type,
+            message_count: 0x00,
+            sub_device_id: SubDeviceId::RootDevice,
+            command_class: CommandClass::GetCommandResponse,
+            parameter_id: ParameterId::DeviceInfo,
+            parameter_data: ResponseData::ParameterData(Some(parameter_data)),
+        };
+
+        let mut model = DeviceModel::new(uid, SubDeviceId::RootDevice);
+
+        assert!(model.apply(&response(
+            uid,
+            ResponseType::Ack,
+            ResponseParameterData::GetDeviceInfo {
+                protocol_version: ProtocolVersion::new(1, 0),
+                model_id: 0x0001,
+                product_category: ProductCategory::Fixture,
+                software_version_id: 0x00000001,
+                footprint: 4,
+                current_personality: 1,
+                personality_count: 2,
+                start_address: 1,
+                sub_device_count: 0,
+                sensor_count: 1,
+            },
+        )));
+        assert!(model.apply(&response(
+            uid,
+            ResponseType::Ack,
+            ResponseParameterData::GetDmxPersonalityDescription {
+                id: 2,
+                dmx_slots_required: 8,
+                description: "Extended".into(),
+            },
+        )));
+        assert!(model.apply(&response(
+            uid,
+            ResponseType::Ack,
+            ResponseParameterData::GetDmxStartAddress(17),
+        )));
+        assert!(model.apply(&response(
+            uid,
+            ResponseType::Ack,
+            ResponseParameterData::GetDeviceLabel("Wash 1".into()),
+        )));
+
+        assert!(!model.apply(&response(
+            DeviceUID::new(0x0102, 0x03040507),
+            ResponseType::Ack,
+            ResponseParameterData::GetDeviceLabel("Wash 2".into()),
+        )));
+        assert!(!model.apply(&response(
+            uid,
+            ResponseType::AckTimer,
+            ResponseParameterData::GetDmxStartAddress(33),
+        )));
+        assert!(!model.apply(&response(
+            uid,
+            ResponseType::Ack,
+            ResponseParameterData::GetDisplayLevel(0xff),
+        )));
+
+        assert_eq!(model.model_id, Some(0x0001));
+        assert_eq!(model.footprint, Some(4));
+        assert_eq!(model.start_address, Some(17));
+        assert_eq!(model.label.as_deref(), Some("Wash 1"));
+        assert_eq!(
+            model.personalities.get(&2),
+            Some(&Personality {
+                id: 2,
+                dmx_slots_required: 8,
+                description: "Extended".into(),
+            })
+        );
+        assert_eq!(model.identify, None);
+    }
+}
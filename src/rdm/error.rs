@@ -3,9 +3,11 @@ use core::{array::TryFromSliceError, error::Error, fmt, str::Utf8Error};
 #[derive(Clone, Debug, PartialEq)]
 #[non_exhaustive]
 pub enum RdmError {
-    InvalidStartCode,
+    InvalidStartCode(u8),
     InvalidFrameLength(u8),
     InvalidMessageLength(u8),
+    InvalidPortId(u8),
+    InvalidSubDeviceId(u16),
     InvalidChecksum(u16, u16),
     InvalidResponseType(u8),
     InvalidNackReasonCode(u16),
@@ -14,10 +16,13 @@ pub enum RdmError {
     InvalidCommandClassImplementation(u8),
     UnsupportedParameter(u8, u16),
     InvalidParameterDataLength(u8),
+    InvalidParameterData,
     InvalidParameterDataType(u8),
+    InvalidParameterId(u16),
     InvalidSensorUnit(u8),
     InvalidSensorUnitPrefix(u8),
     InvalidDiscoveryUniqueBranchPreamble,
+    DiscoveryCollision,
     Utf8Error { source: core::str::Utf8Error },
     TryFromSliceError,
     InvalidLampState(u8),
@@ -37,6 +42,16 @@ pub enum RdmError {
     InvalidDiscoveryState(u8),
     InvalidEndpointMode(u8),
     InvalidEndpointType(u8),
+    ManufacturerPidRegistryFull,
+    TransactionTrackerFull,
+    MissingParameterData {
+        parameter_id: u16,
+    },
+    InvalidCommandClassForParameter {
+        command_class: u8,
+        parameter_id: u16,
+    },
+    UnexpectedDiscoveryResponse(u16),
     MalformedPacket,
 }
 
@@ -55,13 +70,19 @@ impl From<Utf8Error> for RdmError {
 impl fmt::Display for RdmError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::InvalidStartCode => write!(f, "Invalid start code"),
+            Self::InvalidStartCode(start_code) => write!(f, "Invalid start code: {}", start_code),
             Self::InvalidFrameLength(length) => write!(f, "Invalid frame length: {}", length),
             Self::InvalidMessageLength(length) => write!(
                 f,
                 "Invalid message length: {}, must be >= 24 and <= 255",
                 length
             ),
+            Self::InvalidPortId(port_id) => {
+                write!(f, "Invalid port id: {}, must be >= 1 and <= 255", port_id)
+            }
+            Self::InvalidSubDeviceId(sub_device_id) => {
+                write!(f, "Invalid sub-device id: {}", sub_device_id)
+            }
             Self::InvalidChecksum(checksum, expected) => {
                 write!(f, "Invalid checksum: {}, expected: {}", checksum, expected)
             }
@@ -90,9 +111,15 @@ impl fmt::Display for RdmError {
                 "Invalid parameter data length: {}, must be >= 0 and <= 231",
                 length
             ),
+            Self::InvalidParameterData => write!(f, "Invalid parameter data, out of range"),
             Self::InvalidParameterDataType(data_type) => {
                 write!(f, "Invalid ParameterDataType: {}", data_type)
             }
+            Self::InvalidParameterId(parameter_id) => write!(
+                f,
+                "Invalid ParameterId: {:#06x}, must be >= 0x8000 and <= 0xffdf",
+                parameter_id
+            ),
             Self::InvalidSensorUnit(sensor_unit) => {
                 write!(f, "Invalid SensorUnit: {}", sensor_unit)
             }
@@ -102,6 +129,9 @@ impl fmt::Display for RdmError {
             Self::InvalidDiscoveryUniqueBranchPreamble => {
                 write!(f, "Invalid discovery unique branch preamble")
             }
+            Self::DiscoveryCollision => {
+                write!(f, "Discovery collision, more than one device responded")
+            }
             Self::Utf8Error { source } => write!(f, "Invalid utf-8 sequence: {}", source),
             Self::TryFromSliceError => write!(f, "Could not convert slice to array"),
             Self::InvalidLampState(state) => write!(f, "Invalid LampState: {}", state),
@@ -137,6 +167,28 @@ impl fmt::Display for RdmError {
             Self::InvalidDiscoveryState(discovery_state) => write!(f, "Invalid DiscoveryState: {}", discovery_state),
             Self::InvalidEndpointMode(endpoint_mode) => write!(f, "Invalid EndpointMode: {}", endpoint_mode),
             Self::InvalidEndpointType(endpoint_type) => write!(f, "Invalid EndpointType: {}", endpoint_type),
+            Self::ManufacturerPidRegistryFull => write!(f, "Manufacturer PID registry is full"),
+            Self::TransactionTrackerFull => write!(f, "Transaction tracker is full"),
+            Self::MissingParameterData { parameter_id } => {
+                write!(
+                    f,
+                    "Missing parameter data for ParameterId: {}",
+                    parameter_id
+                )
+            }
+            Self::InvalidCommandClassForParameter {
+                command_class,
+                parameter_id,
+            } => write!(
+                f,
+                "Invalid CommandClass: {} for ParameterId: {}",
+                command_class, parameter_id
+            ),
+            Self::UnexpectedDiscoveryResponse(parameter_id) => write!(
+                f,
+                "Unexpected discovery response for ParameterId: {}",
+                parameter_id
+            ),
             Self::MalformedPacket => write!(f, "Malformed packet"),
         }
     }
@@ -0,0 +1,50 @@
+//! A partial sample of the ESTA manufacturer id registry
+//!
+//! Manufacturer ids are allocated by ESTA, see <https://tsp.esta.org/tsp/working_groups/CP/mfctrIDs.php>
+//! for the full list of registered manufacturers. Only a handful of common manufacturers are
+//! bundled, so most registered ids are not found.
+
+// Sorted by manufacturer id for binary search. Entries are copied from the ESTA list above, add
+// to it by inserting the id and name as listed there in sorted order.
+const MANUFACTURERS: &[(u16, &str)] = &[
+    (0x0000, "ESTA"),
+    (0x414c, "Artistic Licence Engineering Ltd."),
+    (0x4354, "City Theatrical Inc."),
+    (0x4744, "Goddard Design Co."),
+    (0x4845, "High End Systems Inc."),
+    (0x4d50, "Martin Professional A/S"),
+    (0x6574, "ETC"),
+];
+
+/// Looks up a manufacturer name in the bundled sample, returns `None` for any id not in it,
+/// which includes most registered manufacturers
+pub fn manufacturer_name(manufacturer_id: u16) -> Option<&'static str> {
+    MANUFACTURERS
+        .binary_search_by_key(&manufacturer_id, |(id, _)| *id)
+        .ok()
+        .map(|index| MANUFACTURERS[index].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rdm::DeviceUID;
+
+    #[test]
+    fn should_keep_manufacturers_sorted() {
+        assert!(MANUFACTURERS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn should_lookup_manufacturer_name() {
+        assert_eq!(
+            DeviceUID::new(0x6574, 0x00000001).manufacturer_name(),
+            Some("ETC")
+        );
+        assert_eq!(
+            DeviceUID::new_dynamic(0x6574, 0x00000001).manufacturer_name(),
+            Some("ETC")
+        );
+        assert_eq!(manufacturer_name(0x7ff0), None);
+    }
+}
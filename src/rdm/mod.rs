@@ -1,14 +1,25 @@
 //! Data types and functionality for encoding and decoding RDM packets
 
+pub mod discovery;
 pub mod error;
 #[macro_use]
 pub mod utils;
+#[cfg(feature = "manufacturer-db")]
+pub mod manufacturer;
 pub mod parameter;
+#[cfg(feature = "rdmnet")]
+pub mod rdmnet;
 pub mod request;
 pub mod response;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+pub mod transaction;
 
+use core::{fmt, ops::RangeInclusive};
 use error::RdmError;
 pub use macaddr;
+use request::RdmRequest;
+use response::RdmResponse;
 
 pub const RDM_START_CODE_BYTE: u8 = 0xcc;
 pub const RDM_SUB_START_CODE_BYTE: u8 = 0x01;
@@ -19,6 +30,10 @@ pub const DISCOVERY_UNIQUE_BRANCH_PREAMBLE_SEPARATOR_BYTE: u8 = 0xaa;
 pub const MAX_RDM_FRAME_LENGTH: usize = 257;
 pub const MAX_RDM_PARAMETER_DATA_LENGTH: usize = 231;
 
+// E1.20 Table 3-3, responder turnaround time measured from the end of the controller request
+pub const RESPONDER_MIN_DELAY_US: u32 = 176;
+pub const RESPONDER_MAX_DELAY_US: u32 = 2000;
+
 #[cfg(not(feature = "alloc"))]
 use heapless::Vec;
 
@@ -32,6 +47,19 @@ pub type EncodedParameterData = Vec<u8>;
 #[cfg(not(feature = "alloc"))]
 pub type EncodedParameterData = Vec<u8, MAX_RDM_PARAMETER_DATA_LENGTH>;
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FrameKind {
+    Rdm,
+    DiscoveryUniqueBranch,
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum RdmFrame {
+    Request(RdmRequest),
+    Response(RdmResponse),
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum CommandClass {
     DiscoveryCommand = 0x10,
@@ -58,6 +86,21 @@ impl TryFrom<u8> for CommandClass {
     }
 }
 
+impl fmt::Display for CommandClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let command_class = match self {
+            Self::DiscoveryCommand => "DISCOVERY_COMMAND",
+            Self::DiscoveryCommandResponse => "DISCOVERY_COMMAND_RESPONSE",
+            Self::GetCommand => "GET_COMMAND",
+            Self::GetCommandResponse => "GET_COMMAND_RESPONSE",
+            Self::SetCommand => "SET_COMMAND",
+            Self::SetCommandResponse => "SET_COMMAND_RESPONSE",
+        };
+
+        f.write_str(command_class)
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct DeviceUID {
     pub manufacturer_id: u16,
@@ -113,6 +156,59 @@ impl DeviceUID {
     pub fn is_dynamic(&self) -> bool {
         self.manufacturer_id & 0x8000 != 0
     }
+
+    /// Broadcasts address all devices, or all devices of a manufacturer, and are never responded to
+    pub fn is_broadcast(&self) -> bool {
+        self.device_id == Self::ALL_DEVICES_ID
+    }
+
+    /// Writes the 6 byte big-endian uid to the start of `buf`
+    pub fn write_be(&self, buf: &mut [u8]) -> Result<(), RdmError> {
+        let buf = buf.get_mut(..6).ok_or(RdmError::TryFromSliceError)?;
+
+        buf[..2].copy_from_slice(&self.manufacturer_id.to_be_bytes());
+        buf[2..].copy_from_slice(&self.device_id.to_be_bytes());
+
+        Ok(())
+    }
+
+    /// Builds a uid from 6 bytes in little-endian order, i.e. the reverse of the big-endian
+    /// order used on the wire and by `From<[u8; 6]>`
+    pub fn from_le_bytes(mut bytes: [u8; 6]) -> Self {
+        bytes.reverse();
+
+        Self::from(bytes)
+    }
+
+    /// Returns the 6 byte uid in little-endian order, the reverse of the big-endian order used
+    /// on the wire and by `From<DeviceUID> for [u8; 6]`
+    pub fn to_le_bytes(&self) -> [u8; 6] {
+        let mut bytes = <[u8; 6]>::from(*self);
+
+        bytes.reverse();
+
+        bytes
+    }
+
+    /// 64-bit FNV-1a hash of the 6 byte big-endian uid, for keying device tables without a
+    /// `Hasher`. The value is stable across platforms and releases.
+    pub fn fnv_hash(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+        const FNV_PRIME: u64 = 0x00000100000001b3;
+
+        <[u8; 6]>::from(*self)
+            .iter()
+            .fold(FNV_OFFSET_BASIS, |hash, byte| {
+                (hash ^ *byte as u64).wrapping_mul(FNV_PRIME)
+            })
+    }
+
+    /// Looks up the ESTA manufacturer name, ignoring the dynamic uid flag. Only a partial sample
+    /// of the registry is bundled, see `manufacturer::manufacturer_name`
+    #[cfg(feature = "manufacturer-db")]
+    pub fn manufacturer_name(&self) -> Option<&'static str> {
+        manufacturer::manufacturer_name(self.manufacturer_id & 0x7fff)
+    }
 }
 
 impl From<[u8; 6]> for DeviceUID {
@@ -127,26 +223,235 @@ impl From<[u8; 6]> for DeviceUID {
     }
 }
 
+impl fmt::Display for DeviceUID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04x}:{:08x}", self.manufacturer_id, self.device_id)
+    }
+}
+
 impl From<DeviceUID> for [u8; 6] {
     fn from(uid: DeviceUID) -> Self {
-        let manufacturer_id_bytes = uid.manufacturer_id.to_be_bytes();
-        let device_id_bytes = uid.device_id.to_be_bytes();
+        let mut bytes = [0; 6];
 
-        [
-            manufacturer_id_bytes[0],
-            manufacturer_id_bytes[1],
-            device_id_bytes[0],
-            device_id_bytes[1],
-            device_id_bytes[2],
-            device_id_bytes[3],
-        ]
+        // A 6 byte buffer is always large enough
+        uid.write_be(&mut bytes).unwrap();
+
+        bytes
     }
 }
 
+/// The window in which a responder must start sending its response, in microseconds
+pub const fn response_window_us() -> RangeInclusive<u32> {
+    RESPONDER_MIN_DELAY_US..=RESPONDER_MAX_DELAY_US
+}
+
+/// The parameter data that fits in a frame after the 24 byte header and 2 byte checksum
+pub const fn max_parameter_data_for_frame() -> usize {
+    MAX_RDM_FRAME_LENGTH - 26
+}
+
+/// Splits parameter data into pieces that each fit in a single frame, for transfers spanning
+/// multiple frames. Empty data yields no pieces.
+pub fn chunk_parameter_data(data: &[u8]) -> impl Iterator<Item = &[u8]> {
+    data.chunks(max_parameter_data_for_frame())
+}
+
 pub fn bsd_16_crc(packet: &[u8]) -> u16 {
     packet
         .iter()
-        .fold(0_u16, |sum, byte| (sum.overflowing_add(*byte as u16).0))
+        .fold(0_u16, |sum, byte| sum.overflowing_add(*byte as u16).0)
+}
+
+/// Encodes a uid as the 12 byte EUID followed by the 4 byte ECS checksum used in a
+/// DISC_UNIQUE_BRANCH response, each byte is sent twice, OR'd with 0xaa and 0x55
+pub fn encode_euid(uid: DeviceUID) -> [u8; 16] {
+    let mut euid = [0; 16];
+
+    let bytes: [u8; 6] = uid.into();
+
+    for (index, byte) in bytes.iter().enumerate() {
+        euid[index * 2] = byte | 0xaa;
+        euid[index * 2 + 1] = byte | 0x55;
+    }
+
+    let [checksum1, checksum0] = bsd_16_crc(&euid[..12]).to_be_bytes();
+
+    euid[12..].copy_from_slice(&[
+        checksum1 | 0xaa,
+        checksum1 | 0x55,
+        checksum0 | 0xaa,
+        checksum0 | 0x55,
+    ]);
+
+    euid
+}
+
+/// Decodes the 12 byte EUID and 4 byte ECS checksum of a DISC_UNIQUE_BRANCH response
+pub fn decode_euid(bytes: &[u8; 16]) -> Result<DeviceUID, RdmError> {
+    let (euid, ecs) = bytes.split_at(12);
+
+    let decoded_checksum = bsd_16_crc(euid);
+
+    let checksum = u16::from_be_bytes([ecs[0] & ecs[1], ecs[2] & ecs[3]]);
+
+    if checksum != decoded_checksum {
+        return Err(RdmError::InvalidChecksum(decoded_checksum, checksum));
+    }
+
+    Ok(DeviceUID::from([
+        euid[0] & euid[1],
+        euid[2] & euid[3],
+        euid[4] & euid[5],
+        euid[6] & euid[7],
+        euid[8] & euid[9],
+        euid[10] & euid[11],
+    ]))
+}
+
+/// Whether the frame begins with the RDM start code and sub-start code
+pub fn has_rdm_start(bytes: &[u8]) -> bool {
+    matches!(bytes, [RDM_START_CODE_BYTE, RDM_SUB_START_CODE_BYTE, ..])
+}
+
+/// Whether the frame begins with a DISC_UNIQUE_BRANCH response preamble or separator byte
+pub fn has_discovery_start(bytes: &[u8]) -> bool {
+    matches!(
+        bytes,
+        [
+            DISCOVERY_UNIQUE_BRANCH_PREAMBLE_BYTE | DISCOVERY_UNIQUE_BRANCH_PREAMBLE_SEPARATOR_BYTE,
+            ..
+        ]
+    )
+}
+
+/// Decodes a captured frame as a request or response, distinguished by the command class
+pub fn decode_frame(bytes: &[u8]) -> Result<RdmFrame, RdmError> {
+    if !has_rdm_start(bytes) {
+        return RdmResponse::decode(bytes).map(RdmFrame::Response);
+    }
+
+    let Some(&command_class) = bytes.get(20) else {
+        return Err(RdmError::InvalidFrameLength(bytes.len() as u8));
+    };
+
+    match CommandClass::try_from(command_class)? {
+        CommandClass::DiscoveryCommand | CommandClass::GetCommand | CommandClass::SetCommand => {
+            RdmRequest::decode(bytes).map(RdmFrame::Request)
+        }
+        CommandClass::DiscoveryCommandResponse
+        | CommandClass::GetCommandResponse
+        | CommandClass::SetCommandResponse => RdmResponse::decode(bytes).map(RdmFrame::Response),
+    }
+}
+
+/// The result of decoding the start of a buffer that may not yet hold a whole frame
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum FrameStatus {
+    /// A frame was decoded from the first `length` bytes of the buffer
+    Complete { frame: RdmFrame, length: usize },
+    /// The buffer holds the start of a frame, more bytes are needed to decode it
+    Incomplete,
+}
+
+/// Decodes a frame from the start of a buffer that is still being filled, e.g. from a serial
+/// port. An error means the buffer does not start with a valid frame and the bytes up to the
+/// next start code should be discarded.
+pub fn decode_partial_frame(bytes: &[u8]) -> Result<FrameStatus, RdmError> {
+    let length = match bytes {
+        [] | [RDM_START_CODE_BYTE] | [RDM_START_CODE_BYTE, RDM_SUB_START_CODE_BYTE] => {
+            return Ok(FrameStatus::Incomplete);
+        }
+        [RDM_START_CODE_BYTE, RDM_SUB_START_CODE_BYTE, message_length, ..] => {
+            if *message_length < 24 {
+                return Err(RdmError::InvalidMessageLength(*message_length));
+            }
+
+            *message_length as usize + 2
+        }
+        [DISCOVERY_UNIQUE_BRANCH_PREAMBLE_BYTE | DISCOVERY_UNIQUE_BRANCH_PREAMBLE_SEPARATOR_BYTE, ..] =>
+        {
+            let preamble_length = bytes
+                .iter()
+                .take_while(|&&byte| byte == DISCOVERY_UNIQUE_BRANCH_PREAMBLE_BYTE)
+                .count();
+
+            // Up to 7 preamble bytes may precede the separator
+            if preamble_length > 7 {
+                return Err(RdmError::InvalidDiscoveryUniqueBranchPreamble);
+            }
+
+            match bytes.get(preamble_length) {
+                Some(&DISCOVERY_UNIQUE_BRANCH_PREAMBLE_SEPARATOR_BYTE) => preamble_length + 17,
+                Some(_) => return Err(RdmError::InvalidDiscoveryUniqueBranchPreamble),
+                None => return Ok(FrameStatus::Incomplete),
+            }
+        }
+        [start_code, ..] => return Err(RdmError::InvalidStartCode(*start_code)),
+    };
+
+    if bytes.len() < length {
+        return Ok(FrameStatus::Incomplete);
+    }
+
+    let frame = decode_frame(&bytes[..length])?;
+
+    Ok(FrameStatus::Complete { frame, length })
+}
+
+/// Checks the start codes, lengths and checksum of a frame without decoding its contents
+pub fn is_valid_frame(bytes: &[u8]) -> Result<FrameKind, RdmError> {
+    match bytes {
+        [RDM_START_CODE_BYTE, RDM_SUB_START_CODE_BYTE, message_length, ..] => {
+            let message_length = *message_length as usize;
+
+            if message_length < 24 {
+                return Err(RdmError::InvalidMessageLength(message_length as u8));
+            }
+
+            if bytes.len() < message_length + 2 {
+                return Err(RdmError::InvalidFrameLength(bytes.len() as u8));
+            }
+
+            let parameter_data_length = bytes[23];
+
+            if message_length != 24 + parameter_data_length as usize {
+                return Err(RdmError::InvalidParameterDataLength(parameter_data_length));
+            }
+
+            let packet_checksum =
+                u16::from_be_bytes([bytes[message_length], bytes[message_length + 1]]);
+
+            let decoded_checksum = bsd_16_crc(&bytes[..message_length]);
+
+            if decoded_checksum != packet_checksum {
+                return Err(RdmError::InvalidChecksum(decoded_checksum, packet_checksum));
+            }
+
+            Ok(FrameKind::Rdm)
+        }
+        [DISCOVERY_UNIQUE_BRANCH_PREAMBLE_BYTE | DISCOVERY_UNIQUE_BRANCH_PREAMBLE_SEPARATOR_BYTE, ..] =>
+        {
+            // Up to 7 preamble bytes may precede the separator
+            let Some(separator_index) = bytes
+                .iter()
+                .take(8)
+                .position(|&byte| byte == DISCOVERY_UNIQUE_BRANCH_PREAMBLE_SEPARATOR_BYTE)
+            else {
+                return Err(RdmError::InvalidDiscoveryUniqueBranchPreamble);
+            };
+
+            let Some(euid) = bytes.get(separator_index + 1..separator_index + 17) else {
+                return Err(RdmError::InvalidFrameLength(bytes.len() as u8));
+            };
+
+            decode_euid(euid.try_into()?)?;
+
+            Ok(FrameKind::DiscoveryUniqueBranch)
+        }
+        [start_code, ..] => Err(RdmError::InvalidStartCode(*start_code)),
+        [] => Err(RdmError::InvalidFrameLength(0)),
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -156,6 +461,11 @@ pub enum SubDeviceId {
     AllDevices,
 }
 
+impl SubDeviceId {
+    /// Sub-devices are numbered from 1, up to a maximum of 512 per device
+    pub const MAX_SUB_DEVICES: u16 = 0x0200;
+}
+
 impl From<u16> for SubDeviceId {
     fn from(value: u16) -> SubDeviceId {
         match value {
@@ -180,6 +490,28 @@ impl From<SubDeviceId> for u16 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn should_write_device_uid_big_endian() {
+        let mut buf = [0xff; 8];
+
+        DeviceUID::new(0x0102, 0x03040506)
+            .write_be(&mut buf[1..])
+            .unwrap();
+
+        assert_eq!(buf, [0xff, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0xff]);
+        assert_eq!(
+            DeviceUID::new(0x0102, 0x03040506).write_be(&mut buf[3..]),
+            Err(RdmError::TryFromSliceError)
+        );
+    }
+
+    #[test]
+    fn should_detect_broadcast_device_uid() {
+        assert!(DeviceUID::broadcast_to_all_devices().is_broadcast());
+        assert!(DeviceUID::broadcast_to_devices_with_manufacturer_id(0x0102).is_broadcast());
+        assert!(!DeviceUID::new(0x0102, 0x03040506).is_broadcast());
+    }
+
     #[test]
     fn should_create_static_device_uid() {
         let device_uid = DeviceUID::new(0x1234, 0x56789abc);
@@ -223,4 +555,276 @@ mod tests {
             [0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc]
         );
     }
+
+    #[test]
+    fn should_convert_device_uid_little_endian() {
+        let device_uid = DeviceUID::new(0x1234, 0x56789abc);
+
+        assert_eq!(
+            device_uid.to_le_bytes(),
+            [0xbc, 0x9a, 0x78, 0x56, 0x34, 0x12]
+        );
+        assert_eq!(
+            DeviceUID::from_le_bytes([0xbc, 0x9a, 0x78, 0x56, 0x34, 0x12]),
+            device_uid
+        );
+    }
+
+    #[test]
+    fn should_hash_device_uid() {
+        assert_eq!(
+            DeviceUID::new(0x1234, 0x56789abc).fnv_hash(),
+            0x222451621836c323
+        );
+        assert_ne!(
+            DeviceUID::new(0x1234, 0x56789abc).fnv_hash(),
+            DeviceUID::new(0x1234, 0x56789abd).fnv_hash()
+        );
+    }
+
+    #[test]
+    fn should_encode_and_decode_euid() {
+        let uid = DeviceUID::new(0x0102, 0x03040506);
+
+        let euid = encode_euid(uid);
+
+        assert_eq!(
+            euid,
+            [
+                0xab, 0x55, // Manufacturer ID 1
+                0xaa, 0x57, // Manufacturer ID 0
+                0xab, 0x57, // Device ID 3
+                0xae, 0x55, // Device ID 2
+                0xaf, 0x55, // Device ID 1
+                0xae, 0x57, // Device ID 0
+                0xae, 0x57, // Checksum 1
+                0xaf, 0x5f, // Checksum 0
+            ]
+        );
+        assert_eq!(decode_euid(&euid), Ok(uid));
+    }
+
+    #[test]
+    fn should_not_decode_euid_with_invalid_checksum() {
+        let mut euid = encode_euid(DeviceUID::new(0x0102, 0x03040506));
+        euid[15] = 0x55;
+
+        assert!(matches!(
+            decode_euid(&euid),
+            Err(RdmError::InvalidChecksum(..))
+        ));
+    }
+
+    #[test]
+    fn should_check_frame_start_bytes() {
+        assert!(has_rdm_start(&[0xcc, 0x01, 0x18]));
+        assert!(!has_rdm_start(&[0xcc]));
+        assert!(!has_rdm_start(&[0xcc, 0x02]));
+        assert!(!has_rdm_start(&[]));
+
+        assert!(has_discovery_start(&[0xfe, 0xfe]));
+        assert!(has_discovery_start(&[0xaa]));
+        assert!(!has_discovery_start(&[0xcc, 0x01]));
+        assert!(!has_discovery_start(&[]));
+    }
+
+    #[test]
+    fn should_decode_frame_as_request_or_response() {
+        let request = [
+            0xcc, // Start Code
+            0x01, // Sub Start Code
+            0x18, // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x01, // Port ID
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
+            0x20, // Command Class = GetCommand
+            0x10, 0x00, // Parameter ID = Identify Device
+            0x00, // PDL
+            0x01, 0x40, // Checksum
+        ];
+
+        let Ok(RdmFrame::Request(decoded)) = decode_frame(&request) else {
+            panic!("expected request frame");
+        };
+
+        assert_eq!(
+            decoded.parameter_id(),
+            parameter::ParameterId::IdentifyDevice
+        );
+
+        let mut response = request;
+        response[16] = 0x00; // Response Type = Ack
+        response[20] = 0x21; // Command Class = GetCommandResponse
+
+        assert!(matches!(
+            decode_frame(&response),
+            Ok(RdmFrame::Response(RdmResponse::RdmFrame(_)))
+        ));
+
+        assert!(matches!(
+            decode_frame(&encode_euid(DeviceUID::new(0x0102, 0x03040506))),
+            Err(RdmError::InvalidStartCode(_))
+        ));
+        assert_eq!(
+            decode_frame(&request[..20]),
+            Err(RdmError::InvalidFrameLength(20))
+        );
+    }
+
+    #[test]
+    fn should_decode_partial_frame() {
+        let request = [
+            0xcc, // Start Code
+            0x01, // Sub Start Code
+            0x18, // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x01, // Port ID
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
+            0x20, // Command Class = GetCommand
+            0x10, 0x00, // Parameter ID = Identify Device
+            0x00, // PDL
+            0x01, 0x40, // Checksum
+            0xcc, // Start Code of the next frame
+        ];
+
+        assert_eq!(decode_partial_frame(&[]), Ok(FrameStatus::Incomplete));
+        assert_eq!(
+            decode_partial_frame(&request[..2]),
+            Ok(FrameStatus::Incomplete)
+        );
+        assert_eq!(
+            decode_partial_frame(&request[..25]),
+            Ok(FrameStatus::Incomplete)
+        );
+        assert!(matches!(
+            decode_partial_frame(&request),
+            Ok(FrameStatus::Complete {
+                frame: RdmFrame::Request(_),
+                length: 26
+            })
+        ));
+        assert_eq!(
+            decode_partial_frame(&request[1..]),
+            Err(RdmError::InvalidStartCode(0x01))
+        );
+
+        let discovery_response =
+            response::DiscoveryUniqueBranchFrameResponse(DeviceUID::new(0x0102, 0x03040506))
+                .encode();
+
+        assert_eq!(
+            decode_partial_frame(&discovery_response[..5]),
+            Ok(FrameStatus::Incomplete)
+        );
+        assert_eq!(
+            decode_partial_frame(&discovery_response[..23]),
+            Ok(FrameStatus::Incomplete)
+        );
+        assert!(matches!(
+            decode_partial_frame(&discovery_response),
+            Ok(FrameStatus::Complete {
+                frame: RdmFrame::Response(RdmResponse::DiscoveryUniqueBranchFrame(_)),
+                length: 24
+            })
+        ));
+        assert_eq!(
+            decode_partial_frame(&[0xfe, 0xfe, 0x00]),
+            Err(RdmError::InvalidDiscoveryUniqueBranchPreamble)
+        );
+    }
+
+    #[test]
+    fn should_chunk_parameter_data_to_fit_frames() {
+        assert_eq!(
+            max_parameter_data_for_frame(),
+            MAX_RDM_PARAMETER_DATA_LENGTH
+        );
+
+        let data = [0xff; 500];
+
+        let mut chunks = chunk_parameter_data(&data);
+
+        assert_eq!(chunks.next().map(<[u8]>::len), Some(231));
+        assert_eq!(chunks.next().map(<[u8]>::len), Some(231));
+        assert_eq!(chunks.next().map(<[u8]>::len), Some(38));
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunk_parameter_data(&[]).next(), None);
+    }
+
+    #[test]
+    fn should_contain_responder_delays_in_response_window() {
+        assert!(response_window_us().contains(&RESPONDER_MIN_DELAY_US));
+        assert!(response_window_us().contains(&RESPONDER_MAX_DELAY_US));
+        assert!(!response_window_us().contains(&175));
+        assert!(!response_window_us().contains(&2001));
+    }
+
+    #[test]
+    fn should_validate_frame_kind() {
+        let mut frame = [
+            0xcc, // Start Code
+            0x01, // Sub Start Code
+            0x18, // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x01, // Port ID
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
+            0x20, // Command Class = GetCommand
+            0x10, 0x00, // Parameter ID = Identify Device
+            0x00, // PDL
+            0x01, 0x40, // Checksum
+        ];
+
+        assert_eq!(is_valid_frame(&frame), Ok(FrameKind::Rdm));
+        assert_eq!(
+            is_valid_frame(&frame[..25]),
+            Err(RdmError::InvalidFrameLength(25))
+        );
+
+        frame[25] = 0x41;
+
+        assert_eq!(
+            is_valid_frame(&frame),
+            Err(RdmError::InvalidChecksum(0x0140, 0x0141))
+        );
+
+        frame[23] = 0x01;
+
+        assert_eq!(
+            is_valid_frame(&frame),
+            Err(RdmError::InvalidParameterDataLength(0x01))
+        );
+
+        let mut dub = [0xfe; 24];
+        dub[7] = 0xaa;
+        dub[8..].copy_from_slice(&encode_euid(DeviceUID::new(0x0102, 0x03040506)));
+
+        assert_eq!(is_valid_frame(&dub), Ok(FrameKind::DiscoveryUniqueBranch));
+        assert_eq!(
+            is_valid_frame(&dub[5..]),
+            Ok(FrameKind::DiscoveryUniqueBranch)
+        );
+        assert_eq!(
+            is_valid_frame(&dub[..20]),
+            Err(RdmError::InvalidFrameLength(20))
+        );
+        assert_eq!(
+            is_valid_frame(&[0xfe; 24]),
+            Err(RdmError::InvalidDiscoveryUniqueBranchPreamble)
+        );
+
+        assert_eq!(
+            is_valid_frame(&[0x17]),
+            Err(RdmError::InvalidStartCode(0x17))
+        );
+        assert_eq!(is_valid_frame(&[]), Err(RdmError::InvalidFrameLength(0)));
+    }
 }
@@ -1,14 +1,15 @@
-use super::{RdmError, SubDeviceId};
+use super::{CommandClass, RdmError, SubDeviceId};
 use core::{
     fmt,
     net::{Ipv4Addr, Ipv6Addr},
+    ops::RangeInclusive,
     result::Result,
 };
 
 #[cfg(not(feature = "alloc"))]
 use core::str::FromStr;
 #[cfg(not(feature = "alloc"))]
-use heapless::{String, Vec};
+use heapless::String;
 
 #[cfg(feature = "alloc")]
 pub fn decode_string_bytes(bytes: &[u8]) -> Result<String, RdmError> {
@@ -21,15 +22,23 @@ pub fn decode_string_bytes(bytes: &[u8]) -> Result<String, RdmError> {
     }
 }
 
+/// Strips at the first NUL and truncates to the capacity `N`, as some devices exceed the
+/// maximum string lengths defined in the spec
 #[cfg(not(feature = "alloc"))]
 pub fn decode_string_bytes<const N: usize>(bytes: &[u8]) -> Result<String<N>, RdmError> {
-    let utf8 = String::<N>::from_utf8(Vec::<u8, N>::from_slice(bytes).unwrap())?;
+    let bytes = bytes.split(|&byte| byte == 0).next().unwrap_or_default();
+    let bytes = &bytes[..bytes.len().min(N)];
 
-    if utf8.contains(char::from(0)) {
-        Ok(String::<N>::from_str(utf8.split_once(char::from(0)).unwrap().0).unwrap())
-    } else {
-        Ok(utf8)
-    }
+    let utf8 = match core::str::from_utf8(bytes) {
+        Ok(utf8) => utf8,
+        // Truncation may split a multi-byte character, drop the incomplete character
+        Err(error) if error.error_len().is_none() => {
+            core::str::from_utf8(&bytes[..error.valid_up_to()])?
+        }
+        Err(error) => return Err(error.into()),
+    };
+
+    Ok(String::<N>::from_str(utf8).unwrap())
 }
 
 #[non_exhaustive]
@@ -151,6 +160,239 @@ pub enum ParameterId {
     Unsupported(u16),
 }
 
+impl ParameterId {
+    /// Parameters whose GET_COMMAND_RESPONSE has a fixed, non-empty layout or prefix, an ACK with
+    /// no parameter data for one of these is a protocol violation
+    pub fn requires_get_response_data(&self) -> bool {
+        matches!(
+            self,
+            // E1.20
+            Self::ProxiedDeviceCount
+                | Self::CommsStatus
+                | Self::SubDeviceIdStatusReportThreshold
+                | Self::ParameterDescription
+                | Self::DeviceInfo
+                | Self::FactoryDefaults
+                | Self::Language
+                | Self::BootSoftwareVersionId
+                | Self::DmxPersonality
+                | Self::DmxPersonalityDescription
+                | Self::DmxStartAddress
+                | Self::SlotDescription
+                | Self::SensorDefinition
+                | Self::SensorValue
+                | Self::DeviceHours
+                | Self::LampHours
+                | Self::LampStrikes
+                | Self::LampState
+                | Self::LampOnMode
+                | Self::DevicePowerCycles
+                | Self::DisplayInvert
+                | Self::DisplayLevel
+                | Self::PanInvert
+                | Self::TiltInvert
+                | Self::PanTiltSwap
+                | Self::RealTimeClock
+                | Self::IdentifyDevice
+                | Self::PowerState
+                | Self::PerformSelfTest
+                | Self::SelfTestDescription
+                | Self::PresetPlayback
+                // E1.37-1
+                | Self::DmxBlockAddress
+                | Self::DmxFailMode
+                | Self::DmxStartupMode
+                | Self::DimmerInfo
+                | Self::MinimumLevel
+                | Self::MaximumLevel
+                | Self::Curve
+                | Self::CurveDescription
+                | Self::OutputResponseTime
+                | Self::OutputResponseTimeDescription
+                | Self::ModulationFrequency
+                | Self::ModulationFrequencyDescription
+                | Self::BurnIn
+                | Self::LockPin
+                | Self::LockState
+                | Self::LockStateDescription
+                | Self::IdentifyMode
+                | Self::PresetInfo
+                | Self::PresetStatus
+                | Self::PresetMergeMode
+                | Self::PowerOnSelfTest
+                // E1.37-2
+                | Self::InterfaceLabel
+                | Self::InterfaceHardwareAddressType1
+                | Self::IpV4DhcpMode
+                | Self::IpV4ZeroConfMode
+                | Self::IpV4CurrentAddress
+                | Self::IpV4StaticAddress
+                | Self::IpV4DefaultRoute
+                | Self::DnsIpV4NameServer
+                // E1.37-7
+                | Self::EndpointList
+                | Self::EndpointListChange
+                | Self::IdentifyEndpoint
+                | Self::EndpointToUniverse
+                | Self::EndpointMode
+                | Self::EndpointLabel
+                | Self::RdmTrafficEnable
+                | Self::DiscoveryState
+                | Self::BackgroundDiscovery
+                | Self::EndpointTiming
+                | Self::EndpointTimingDescription
+                | Self::EndpointResponders
+                | Self::EndpointResponderListChange
+                | Self::BindingControlFields
+                | Self::BackgroundQueuedStatusPolicy
+                | Self::BackgroundQueuedStatusPolicyDescription
+                // E1.33
+                | Self::ComponentScope
+                | Self::TcpCommsStatus
+                | Self::BrokerStatus
+        )
+    }
+
+    /// The request command classes defined for the parameter, `None` for manufacturer-specific
+    /// and unsupported parameters
+    pub fn supported_command_classes(&self) -> Option<&'static [CommandClass]> {
+        let command_classes: &'static [CommandClass] = match self {
+            // E1.20
+            Self::DiscUniqueBranch
+            | Self::DiscMute
+            | Self::DiscUnMute => &[CommandClass::DiscoveryCommand],
+            // E1.20
+            Self::ProxiedDevices
+            | Self::ProxiedDeviceCount
+            | Self::QueuedMessage
+            | Self::StatusMessages
+            | Self::StatusIdDescription
+            | Self::SupportedParameters
+            | Self::ParameterDescription
+            | Self::DeviceInfo
+            | Self::ProductDetailIdList
+            | Self::DeviceModelDescription
+            | Self::ManufacturerLabel
+            | Self::LanguageCapabilities
+            | Self::SoftwareVersionLabel
+            | Self::BootSoftwareVersionId
+            | Self::BootSoftwareVersionLabel
+            | Self::DmxPersonalityDescription
+            | Self::SlotInfo
+            | Self::SlotDescription
+            | Self::DefaultSlotValue
+            | Self::SensorDefinition
+            | Self::SelfTestDescription
+            // E1.37-1
+            | Self::DimmerInfo
+            | Self::CurveDescription
+            | Self::OutputResponseTimeDescription
+            | Self::ModulationFrequencyDescription
+            | Self::LockStateDescription
+            | Self::PresetInfo
+            // E1.37-2
+            | Self::ListInterfaces
+            | Self::InterfaceLabel
+            | Self::InterfaceHardwareAddressType1
+            | Self::IpV4CurrentAddress
+            // E1.37-7
+            | Self::EndpointList
+            | Self::EndpointListChange
+            | Self::EndpointTimingDescription
+            | Self::EndpointResponders
+            | Self::EndpointResponderListChange
+            | Self::BindingControlFields
+            | Self::BackgroundQueuedStatusPolicyDescription => &[CommandClass::GetCommand],
+            // E1.20
+            Self::ClearStatusId
+            | Self::RecordSensors
+            | Self::ResetDevice
+            | Self::CapturePreset
+            // E1.37-2
+            | Self::InterfaceRenewDhcp
+            | Self::InterfaceReleaseDhcp
+            | Self::InterfaceApplyConfiguration => &[CommandClass::SetCommand],
+            // E1.20
+            Self::CommsStatus
+            | Self::SubDeviceIdStatusReportThreshold
+            | Self::DeviceLabel
+            | Self::FactoryDefaults
+            | Self::Language
+            | Self::DmxPersonality
+            | Self::DmxStartAddress
+            | Self::SensorValue
+            | Self::DeviceHours
+            | Self::LampHours
+            | Self::LampStrikes
+            | Self::LampState
+            | Self::LampOnMode
+            | Self::DevicePowerCycles
+            | Self::DisplayInvert
+            | Self::DisplayLevel
+            | Self::PanInvert
+            | Self::TiltInvert
+            | Self::PanTiltSwap
+            | Self::RealTimeClock
+            | Self::IdentifyDevice
+            | Self::PowerState
+            | Self::PerformSelfTest
+            | Self::PresetPlayback
+            // E1.37-1
+            | Self::DmxBlockAddress
+            | Self::DmxFailMode
+            | Self::DmxStartupMode
+            | Self::MinimumLevel
+            | Self::MaximumLevel
+            | Self::Curve
+            | Self::OutputResponseTime
+            | Self::ModulationFrequency
+            | Self::BurnIn
+            | Self::LockPin
+            | Self::LockState
+            | Self::IdentifyMode
+            | Self::PresetStatus
+            | Self::PresetMergeMode
+            | Self::PowerOnSelfTest
+            // E1.37-2
+            | Self::IpV4DhcpMode
+            | Self::IpV4ZeroConfMode
+            | Self::IpV4StaticAddress
+            | Self::IpV4DefaultRoute
+            | Self::DnsIpV4NameServer
+            | Self::DnsHostName
+            | Self::DnsDomainName
+            // E1.37-7
+            | Self::IdentifyEndpoint
+            | Self::EndpointToUniverse
+            | Self::EndpointMode
+            | Self::EndpointLabel
+            | Self::RdmTrafficEnable
+            | Self::DiscoveryState
+            | Self::BackgroundDiscovery
+            | Self::EndpointTiming
+            | Self::BackgroundQueuedStatusPolicy
+            // E1.33
+            | Self::ComponentScope
+            | Self::SearchDomain
+            | Self::TcpCommsStatus
+            | Self::BrokerStatus => &[CommandClass::GetCommand, CommandClass::SetCommand],
+            Self::ManufacturerSpecific(_) | Self::Unsupported(_) => return None,
+        };
+
+        Some(command_classes)
+    }
+
+    /// Builds a `ManufacturerSpecific` parameter id, rejecting ids outside of the manufacturer
+    /// specific range 0x8000..=0xffdf so it can't collide with a standard parameter id
+    pub fn manufacturer_specific(parameter_id: u16) -> Result<Self, RdmError> {
+        if !(0x8000..=0xffdf).contains(&parameter_id) {
+            return Err(RdmError::InvalidParameterId(parameter_id));
+        }
+
+        Ok(Self::ManufacturerSpecific(parameter_id))
+    }
+}
+
 impl From<u16> for ParameterId {
     fn from(value: u16) -> Self {
         match value {
@@ -262,10 +504,10 @@ impl From<u16> for ParameterId {
             0x090e => Self::BackgroundQueuedStatusPolicy,
             0x090f => Self::BackgroundQueuedStatusPolicyDescription,
             // E1.33
-            0x8000 => Self::ComponentScope,
-            0x8001 => Self::SearchDomain,
-            0x8002 => Self::TcpCommsStatus,
-            0x8003 => Self::BrokerStatus,
+            0x0800 => Self::ComponentScope,
+            0x0801 => Self::SearchDomain,
+            0x0802 => Self::TcpCommsStatus,
+            0x0803 => Self::BrokerStatus,
             n if (0x8000..=0xffdf).contains(&n) => Self::ManufacturerSpecific(n),
             n => Self::Unsupported(n),
         }
@@ -393,6 +635,131 @@ impl From<ParameterId> for u16 {
     }
 }
 
+impl fmt::Display for ParameterId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            // E1.20
+            Self::DiscUniqueBranch => write!(f, "DISC_UNIQUE_BRANCH"),
+            Self::DiscMute => write!(f, "DISC_MUTE"),
+            Self::DiscUnMute => write!(f, "DISC_UN_MUTE"),
+            Self::ProxiedDevices => write!(f, "PROXIED_DEVICES"),
+            Self::ProxiedDeviceCount => write!(f, "PROXIED_DEVICE_COUNT"),
+            Self::CommsStatus => write!(f, "COMMS_STATUS"),
+            Self::QueuedMessage => write!(f, "QUEUED_MESSAGE"),
+            Self::StatusMessages => write!(f, "STATUS_MESSAGES"),
+            Self::StatusIdDescription => write!(f, "STATUS_ID_DESCRIPTION"),
+            Self::ClearStatusId => write!(f, "CLEAR_STATUS_ID"),
+            Self::SubDeviceIdStatusReportThreshold => {
+                write!(f, "SUB_DEVICE_STATUS_REPORT_THRESHOLD")
+            }
+            Self::SupportedParameters => write!(f, "SUPPORTED_PARAMETERS"),
+            Self::ParameterDescription => write!(f, "PARAMETER_DESCRIPTION"),
+            Self::DeviceInfo => write!(f, "DEVICE_INFO"),
+            Self::ProductDetailIdList => write!(f, "PRODUCT_DETAIL_ID_LIST"),
+            Self::DeviceModelDescription => write!(f, "DEVICE_MODEL_DESCRIPTION"),
+            Self::ManufacturerLabel => write!(f, "MANUFACTURER_LABEL"),
+            Self::DeviceLabel => write!(f, "DEVICE_LABEL"),
+            Self::FactoryDefaults => write!(f, "FACTORY_DEFAULTS"),
+            Self::LanguageCapabilities => write!(f, "LANGUAGE_CAPABILITIES"),
+            Self::Language => write!(f, "LANGUAGE"),
+            Self::SoftwareVersionLabel => write!(f, "SOFTWARE_VERSION_LABEL"),
+            Self::BootSoftwareVersionId => write!(f, "BOOT_SOFTWARE_VERSION_ID"),
+            Self::BootSoftwareVersionLabel => write!(f, "BOOT_SOFTWARE_VERSION_LABEL"),
+            Self::DmxPersonality => write!(f, "DMX_PERSONALITY"),
+            Self::DmxPersonalityDescription => write!(f, "DMX_PERSONALITY_DESCRIPTION"),
+            Self::DmxStartAddress => write!(f, "DMX_START_ADDRESS"),
+            Self::SlotInfo => write!(f, "SLOT_INFO"),
+            Self::SlotDescription => write!(f, "SLOT_DESCRIPTION"),
+            Self::DefaultSlotValue => write!(f, "DEFAULT_SLOT_VALUE"),
+            Self::SensorDefinition => write!(f, "SENSOR_DEFINITION"),
+            Self::SensorValue => write!(f, "SENSOR_VALUE"),
+            Self::RecordSensors => write!(f, "RECORD_SENSORS"),
+            Self::DeviceHours => write!(f, "DEVICE_HOURS"),
+            Self::LampHours => write!(f, "LAMP_HOURS"),
+            Self::LampStrikes => write!(f, "LAMP_STRIKES"),
+            Self::LampState => write!(f, "LAMP_STATE"),
+            Self::LampOnMode => write!(f, "LAMP_ON_MODE"),
+            Self::DevicePowerCycles => write!(f, "DEVICE_POWER_CYCLES"),
+            Self::DisplayInvert => write!(f, "DISPLAY_INVERT"),
+            Self::DisplayLevel => write!(f, "DISPLAY_LEVEL"),
+            Self::PanInvert => write!(f, "PAN_INVERT"),
+            Self::TiltInvert => write!(f, "TILT_INVERT"),
+            Self::PanTiltSwap => write!(f, "PAN_TILT_SWAP"),
+            Self::RealTimeClock => write!(f, "REAL_TIME_CLOCK"),
+            Self::IdentifyDevice => write!(f, "IDENTIFY_DEVICE"),
+            Self::ResetDevice => write!(f, "RESET_DEVICE"),
+            Self::PowerState => write!(f, "POWER_STATE"),
+            Self::PerformSelfTest => write!(f, "PERFORM_SELFTEST"),
+            Self::SelfTestDescription => write!(f, "SELF_TEST_DESCRIPTION"),
+            Self::CapturePreset => write!(f, "CAPTURE_PRESET"),
+            Self::PresetPlayback => write!(f, "PRESET_PLAYBACK"),
+            // E1.37-1
+            Self::DmxBlockAddress => write!(f, "DMX_BLOCK_ADDRESS"),
+            Self::DmxFailMode => write!(f, "DMX_FAIL_MODE"),
+            Self::DmxStartupMode => write!(f, "DMX_STARTUP_MODE"),
+            Self::DimmerInfo => write!(f, "DIMMER_INFO"),
+            Self::MinimumLevel => write!(f, "MINIMUM_LEVEL"),
+            Self::MaximumLevel => write!(f, "MAXIMUM_LEVEL"),
+            Self::Curve => write!(f, "CURVE"),
+            Self::CurveDescription => write!(f, "CURVE_DESCRIPTION"),
+            Self::OutputResponseTime => write!(f, "OUTPUT_RESPONSE_TIME"),
+            Self::OutputResponseTimeDescription => write!(f, "OUTPUT_RESPONSE_TIME_DESCRIPTION"),
+            Self::ModulationFrequency => write!(f, "MODULATION_FREQUENCY"),
+            Self::ModulationFrequencyDescription => write!(f, "MODULATION_FREQUENCY_DESCRIPTION"),
+            Self::BurnIn => write!(f, "BURN_IN"),
+            Self::LockPin => write!(f, "LOCK_PIN"),
+            Self::LockState => write!(f, "LOCK_STATE"),
+            Self::LockStateDescription => write!(f, "LOCK_STATE_DESCRIPTION"),
+            Self::IdentifyMode => write!(f, "IDENTIFY_MODE"),
+            Self::PresetInfo => write!(f, "PRESET_INFO"),
+            Self::PresetStatus => write!(f, "PRESET_STATUS"),
+            Self::PresetMergeMode => write!(f, "PRESET_MERGEMODE"),
+            Self::PowerOnSelfTest => write!(f, "POWER_ON_SELF_TEST"),
+            // E1.37-2
+            Self::ListInterfaces => write!(f, "LIST_INTERFACES"),
+            Self::InterfaceLabel => write!(f, "INTERFACE_LABEL"),
+            Self::InterfaceHardwareAddressType1 => write!(f, "INTERFACE_HARDWARE_ADDRESS_TYPE1"),
+            Self::IpV4DhcpMode => write!(f, "IPV4_DHCP_MODE"),
+            Self::IpV4ZeroConfMode => write!(f, "IPV4_ZEROCONF_MODE"),
+            Self::IpV4CurrentAddress => write!(f, "IPV4_CURRENT_ADDRESS"),
+            Self::IpV4StaticAddress => write!(f, "IPV4_STATIC_ADDRESS"),
+            Self::InterfaceRenewDhcp => write!(f, "INTERFACE_RENEW_DHCP"),
+            Self::InterfaceReleaseDhcp => write!(f, "INTERFACE_RELEASE_DHCP"),
+            Self::InterfaceApplyConfiguration => write!(f, "INTERFACE_APPLY_CONFIGURATION"),
+            Self::IpV4DefaultRoute => write!(f, "IPV4_DEFAULT_ROUTE"),
+            Self::DnsIpV4NameServer => write!(f, "DNS_IPV4_NAME_SERVER"),
+            Self::DnsHostName => write!(f, "DNS_HOSTNAME"),
+            Self::DnsDomainName => write!(f, "DNS_DOMAIN_NAME"),
+            // E1.37-7
+            Self::EndpointList => write!(f, "ENDPOINT_LIST"),
+            Self::EndpointListChange => write!(f, "ENDPOINT_LIST_CHANGE"),
+            Self::IdentifyEndpoint => write!(f, "IDENTIFY_ENDPOINT"),
+            Self::EndpointToUniverse => write!(f, "ENDPOINT_TO_UNIVERSE"),
+            Self::EndpointMode => write!(f, "ENDPOINT_MODE"),
+            Self::EndpointLabel => write!(f, "ENDPOINT_LABEL"),
+            Self::RdmTrafficEnable => write!(f, "RDM_TRAFFIC_ENABLE"),
+            Self::DiscoveryState => write!(f, "DISCOVERY_STATE"),
+            Self::BackgroundDiscovery => write!(f, "BACKGROUND_DISCOVERY"),
+            Self::EndpointTiming => write!(f, "ENDPOINT_TIMING"),
+            Self::EndpointTimingDescription => write!(f, "ENDPOINT_TIMING_DESCRIPTION"),
+            Self::EndpointResponders => write!(f, "ENDPOINT_RESPONDERS"),
+            Self::EndpointResponderListChange => write!(f, "ENDPOINT_RESPONDER_LIST_CHANGE"),
+            Self::BindingControlFields => write!(f, "BINDING_CONTROL_FIELDS"),
+            Self::BackgroundQueuedStatusPolicy => write!(f, "BACKGROUND_QUEUED_STATUS_POLICY"),
+            Self::BackgroundQueuedStatusPolicyDescription => {
+                write!(f, "BACKGROUND_QUEUED_STATUS_POLICY_DESCRIPTION")
+            }
+            // E1.33
+            Self::ComponentScope => write!(f, "COMPONENT_SCOPE"),
+            Self::SearchDomain => write!(f, "SEARCH_DOMAIN"),
+            Self::TcpCommsStatus => write!(f, "TCP_COMMS_STATUS"),
+            Self::BrokerStatus => write!(f, "BROKER_STATUS"),
+            Self::ManufacturerSpecific(pid) => write!(f, "MANUFACTURER_SPECIFIC({:#06x})", pid),
+            Self::Unsupported(pid) => write!(f, "UNSUPPORTED({:#06x})", pid),
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct ProtocolVersion {
     pub major: u8,
@@ -417,6 +784,34 @@ impl fmt::Display for ProtocolVersion {
     }
 }
 
+/// The E1.20 spec leaves the layout of the 32-bit boot software version id to the
+/// manufacturer, so the raw value is kept alongside a big-endian byte split, which
+/// is how most vendors pack their major / minor / patch / build numbers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BootSoftwareVersion(pub u32);
+
+impl BootSoftwareVersion {
+    pub fn new(value: u32) -> Self {
+        Self(value)
+    }
+
+    pub fn to_bytes(&self) -> [u8; 4] {
+        self.0.to_be_bytes()
+    }
+}
+
+impl From<u32> for BootSoftwareVersion {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<BootSoftwareVersion> for u32 {
+    fn from(value: BootSoftwareVersion) -> Self {
+        value.0
+    }
+}
+
 // E1.20 2025 Table A-6
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum ProductDetail {
@@ -755,7 +1150,51 @@ impl From<ParameterDataType> for u8 {
     }
 }
 
+impl ParameterDataType {
+    /// Interprets a raw minimum, maximum or default value from PARAMETER_DESCRIPTION, byte and
+    /// word sized values are held in the least significant bytes
+    pub fn convert(&self, value: [u8; 4]) -> ConvertedParameterValue {
+        match self {
+            Self::UnsignedByte => ConvertedParameterValue::UnsignedByte(value[3]),
+            Self::SignedByte => ConvertedParameterValue::SignedByte(value[3] as i8),
+            Self::UnsignedWord => {
+                ConvertedParameterValue::UnsignedWord(u16::from_be_bytes([value[2], value[3]]))
+            }
+            Self::SignedWord => {
+                ConvertedParameterValue::SignedWord(i16::from_be_bytes([value[2], value[3]]))
+            }
+            Self::UnsignedDWord => {
+                ConvertedParameterValue::UnsignedDWord(u32::from_be_bytes(value))
+            }
+            Self::SignedDWord => ConvertedParameterValue::SignedDWord(i32::from_be_bytes(value)),
+            Self::BitField | Self::Ascii | Self::NotDefined | Self::ManufacturerSpecific(..) => {
+                ConvertedParameterValue::Raw(value)
+            }
+        }
+    }
+}
+
+impl fmt::Display for ParameterDataType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotDefined => write!(f, "NOT_DEFINED"),
+            Self::BitField => write!(f, "BIT_FIELD"),
+            Self::Ascii => write!(f, "ASCII"),
+            Self::UnsignedByte => write!(f, "UNSIGNED_BYTE"),
+            Self::SignedByte => write!(f, "SIGNED_BYTE"),
+            Self::UnsignedWord => write!(f, "UNSIGNED_WORD"),
+            Self::SignedWord => write!(f, "SIGNED_WORD"),
+            Self::UnsignedDWord => write!(f, "UNSIGNED_DWORD"),
+            Self::SignedDWord => write!(f, "SIGNED_DWORD"),
+            Self::ManufacturerSpecific(data_type) => {
+                write!(f, "MANUFACTURER_SPECIFIC({:#04x})", data_type)
+            }
+        }
+    }
+}
+
 // E1.20 2025 Section 10.4.2
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum ConvertedParameterValue {
     UnsignedByte(u8),
     SignedByte(i8),
@@ -766,6 +1205,20 @@ pub enum ConvertedParameterValue {
     Raw([u8; 4]),
 }
 
+impl fmt::Display for ConvertedParameterValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsignedByte(value) => write!(f, "{}", value),
+            Self::SignedByte(value) => write!(f, "{}", value),
+            Self::UnsignedWord(value) => write!(f, "{}", value),
+            Self::SignedWord(value) => write!(f, "{}", value),
+            Self::UnsignedDWord(value) => write!(f, "{}", value),
+            Self::SignedDWord(value) => write!(f, "{}", value),
+            Self::Raw(value) => write!(f, "{:#010x}", u32::from_be_bytes(*value)),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct ParameterDescription {
     pub parameter_id: u16,
@@ -788,32 +1241,7 @@ impl ParameterDescription {
         parameter_data_type: ParameterDataType,
         value: [u8; 4],
     ) -> Result<ConvertedParameterValue, RdmError> {
-        match parameter_data_type {
-            ParameterDataType::UnsignedByte => Ok(ConvertedParameterValue::UnsignedByte(value[3])),
-            ParameterDataType::SignedByte => {
-                Ok(ConvertedParameterValue::SignedByte(value[3] as i8))
-            }
-            ParameterDataType::UnsignedWord => {
-                Ok(ConvertedParameterValue::UnsignedWord(u16::from_be_bytes([
-                    value[2], value[3],
-                ])))
-            }
-            ParameterDataType::SignedWord => {
-                Ok(ConvertedParameterValue::SignedWord(i16::from_be_bytes([
-                    value[2], value[3],
-                ])))
-            }
-            ParameterDataType::UnsignedDWord => Ok(ConvertedParameterValue::UnsignedDWord(
-                u32::from_be_bytes(value),
-            )),
-            ParameterDataType::SignedDWord => Ok(ConvertedParameterValue::SignedDWord(
-                i32::from_be_bytes(value),
-            )),
-            ParameterDataType::BitField | ParameterDataType::Ascii |
-            ParameterDataType::NotDefined | ParameterDataType::ManufacturerSpecific(..) => {
-                Ok(ConvertedParameterValue::Raw(value))
-            }
-        }
+        Ok(parameter_data_type.convert(value))
     }
 
     pub fn minimum_valid_value(&self) -> Result<ConvertedParameterValue, RdmError> {
@@ -827,6 +1255,45 @@ impl ParameterDescription {
     }
 }
 
+// E1.20 2025 Table 7-3
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DiscControlField(pub u16);
+
+impl DiscControlField {
+    pub const MANAGED_PROXY: u16 = 0x0001;
+    pub const SUB_DEVICE: u16 = 0x0002;
+    pub const BOOT_LOADER: u16 = 0x0004;
+    pub const PROXIED_DEVICE: u16 = 0x0008;
+
+    pub fn is_proxy(&self) -> bool {
+        self.0 & Self::MANAGED_PROXY != 0
+    }
+
+    pub fn has_sub_devices(&self) -> bool {
+        self.0 & Self::SUB_DEVICE != 0
+    }
+
+    pub fn is_boot_loader(&self) -> bool {
+        self.0 & Self::BOOT_LOADER != 0
+    }
+
+    pub fn is_proxied_device(&self) -> bool {
+        self.0 & Self::PROXIED_DEVICE != 0
+    }
+}
+
+impl From<u16> for DiscControlField {
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+
+impl From<DiscControlField> for u16 {
+    fn from(value: DiscControlField) -> Self {
+        value.0
+    }
+}
+
 // E1.20 2025 Table A-4
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum StatusType {
@@ -1291,6 +1758,33 @@ pub struct FadeTimes {
     pub wait_time: u16,
 }
 
+/// Communication error counters reported by COMMS_STATUS.
+///
+/// Each counter is a 16-bit value maintained by the responder, which clears all three when it
+/// receives SET COMMS_STATUS.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct CommsStatus {
+    pub short_message: u16,
+    pub length_mismatch: u16,
+    pub checksum_fail: u16,
+}
+
+impl CommsStatus {
+    pub fn new(short_message: u16, length_mismatch: u16, checksum_fail: u16) -> Self {
+        Self {
+            short_message,
+            length_mismatch,
+            checksum_fail,
+        }
+    }
+
+    /// Sum of all three counters, widened so that it cannot overflow even when every counter
+    /// has reached 0xffff
+    pub fn total_errors(&self) -> u32 {
+        self.short_message as u32 + self.length_mismatch as u32 + self.checksum_fail as u32
+    }
+}
+
 // E1.20 2025 Table B-2
 #[non_exhaustive]
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -2138,6 +2632,58 @@ impl TryFrom<u8> for SensorUnitPrefix {
     }
 }
 
+impl SensorUnitPrefix {
+    /// The power of ten the prefix multiplies a value by
+    pub fn exponent(&self) -> i8 {
+        match self {
+            Self::None => 0,
+            Self::Deci => -1,
+            Self::Centi => -2,
+            Self::Milli => -3,
+            Self::Micro => -6,
+            Self::Nano => -9,
+            Self::Pico => -12,
+            Self::Femto => -15,
+            Self::Atto => -18,
+            Self::Zepto => -21,
+            Self::Yocto => -24,
+            Self::Deca => 1,
+            Self::Hecto => 2,
+            Self::Kilo => 3,
+            Self::Mega => 6,
+            Self::Giga => 9,
+            Self::Terra => 12,
+            Self::Peta => 15,
+            Self::Exa => 18,
+            Self::Zetta => 21,
+            Self::Yotta => 24,
+        }
+    }
+
+    /// Applies the prefix to a value, e.g. 25 with `Milli` is 0.025
+    pub fn scale(&self, value: i16) -> f64 {
+        let exponent = self.exponent();
+        let factor = (0..exponent.unsigned_abs()).fold(1.0, |factor, _| factor * 10.0);
+
+        if exponent < 0 {
+            value as f64 / factor
+        } else {
+            value as f64 * factor
+        }
+    }
+}
+
+/// A DMX personality and its footprint, from DMX_PERSONALITY_DESCRIPTION
+#[derive(Clone, Debug, PartialEq)]
+pub struct Personality {
+    pub id: u8,
+    pub dmx_slots_required: u16,
+    #[cfg(feature = "alloc")]
+    pub description: String,
+    #[cfg(not(feature = "alloc"))]
+    pub description: String<32>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct SensorDefinition {
     pub id: u8,
@@ -2156,6 +2702,26 @@ pub struct SensorDefinition {
     pub description: String<32>,
 }
 
+impl SensorDefinition {
+    /// The range of values the sensor can report, before the prefix is applied
+    pub fn range(&self) -> RangeInclusive<i32> {
+        self.range_minimum_value as i32..=self.range_maximum_value as i32
+    }
+
+    /// The range of values in normal operation, before the prefix is applied
+    pub fn normal_range(&self) -> RangeInclusive<i32> {
+        self.normal_minimum_value as i32..=self.normal_maximum_value as i32
+    }
+
+    pub fn scaled_range(&self) -> RangeInclusive<f64> {
+        self.prefix.scale(self.range_minimum_value)..=self.prefix.scale(self.range_maximum_value)
+    }
+
+    pub fn scaled_normal_range(&self) -> RangeInclusive<f64> {
+        self.prefix.scale(self.normal_minimum_value)..=self.prefix.scale(self.normal_maximum_value)
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct SensorValue {
     pub sensor_id: u8,
@@ -2183,7 +2749,7 @@ impl SensorValue {
     }
 }
 
-// E1.31-1 2012r2022 Section 3.2
+// E1.37-1 2012r2022 Section 3.2
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum IdentifyMode {
     Quiet = 0x00,
@@ -2264,12 +2830,28 @@ impl TryFrom<u16> for PinCode {
 }
 
 // E1.37-1 2012r2022 Section 5.2
+/// Fade time limits reported in PRESET_INFO, in tenths of a second. 0xffff means the time is not
+/// supported.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum SupportedTimes {
     NotSupported,
     Time(u16),
 }
 
+impl SupportedTimes {
+    pub fn is_supported(&self) -> bool {
+        matches!(self, Self::Time(_))
+    }
+
+    /// The time in whole seconds, rounded down
+    pub fn as_seconds(&self) -> Option<u16> {
+        match self {
+            Self::NotSupported => None,
+            Self::Time(tenths) => Some(tenths / 10),
+        }
+    }
+}
+
 impl From<u16> for SupportedTimes {
     fn from(value: u16) -> Self {
         match value {
@@ -2288,13 +2870,47 @@ impl From<SupportedTimes> for u16 {
     }
 }
 
+impl fmt::Display for SupportedTimes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotSupported => write!(f, "Not supported"),
+            Self::Time(tenths) => write!(f, "{}.{}s", tenths / 10, tenths % 10),
+        }
+    }
+}
+
 // E1.37-1 2012r2022 Section 3.4, 3.5
+/// Delay and hold times used by DMX_FAIL_MODE and DMX_STARTUP_MODE, in tenths of a second.
+///
+/// On the wire 0x0000 means no delay and 0xffff means infinite, whether a responder accepts an
+/// infinite time is reported by the corresponding flags in PRESET_INFO.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum TimeMode {
     Infinite,
     TenthOfSeconds(u16),
 }
 
+impl TimeMode {
+    pub const fn infinite() -> Self {
+        Self::Infinite
+    }
+
+    pub const fn none() -> Self {
+        Self::TenthOfSeconds(0)
+    }
+
+    /// Saturates at 0xfffe tenths of a second so the value can never be mistaken for infinite
+    pub const fn seconds(seconds: u16) -> Self {
+        let tenths = seconds as u32 * 10;
+
+        if tenths > 0xfffe {
+            Self::TenthOfSeconds(0xfffe)
+        } else {
+            Self::TenthOfSeconds(tenths as u16)
+        }
+    }
+}
+
 impl From<u16> for TimeMode {
     fn from(value: u16) -> Self {
         match value {
@@ -2334,6 +2950,22 @@ impl TryFrom<u8> for DhcpMode {
     }
 }
 
+impl From<DhcpMode> for u8 {
+    fn from(value: DhcpMode) -> Self {
+        value as u8
+    }
+}
+
+impl fmt::Display for DhcpMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Inactive => write!(f, "INACTIVE"),
+            Self::Active => write!(f, "ACTIVE"),
+            Self::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Ipv4Address {
     Unconfigured,
@@ -2843,6 +3475,8 @@ impl From<IdentifyTimeout> for u16 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(not(feature = "alloc"))]
+    use heapless::Vec;
 
     #[test]
     #[cfg(feature = "alloc")]
@@ -2879,4 +3513,191 @@ mod tests {
             String::from_utf8(Vec::<u8, 32>::from_slice(b"early terminated").unwrap()).unwrap()
         );
     }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn should_decode_over_length_string_bytes() {
+        assert_eq!(
+            decode_string_bytes(&b"a personality description longer than 32 characters"[..])
+                .unwrap(),
+            "a personality description longer than 32 characters".to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "alloc"))]
+    fn should_decode_over_length_string_bytes() {
+        assert_eq!(
+            decode_string_bytes::<32>(&b"a personality description longer than 32 characters"[..])
+                .unwrap(),
+            String::<32>::from_str("a personality description longer").unwrap()
+        );
+        assert_eq!(
+            decode_string_bytes::<4>("abc\u{e9}".as_bytes()).unwrap(),
+            String::<4>::from_str("abc").unwrap()
+        );
+        assert_eq!(
+            decode_string_bytes::<4>(&b"ab\0cdef"[..]).unwrap(),
+            String::<4>::from_str("ab").unwrap()
+        );
+    }
+
+    #[test]
+    fn should_split_boot_software_version_into_bytes() {
+        let version = BootSoftwareVersion::from(0x01020304);
+
+        assert_eq!(version.to_bytes(), [0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(u32::from(version), 0x01020304);
+    }
+
+    #[test]
+    fn should_create_manufacturer_specific_parameter_id() {
+        assert_eq!(
+            ParameterId::manufacturer_specific(0x8000),
+            Ok(ParameterId::ManufacturerSpecific(0x8000))
+        );
+        assert_eq!(
+            ParameterId::manufacturer_specific(0xffdf),
+            Ok(ParameterId::ManufacturerSpecific(0xffdf))
+        );
+        assert_eq!(
+            ParameterId::manufacturer_specific(0x1000),
+            Err(RdmError::InvalidParameterId(0x1000))
+        );
+        assert_eq!(
+            ParameterId::manufacturer_specific(0xffe0),
+            Err(RdmError::InvalidParameterId(0xffe0))
+        );
+    }
+
+    #[test]
+    fn should_convert_dhcp_mode_status_values() {
+        for (value, mode) in [
+            (0x00, DhcpMode::Inactive),
+            (0x01, DhcpMode::Active),
+            (0x02, DhcpMode::Unknown),
+        ] {
+            assert_eq!(DhcpMode::try_from(value), Ok(mode));
+            assert_eq!(u8::from(mode), value);
+        }
+
+        assert_eq!(
+            DhcpMode::try_from(0x03),
+            Err(RdmError::InvalidDhcpMode(0x03))
+        );
+        assert_eq!(
+            DhcpMode::try_from(0xff),
+            Err(RdmError::InvalidDhcpMode(0xff))
+        );
+    }
+
+    #[test]
+    fn should_convert_reset_device_mode_values() {
+        assert_eq!(ResetDeviceMode::try_from(0x01), Ok(ResetDeviceMode::Warm));
+        assert_eq!(ResetDeviceMode::try_from(0xff), Ok(ResetDeviceMode::Cold));
+        assert_eq!(ResetDeviceMode::Cold as u8, 0xff);
+
+        for value in [0x00, 0x02, 0xfe] {
+            assert_eq!(
+                ResetDeviceMode::try_from(value),
+                Err(RdmError::InvalidResetDeviceMode(value))
+            );
+        }
+    }
+
+    #[test]
+    fn should_interpret_supported_times() {
+        let time = SupportedTimes::from(0x0019);
+
+        assert!(time.is_supported());
+        assert_eq!(time.as_seconds(), Some(2));
+
+        let time = SupportedTimes::from(0xffff);
+
+        assert!(!time.is_supported());
+        assert_eq!(time.as_seconds(), None);
+
+        #[cfg(feature = "alloc")]
+        {
+            assert_eq!(SupportedTimes::Time(0x0019).to_string(), "2.5s");
+            assert_eq!(SupportedTimes::Time(0x0000).to_string(), "0.0s");
+            assert_eq!(SupportedTimes::NotSupported.to_string(), "Not supported");
+        }
+    }
+
+    #[test]
+    fn should_create_time_mode_sentinels() {
+        assert_eq!(u16::from(TimeMode::infinite()), 0xffff);
+        assert_eq!(u16::from(TimeMode::none()), 0x0000);
+        assert_eq!(TimeMode::seconds(5), TimeMode::TenthOfSeconds(50));
+        assert_eq!(
+            TimeMode::seconds(u16::MAX),
+            TimeMode::TenthOfSeconds(0xfffe)
+        );
+        assert_eq!(TimeMode::from(0xffff), TimeMode::infinite());
+    }
+
+    #[test]
+    fn should_convert_raw_values_by_parameter_data_type() {
+        let value = [0x00, 0x00, 0xff, 0xfe];
+
+        assert_eq!(
+            ParameterDataType::UnsignedByte.convert(value),
+            ConvertedParameterValue::UnsignedByte(0xfe)
+        );
+        assert_eq!(
+            ParameterDataType::SignedWord.convert(value),
+            ConvertedParameterValue::SignedWord(-2)
+        );
+        assert_eq!(
+            ParameterDataType::UnsignedDWord.convert(value),
+            ConvertedParameterValue::UnsignedDWord(0xfffe)
+        );
+        assert_eq!(
+            ParameterDataType::ManufacturerSpecific(0x80).convert(value),
+            ConvertedParameterValue::Raw(value)
+        );
+    }
+
+    #[test]
+    fn should_decode_disc_control_field_flags() {
+        let control_field = DiscControlField::from(0x0005);
+
+        assert!(control_field.is_proxy());
+        assert!(!control_field.has_sub_devices());
+        assert!(control_field.is_boot_loader());
+        assert!(!control_field.is_proxied_device());
+
+        let control_field = DiscControlField::from(0x000a);
+
+        assert!(!control_field.is_proxy());
+        assert!(control_field.has_sub_devices());
+        assert!(!control_field.is_boot_loader());
+        assert!(control_field.is_proxied_device());
+        assert_eq!(u16::from(control_field), 0x000a);
+    }
+
+    #[test]
+    fn should_scale_sensor_definition_ranges() {
+        let definition = SensorDefinition {
+            id: 0x01,
+            kind: SensorType::Voltage,
+            unit: SensorUnit::VoltsDc,
+            prefix: SensorUnitPrefix::Milli,
+            range_minimum_value: -500,
+            range_maximum_value: 25_000,
+            normal_minimum_value: 11_500,
+            normal_maximum_value: 12_500,
+            is_lowest_highest_detected_value_supported: true,
+            is_recorded_value_supported: false,
+            description: String::new(),
+        };
+
+        assert_eq!(definition.range(), -500..=25_000);
+        assert!(definition.normal_range().contains(&12_000));
+        assert_eq!(definition.scaled_range(), -0.5..=25.0);
+        assert_eq!(definition.scaled_normal_range(), 11.5..=12.5);
+        assert_eq!(SensorUnitPrefix::Kilo.scale(12), 12_000.0);
+        assert_eq!(SensorUnitPrefix::None.scale(-7), -7.0);
+    }
 }
@@ -0,0 +1,222 @@
+//! Framing of RDM messages in the E1.33 RDM PDU, for tunnelling RDM over a network
+//!
+//! Only the RDM PDU layer is implemented, the root, RPT and broker layers are left to the
+//! transport.
+//!
+//! ```rust
+//! use dmx512_rdm_protocol::rdm::{
+//!     rdmnet,
+//!     request::{RdmRequest, RequestParameter},
+//!     DeviceUID,
+//! };
+//!
+//! let frame = RdmRequest::template(
+//!     DeviceUID::new(0x0102, 0x03040506),
+//!     DeviceUID::new(0x0605, 0x04030201),
+//! )
+//! .parameter(RequestParameter::GetIdentifyDevice)
+//! .encode();
+//!
+//! let pdu = rdmnet::wrap(&frame).unwrap();
+//!
+//! assert_eq!(rdmnet::unwrap(&pdu).unwrap(), frame);
+//! ```
+
+use super::{bsd_16_crc, error::RdmError, has_rdm_start, EncodedFrame, RDM_START_CODE_BYTE};
+
+#[cfg(not(feature = "alloc"))]
+use heapless::Vec;
+
+// E1.33 2019 Table A-13
+pub const VECTOR_RDM_CMD_RDM_DATA: u8 = 0xcc;
+
+// Flags and length, vector, and an RDM message without its start code but with its checksum
+pub const MAX_RDM_PDU_LENGTH: usize = 3 + 1 + 256;
+
+#[cfg(feature = "alloc")]
+pub type EncodedRdmPdu = Vec<u8>;
+#[cfg(not(feature = "alloc"))]
+pub type EncodedRdmPdu = Vec<u8, MAX_RDM_PDU_LENGTH>;
+
+// E1.33 2019 Section 5.3, the length always occupies 20 bits
+const PDU_FLAGS: u8 = 0xf0;
+
+/// Wraps an encoded RDM frame in an RDM PDU, the start code becomes the vector and the rest of
+/// the frame, including its checksum, is the data
+pub fn wrap(frame: &[u8]) -> Result<EncodedRdmPdu, RdmError> {
+    if !has_rdm_start(frame) || frame.len() < 3 {
+        return Err(RdmError::InvalidStartCode(
+            frame.first().copied().unwrap_or(0),
+        ));
+    }
+
+    let message_length = frame[2] as usize;
+
+    if message_length < 24 {
+        return Err(RdmError::InvalidMessageLength(frame[2]));
+    }
+
+    if frame.len() < message_length + 2 {
+        return Err(RdmError::InvalidFrameLength(frame.len() as u8));
+    }
+
+    // The start code is carried as the vector, the checksum follows the message
+    let pdu_length = 3 + message_length + 2;
+
+    #[cfg(feature = "alloc")]
+    let mut buf = Vec::with_capacity(pdu_length);
+    #[cfg(not(feature = "alloc"))]
+    let mut buf = Vec::new();
+
+    buf.extend([
+        PDU_FLAGS | (pdu_length >> 16) as u8,
+        (pdu_length >> 8) as u8,
+        pdu_length as u8,
+        VECTOR_RDM_CMD_RDM_DATA,
+    ]);
+
+    buf.extend(frame[1..message_length + 2].iter().copied());
+
+    Ok(buf)
+}
+
+/// Unwraps an RDM PDU into an encoded RDM frame, restoring the start code and verifying the
+/// checksum
+pub fn unwrap(pdu: &[u8]) -> Result<EncodedFrame, RdmError> {
+    if pdu.len() < 4 {
+        return Err(RdmError::InvalidFrameLength(pdu.len() as u8));
+    }
+
+    let pdu_length = u32::from_be_bytes([0, pdu[0] & 0x0f, pdu[1], pdu[2]]) as usize;
+
+    if pdu[0] & 0xf0 != PDU_FLAGS
+        || pdu_length < 4
+        || pdu_length > pdu.len()
+        || pdu_length > MAX_RDM_PDU_LENGTH
+    {
+        return Err(RdmError::MalformedPacket);
+    }
+
+    if pdu[3] != VECTOR_RDM_CMD_RDM_DATA {
+        return Err(RdmError::InvalidStartCode(pdu[3]));
+    }
+
+    let data = &pdu[4..pdu_length];
+
+    // The message length includes the start code which was carried as the vector, but not the
+    // checksum which follows the message
+    if data.len() < 25 || data[1] as usize != data.len() - 1 {
+        return Err(RdmError::InvalidMessageLength(
+            data.get(1).copied().unwrap_or(0),
+        ));
+    }
+
+    #[cfg(feature = "alloc")]
+    let mut buf = Vec::with_capacity(data.len() + 1);
+    #[cfg(not(feature = "alloc"))]
+    let mut buf = Vec::new();
+
+    #[cfg(feature = "alloc")]
+    buf.push(RDM_START_CODE_BYTE);
+    #[cfg(not(feature = "alloc"))]
+    buf.push(RDM_START_CODE_BYTE).unwrap();
+
+    buf.extend(data.iter().copied());
+
+    let message_length = data[1] as usize;
+
+    let packet_checksum = u16::from_be_bytes([buf[message_length], buf[message_length + 1]]);
+
+    let decoded_checksum = bsd_16_crc(&buf[..message_length]);
+
+    if decoded_checksum != packet_checksum {
+        return Err(RdmError::InvalidChecksum(decoded_checksum, packet_checksum));
+    }
+
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_wrap_rdm_frame_in_rdm_pdu() {
+        let frame = [
+            0xcc, // Start Code
+            0x01, // Sub Start Code
+            0x18, // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x01, // Port ID
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
+            0x20, // Command Class = GetCommand
+            0x10, 0x00, // Parameter ID = Identify Device
+            0x00, // PDL
+            0x01, 0x40, // Checksum
+        ];
+
+        let pdu = wrap(&frame).unwrap();
+
+        assert_eq!(&pdu[..4], &[0xf0, 0x00, 0x1d, 0xcc]);
+        assert_eq!(&pdu[4..], &frame[1..]);
+        assert_eq!(&unwrap(&pdu).unwrap()[..], &frame[..]);
+    }
+
+    #[test]
+    fn should_unwrap_rdm_pdu() {
+        let pdu = [
+            0xf0, 0x00, 0x1d, // Flags and Length
+            0xcc, // Vector = VECTOR_RDM_CMD_RDM_DATA
+            0x01, // Sub Start Code
+            0x18, // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x01, // Port ID
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
+            0x20, // Command Class = GetCommand
+            0x10, 0x00, // Parameter ID = Identify Device
+            0x00, // PDL
+            0x01, 0x40, // Checksum
+        ];
+
+        let frame = unwrap(&pdu).unwrap();
+
+        assert_eq!(frame[0], RDM_START_CODE_BYTE);
+        assert_eq!(&frame[1..], &pdu[4..]);
+        assert_eq!(&wrap(&frame).unwrap()[..], &pdu[..]);
+
+        let mut corrupted = pdu;
+        corrupted[28] = 0x41; // Checksum
+
+        assert_eq!(
+            unwrap(&corrupted),
+            Err(RdmError::InvalidChecksum(0x0140, 0x0141))
+        );
+    }
+
+    #[test]
+    fn should_not_unwrap_invalid_rdm_pdu() {
+        assert_eq!(unwrap(&[0xf0, 0x00]), Err(RdmError::InvalidFrameLength(2)));
+        assert_eq!(
+            unwrap(&[0xf0, 0x00, 0x1b, 0xcc]),
+            Err(RdmError::MalformedPacket)
+        );
+        assert_eq!(
+            unwrap(&[0xf0, 0x00, 0x02, 0xcc]),
+            Err(RdmError::MalformedPacket)
+        );
+        assert_eq!(
+            unwrap(&[0xf0, 0x00, 0x04, 0x17]),
+            Err(RdmError::InvalidStartCode(0x17))
+        );
+        assert_eq!(
+            unwrap(&[0xf0, 0x00, 0x04, 0xcc]),
+            Err(RdmError::InvalidMessageLength(0))
+        );
+    }
+}
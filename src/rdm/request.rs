@@ -48,6 +48,7 @@ use super::{
         MergeMode, ParameterId, PinCode, PowerState, PresetPlaybackMode, ResetDeviceMode, SelfTest,
         StaticConfigType, StatusType, TimeMode,
     },
+    response::ResponseKind,
     CommandClass, DeviceUID, EncodedFrame, EncodedParameterData, SubDeviceId, RDM_START_CODE_BYTE,
     RDM_SUB_START_CODE_BYTE,
 };
@@ -694,6 +695,25 @@ impl RequestParameter {
         }
     }
 
+    /// Discovery responses may collide or arrive without a break, so most interfaces
+    /// transmit discovery commands through a separate path
+    pub fn is_discovery(&self) -> bool {
+        self.command_class() == CommandClass::DiscoveryCommand
+    }
+
+    /// The kind of parameter data expected in an ACK to this request, `None` if the ACK carries
+    /// no parameter data
+    pub fn expected_response(&self) -> Option<ResponseKind> {
+        let command_class = match self.command_class() {
+            CommandClass::DiscoveryCommand => CommandClass::DiscoveryCommandResponse,
+            CommandClass::GetCommand => CommandClass::GetCommandResponse,
+            CommandClass::SetCommand => CommandClass::SetCommandResponse,
+            command_class => command_class,
+        };
+
+        ResponseKind::expected(command_class, self.parameter_id())
+    }
+
     pub fn parameter_id(&self) -> ParameterId {
         match self {
             // E1.20
@@ -875,6 +895,57 @@ impl RequestParameter {
         }
     }
 
+    /// Checks string parameters against their maximum length in the spec, strings are unbounded
+    /// with the `alloc` feature and would otherwise encode an invalid parameter data length.
+    /// Real time clock components are checked against their ranges in the spec.
+    pub fn validate(&self) -> Result<(), RdmError> {
+        let (string, max_length) = match self {
+            Self::SetRealTimeClock {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+            } => {
+                // Ranges from E1.20 REAL_TIME_CLOCK, a second of 60 allows for a leap second
+                if *year < 2003
+                    || !(1..=12).contains(month)
+                    || !(1..=31).contains(day)
+                    || *hour > 23
+                    || *minute > 59
+                    || *second > 60
+                {
+                    return Err(RdmError::InvalidParameterData);
+                }
+
+                return Ok(());
+            }
+            // E1.20 LANGUAGE is a two character ISO 639-1 code
+            Self::SetLanguage { language } if language.len() != 2 => {
+                return Err(RdmError::InvalidParameterDataLength(
+                    language.len().min(u8::MAX as usize) as u8,
+                ));
+            }
+            Self::SetDeviceLabel { device_label } => (device_label.as_str(), 32),
+            Self::SetDnsHostName { host_name } => (host_name.as_str(), 63),
+            Self::SetDnsDomainName { domain_name } => (domain_name.as_str(), 231),
+            Self::SetEndpointLabel { label, .. } => (label.as_str(), 32),
+            Self::SetSearchDomain(search_domain) => (search_domain.as_str(), 231),
+            Self::SetComponentScope { scope_string, .. } => (scope_string.as_str(), 63),
+            Self::SetTcpCommsStatus { scope_string } => (scope_string.as_str(), 63),
+            _ => return Ok(()),
+        };
+
+        if string.len() > max_length {
+            return Err(RdmError::InvalidParameterDataLength(
+                string.len().min(u8::MAX as usize) as u8,
+            ));
+        }
+
+        Ok(())
+    }
+
     pub fn encode(&self) -> EncodedParameterData {
         #[cfg(feature = "alloc")]
         let mut buf = Vec::new();
@@ -1325,18 +1396,18 @@ impl RequestParameter {
                 buf.extend((pin_code.0).to_be_bytes());
 
                 #[cfg(feature = "alloc")]
-                buf.push(*lock_state as u8);
+                buf.push(*lock_state);
                 #[cfg(not(feature = "alloc"))]
-                buf.push(*lock_state as u8).unwrap();
+                buf.push(*lock_state).unwrap();
             }
             Self::GetLockStateDescription { lock_state } => {
                 #[cfg(feature = "alloc")]
                 buf.reserve(0x01);
 
                 #[cfg(feature = "alloc")]
-                buf.push(*lock_state as u8);
+                buf.push(*lock_state);
                 #[cfg(not(feature = "alloc"))]
-                buf.push(*lock_state as u8).unwrap();
+                buf.push(*lock_state).unwrap();
             }
             Self::GetLockPin => {}
             Self::SetLockPin {
@@ -1838,10 +1909,11 @@ impl RequestParameter {
                 static_broker_port,
             } => {
                 #[cfg(feature = "alloc")]
-                buf.reserve(scope_string.len() + 25);
+                buf.reserve(88);
 
                 buf.extend((*scope_slot).to_be_bytes());
-                buf.extend(scope_string.bytes());
+                // Scope string is a fixed 63 byte field, null padded
+                buf.extend(scope_string.bytes().chain(core::iter::repeat(0)).take(63));
 
                 #[cfg(feature = "alloc")]
                 buf.push(*static_config_type as u8);
@@ -2645,20 +2717,20 @@ impl RequestParameter {
                 })
             }
             (CommandClass::SetCommand, ParameterId::ComponentScope) => {
-                check_msg_len!(bytes, 89);
+                check_msg_len!(bytes, 88);
                 Ok(Self::SetComponentScope {
                     scope_slot: u16::from_be_bytes([bytes[0], bytes[1]]),
-                    scope_string: decode_string_bytes(&bytes[2..=65])?,
-                    static_config_type: bytes[66].try_into()?,
+                    scope_string: decode_string_bytes(&bytes[2..=64])?,
+                    static_config_type: bytes[65].try_into()?,
                     static_broker_ipv4_address: Ipv4Address::from([
-                        bytes[67], bytes[68], bytes[69], bytes[70],
+                        bytes[66], bytes[67], bytes[68], bytes[69],
                     ]),
                     static_broker_ipv6_address: Ipv6Address::from([
-                        bytes[71], bytes[72], bytes[73], bytes[74], bytes[75], bytes[76],
-                        bytes[77], bytes[78], bytes[79], bytes[80], bytes[81], bytes[82],
-                        bytes[83], bytes[84], bytes[85], bytes[86],
+                        bytes[70], bytes[71], bytes[72], bytes[73], bytes[74], bytes[75],
+                        bytes[76], bytes[77], bytes[78], bytes[79], bytes[80], bytes[81],
+                        bytes[82], bytes[83], bytes[84], bytes[85],
                     ]),
-                    static_broker_port: u16::from_be_bytes([bytes[87], bytes[88]]),
+                    static_broker_port: u16::from_be_bytes([bytes[86], bytes[87]]),
                 })
             }
             (CommandClass::GetCommand, ParameterId::SearchDomain) => Ok(Self::GetSearchDomain),
@@ -2733,14 +2805,121 @@ impl RdmRequest {
         }
     }
 
+    /// Starts a request with transaction number 0, port id 1 and the root device, the
+    /// parameter must be set to complete the request
+    pub fn template(destination_uid: DeviceUID, source_uid: DeviceUID) -> RdmRequestBuilder {
+        RdmRequestBuilder {
+            destination_uid,
+            source_uid,
+            transaction_number: 0,
+            port_id: 1,
+            sub_device_id: SubDeviceId::RootDevice,
+        }
+    }
+
+    /// The identical request with the next transaction number, sent to collect the remaining
+    /// data after an ACK_OVERFLOW response
+    pub fn overflow_follow_up(&self) -> Self {
+        Self {
+            transaction_number: self.transaction_number.wrapping_add(1),
+            ..self.clone()
+        }
+    }
+
+    /// Sets identify on every device, no responses will be sent
+    pub fn identify_all(source_uid: DeviceUID, identify: bool) -> Self {
+        Self::template(DeviceUID::broadcast_to_all_devices(), source_uid)
+            .parameter(RequestParameter::SetIdentifyDevice { identify })
+    }
+
+    /// A GET IDENTIFY_DEVICE to the root device, for checking a device is still present. The
+    /// parameter is required of every responder and reading it has no side effects.
+    pub fn ping(destination_uid: DeviceUID, source_uid: DeviceUID) -> Self {
+        Self::template(destination_uid, source_uid).parameter(RequestParameter::GetIdentifyDevice)
+    }
+
+    /// One request per destination with the same parameter, transaction numbers increment from
+    /// `transaction_number` and wrap
+    #[cfg(feature = "alloc")]
+    pub fn batch(
+        destination_uids: &[DeviceUID],
+        source_uid: DeviceUID,
+        parameter: RequestParameter,
+        transaction_number: u8,
+    ) -> Vec<Self> {
+        destination_uids
+            .iter()
+            .enumerate()
+            .map(|(offset, &destination_uid)| {
+                Self::template(destination_uid, source_uid)
+                    .transaction_number(transaction_number.wrapping_add(offset as u8))
+                    .parameter(parameter.clone())
+            })
+            .collect()
+    }
+
+    pub fn is_broadcast(&self) -> bool {
+        self.destination_uid.is_broadcast()
+    }
+
     pub fn command_class(&self) -> CommandClass {
         self.parameter.command_class()
     }
 
+    pub fn is_discovery(&self) -> bool {
+        self.parameter.is_discovery()
+    }
+
     pub fn parameter_id(&self) -> ParameterId {
         self.parameter.parameter_id()
     }
 
+    /// Whether a responder will reply to this request.
+    ///
+    /// Responders never reply to requests sent to a broadcast uid, other than DISC_UNIQUE_BRANCH
+    /// which is always broadcast. A request sent to `SubDeviceId::AllDevices` is answered with a
+    /// single response from the root device, rather than one per sub-device.
+    pub fn expects_response(&self) -> bool {
+        matches!(self.parameter, RequestParameter::DiscUniqueBranch { .. })
+            || !self.destination_uid.is_broadcast()
+    }
+
+    /// Checks the sub-device is addressable with the command class. Discovery requests must be
+    /// sent to the root device, and only SET requests may be sent to `SubDeviceId::AllDevices`,
+    /// responders NACK a GET with `SubDeviceIdOutOfRange`. Sub-device ids range from 1 to 512.
+    pub fn validate_sub_device(&self) -> Result<(), RdmError> {
+        let is_valid = match (self.command_class(), self.sub_device_id) {
+            (CommandClass::DiscoveryCommand, sub_device_id) => {
+                sub_device_id == SubDeviceId::RootDevice
+            }
+            (_, SubDeviceId::Id(id)) => (0x0001..=SubDeviceId::MAX_SUB_DEVICES).contains(&id),
+            (CommandClass::GetCommand, SubDeviceId::AllDevices) => false,
+            _ => true,
+        };
+
+        if !is_valid {
+            return Err(RdmError::InvalidSubDeviceId(self.sub_device_id.into()));
+        }
+
+        Ok(())
+    }
+
+    /// Validates the port id, the sub-device with `validate_sub_device` and the parameter data
+    /// with `RequestParameter::validate` before encoding, port id 0 is reserved and may be NACKed
+    /// by responders
+    pub fn try_encode(&self) -> Result<EncodedFrame, RdmError> {
+        if self.port_id == 0 {
+            return Err(RdmError::InvalidPortId(self.port_id));
+        }
+
+        self.validate_sub_device()?;
+
+        self.parameter.validate()?;
+
+        Ok(self.encode())
+    }
+
+    /// Encodes the request as is, the addressing is not validated, see `validate_sub_device`
     pub fn encode(&self) -> EncodedFrame {
         let parameter_data = self.parameter.encode();
 
@@ -2766,10 +2945,8 @@ impl RdmRequest {
         #[cfg(not(feature = "alloc"))]
         buf.push(message_length as u8).unwrap();
 
-        buf.extend(self.destination_uid.manufacturer_id.to_be_bytes());
-        buf.extend(self.destination_uid.device_id.to_be_bytes());
-        buf.extend(self.source_uid.manufacturer_id.to_be_bytes());
-        buf.extend(self.source_uid.device_id.to_be_bytes());
+        buf.extend(<[u8; 6]>::from(self.destination_uid));
+        buf.extend(<[u8; 6]>::from(self.source_uid));
 
         #[cfg(feature = "alloc")]
         buf.push(self.transaction_number);
@@ -2804,6 +2981,14 @@ impl RdmRequest {
         buf.extend(parameter_data);
         buf.extend(bsd_16_crc(&buf[..]).to_be_bytes());
 
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            parameter_id = ?self.parameter.parameter_id(),
+            command_class = ?self.parameter.command_class(),
+            length = buf.len(),
+            "encoded rdm request"
+        );
+
         buf
     }
 
@@ -2817,6 +3002,10 @@ impl RdmRequest {
 
         let transaction_number = bytes[15];
         let port_id = bytes[16];
+
+        // Message Count (bytes[17]) shall be 0x00 in controller generated requests, but it is
+        // ignored so frames from non-compliant controllers can still be decoded
+
         let sub_device_id = u16::from_be_bytes([bytes[18], bytes[19]]).into();
         let command_class = bytes[20].try_into()?;
         let parameter_id = u16::from_be_bytes([bytes[21], bytes[22]]).into();
@@ -2841,6 +3030,43 @@ impl RdmRequest {
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct RdmRequestBuilder {
+    destination_uid: DeviceUID,
+    source_uid: DeviceUID,
+    transaction_number: u8,
+    port_id: u8,
+    sub_device_id: SubDeviceId,
+}
+
+impl RdmRequestBuilder {
+    pub fn transaction_number(mut self, transaction_number: u8) -> Self {
+        self.transaction_number = transaction_number;
+        self
+    }
+
+    pub fn port_id(mut self, port_id: u8) -> Self {
+        self.port_id = port_id;
+        self
+    }
+
+    pub fn sub_device_id(mut self, sub_device_id: SubDeviceId) -> Self {
+        self.sub_device_id = sub_device_id;
+        self
+    }
+
+    pub fn parameter(self, parameter: RequestParameter) -> RdmRequest {
+        RdmRequest::new(
+            self.destination_uid,
+            self.source_uid,
+            self.transaction_number,
+            self.port_id,
+            self.sub_device_id,
+            parameter,
+        )
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl From<RdmRequest> for Vec<u8> {
     fn from(request: RdmRequest) -> Self {
@@ -2858,6 +3084,8 @@ impl From<RdmRequest> for Vec<u8, 257> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rdm::discovery::REQUIRED_PARAMETERS;
+    use core::net::Ipv4Addr;
 
     #[test]
     fn should_encode_discovery_unique_branch_request() {
@@ -2931,6 +3159,45 @@ mod tests {
         assert_eq!(decoded, expected);
     }
 
+    #[test]
+    fn should_preserve_dynamic_uids_in_discovery_unique_branch_bounds() {
+        let request = RdmRequest::new(
+            DeviceUID::broadcast_to_all_devices(),
+            DeviceUID::new(0x0605, 0x04030201),
+            0x00,
+            0x01,
+            SubDeviceId::RootDevice,
+            RequestParameter::DiscUniqueBranch {
+                lower_bound_uid: DeviceUID::new_dynamic(0x0102, 0x00000000),
+                upper_bound_uid: DeviceUID::new_dynamic(0x0102, 0xffffffff),
+            },
+        );
+
+        let encoded = request.encode();
+
+        assert_eq!(
+            &encoded[24..36],
+            &[
+                0x81, 0x02, 0x00, 0x00, 0x00, 0x00, // Parameter Data - Lower Bound UID
+                0x81, 0x02, 0xff, 0xff, 0xff, 0xff, // Parameter Data - Upper Bound UID
+            ]
+        );
+
+        let decoded = RdmRequest::decode(&encoded).unwrap();
+
+        let RequestParameter::DiscUniqueBranch {
+            lower_bound_uid,
+            upper_bound_uid,
+        } = decoded.parameter
+        else {
+            panic!("expected DiscUniqueBranch, got {:?}", decoded.parameter);
+        };
+
+        assert!(lower_bound_uid.is_dynamic());
+        assert!(upper_bound_uid.is_dynamic());
+        assert_eq!(decoded, request);
+    }
+
     #[test]
     fn should_encode_valid_rdm_request() {
         let encoded = RdmRequest::new(
@@ -2993,6 +3260,813 @@ mod tests {
         assert_eq!(decoded, expected);
     }
 
+    #[test]
+    fn should_round_trip_interface_hardware_address_type1_request_for_multiple_interfaces() {
+        for interface_id in [0x00000001, 0x00000002] {
+            let request = RdmRequest::new(
+                DeviceUID::new(0x0102, 0x03040506),
+                DeviceUID::new(0x0605, 0x04030201),
+                0x00,
+                0x01,
+                SubDeviceId::RootDevice,
+                RequestParameter::GetInterfaceHardwareAddressType1 { interface_id },
+            );
+
+            let encoded = request.encode();
+
+            assert_eq!(&encoded[24..28], &interface_id.to_be_bytes());
+            assert_eq!(RdmRequest::decode(&encoded), Ok(request));
+        }
+    }
+
+    #[test]
+    fn should_round_trip_set_identify_mode_request() {
+        for (identify_mode, byte) in [(IdentifyMode::Quiet, 0x00), (IdentifyMode::Loud, 0xff)] {
+            let request = RdmRequest::new(
+                DeviceUID::new(0x0102, 0x03040506),
+                DeviceUID::new(0x0605, 0x04030201),
+                0x00,
+                0x01,
+                SubDeviceId::RootDevice,
+                RequestParameter::SetIdentifyMode { identify_mode },
+            );
+
+            let encoded = request.encode();
+
+            assert_eq!(encoded[21..24], [0x10, 0x40, 0x01]);
+            assert_eq!(encoded[24], byte);
+            assert_eq!(RdmRequest::decode(&encoded), Ok(request));
+        }
+    }
+
+    #[test]
+    fn should_round_trip_set_reset_device_request() {
+        for (reset_device, byte) in [(ResetDeviceMode::Warm, 0x01), (ResetDeviceMode::Cold, 0xff)] {
+            let request = RdmRequest::new(
+                DeviceUID::new(0x0102, 0x03040506),
+                DeviceUID::new(0x0605, 0x04030201),
+                0x00,
+                0x01,
+                SubDeviceId::RootDevice,
+                RequestParameter::SetResetDevice { reset_device },
+            );
+
+            let mut encoded = request.encode();
+
+            assert_eq!(encoded[20..24], [0x30, 0x10, 0x01, 0x01]);
+            assert_eq!(encoded[24], byte);
+            assert_eq!(RdmRequest::decode(&encoded), Ok(request));
+
+            encoded[24] = 0x02;
+            let checksum = encoded[..25]
+                .iter()
+                .fold(0u16, |sum, &byte| sum.wrapping_add(byte as u16));
+            encoded[25..].copy_from_slice(&checksum.to_be_bytes());
+
+            assert_eq!(
+                RdmRequest::decode(&encoded),
+                Err(RdmError::InvalidResetDeviceMode(0x02))
+            );
+        }
+    }
+
+    #[test]
+    fn should_build_request_from_template() {
+        let destination_uid = DeviceUID::new(0x0102, 0x03040506);
+        let source_uid = DeviceUID::new(0x0605, 0x04030201);
+
+        assert_eq!(
+            RdmRequest::template(destination_uid, source_uid)
+                .parameter(RequestParameter::GetIdentifyDevice),
+            RdmRequest::new(
+                destination_uid,
+                source_uid,
+                0x00,
+                0x01,
+                SubDeviceId::RootDevice,
+                RequestParameter::GetIdentifyDevice,
+            )
+        );
+
+        assert_eq!(
+            RdmRequest::template(destination_uid, source_uid)
+                .transaction_number(0x10)
+                .port_id(0x02)
+                .sub_device_id(SubDeviceId::Id(0x0001))
+                .parameter(RequestParameter::GetIdentifyDevice),
+            RdmRequest::new(
+                destination_uid,
+                source_uid,
+                0x10,
+                0x02,
+                SubDeviceId::Id(0x0001),
+                RequestParameter::GetIdentifyDevice,
+            )
+        );
+    }
+
+    #[test]
+    fn should_create_overflow_follow_up_request() {
+        let request = RdmRequest::template(
+            DeviceUID::new(0x0102, 0x03040506),
+            DeviceUID::new(0x0605, 0x04030201),
+        )
+        .transaction_number(0xff)
+        .parameter(RequestParameter::GetSupportedParameters);
+
+        let follow_up = request.overflow_follow_up();
+
+        assert_eq!(follow_up.transaction_number, 0x00);
+        assert_eq!(follow_up.parameter, request.parameter);
+        assert_eq!(follow_up.destination_uid, request.destination_uid);
+    }
+
+    #[test]
+    fn should_create_identify_all_broadcast_request() {
+        let request = RdmRequest::identify_all(DeviceUID::new(0x0605, 0x04030201), true);
+
+        assert!(request.is_broadcast());
+        assert_eq!(
+            request.destination_uid,
+            DeviceUID::broadcast_to_all_devices()
+        );
+        assert_eq!(
+            request.parameter,
+            RequestParameter::SetIdentifyDevice { identify: true }
+        );
+    }
+
+    #[test]
+    fn should_create_ping_request() {
+        let request = RdmRequest::ping(
+            DeviceUID::new(0x0102, 0x03040506),
+            DeviceUID::new(0x0605, 0x04030201),
+        );
+
+        assert_eq!(request.command_class(), CommandClass::GetCommand);
+        assert_eq!(request.sub_device_id, SubDeviceId::RootDevice);
+        assert_eq!(request.parameter, RequestParameter::GetIdentifyDevice);
+        assert!(REQUIRED_PARAMETERS.contains(&request.parameter));
+        assert!(request.try_encode().is_ok());
+    }
+
+    #[test]
+    fn should_return_expected_response_kind() {
+        assert_eq!(
+            RequestParameter::GetDeviceInfo.expected_response(),
+            Some(ResponseKind::GetDeviceInfo)
+        );
+        assert_eq!(
+            RequestParameter::DiscMute.expected_response(),
+            Some(ResponseKind::DiscMute)
+        );
+        assert_eq!(
+            RequestParameter::SetEndpointMode {
+                endpoint_id: EndpointId::Device(0x0001),
+                mode: EndpointMode::Input,
+            }
+            .expected_response(),
+            Some(ResponseKind::SetEndpointMode)
+        );
+        assert_eq!(
+            RequestParameter::SetIdentifyDevice { identify: true }.expected_response(),
+            None
+        );
+        assert_eq!(
+            RequestParameter::DiscUniqueBranch {
+                lower_bound_uid: DeviceUID::new(0x0000, 0x00000000),
+                upper_bound_uid: DeviceUID::new(0xffff, 0xffffffff),
+            }
+            .expected_response(),
+            None
+        );
+    }
+
+    #[test]
+    fn should_only_expect_response_to_non_broadcast_requests() {
+        let source_uid = DeviceUID::new(0x0605, 0x04030201);
+
+        assert!(!RdmRequest::identify_all(source_uid, true).expects_response());
+        assert!(
+            !RdmRequest::template(DeviceUID::broadcast_to_all_devices(), source_uid)
+                .parameter(RequestParameter::DiscUnMute)
+                .expects_response()
+        );
+        assert!(
+            RdmRequest::template(DeviceUID::broadcast_to_all_devices(), source_uid)
+                .parameter(RequestParameter::DiscUniqueBranch {
+                    lower_bound_uid: DeviceUID::new(0x0000, 0x00000000),
+                    upper_bound_uid: DeviceUID::new(0xffff, 0xffffffff),
+                })
+                .expects_response()
+        );
+        assert!(
+            RdmRequest::template(DeviceUID::new(0x0102, 0x03040506), source_uid)
+                .sub_device_id(SubDeviceId::AllDevices)
+                .parameter(RequestParameter::SetIdentifyDevice { identify: true })
+                .expects_response()
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_batch_requests_with_incrementing_transaction_numbers() {
+        let destination_uids = [
+            DeviceUID::new(0x0102, 0x03040506),
+            DeviceUID::new(0x0102, 0x03040507),
+            DeviceUID::new(0x0102, 0x03040508),
+        ];
+
+        let requests = RdmRequest::batch(
+            &destination_uids,
+            DeviceUID::new(0x0605, 0x04030201),
+            RequestParameter::SetIdentifyDevice { identify: true },
+            0xfe,
+        );
+
+        assert_eq!(
+            requests
+                .iter()
+                .map(|request| (request.destination_uid, request.transaction_number))
+                .collect::<Vec<_>>(),
+            vec![
+                (destination_uids[0], 0xfe),
+                (destination_uids[1], 0xff),
+                (destination_uids[2], 0x00),
+            ]
+        );
+        assert!(requests
+            .iter()
+            .all(|request| request.parameter
+                == RequestParameter::SetIdentifyDevice { identify: true }));
+    }
+
+    #[test]
+    fn should_validate_sub_device() {
+        let request = |sub_device_id, parameter| {
+            RdmRequest::template(
+                DeviceUID::new(0x0102, 0x03040506),
+                DeviceUID::new(0x0605, 0x04030201),
+            )
+            .sub_device_id(sub_device_id)
+            .parameter(parameter)
+        };
+
+        assert_eq!(
+            request(SubDeviceId::AllDevices, RequestParameter::GetDeviceInfo).try_encode(),
+            Err(RdmError::InvalidSubDeviceId(0xffff))
+        );
+        assert_eq!(
+            request(
+                SubDeviceId::AllDevices,
+                RequestParameter::SetIdentifyDevice { identify: true }
+            )
+            .validate_sub_device(),
+            Ok(())
+        );
+        assert_eq!(
+            request(SubDeviceId::Id(0x0001), RequestParameter::DiscMute).validate_sub_device(),
+            Err(RdmError::InvalidSubDeviceId(0x0001))
+        );
+        assert_eq!(
+            request(SubDeviceId::Id(0x0200), RequestParameter::GetDeviceInfo).validate_sub_device(),
+            Ok(())
+        );
+        assert_eq!(
+            request(SubDeviceId::Id(0x0201), RequestParameter::GetDeviceInfo).validate_sub_device(),
+            Err(RdmError::InvalidSubDeviceId(0x0201))
+        );
+    }
+
+    #[test]
+    fn should_not_encode_zero_port_id() {
+        let request = RdmRequest::template(
+            DeviceUID::new(0x0102, 0x03040506),
+            DeviceUID::new(0x0605, 0x04030201),
+        )
+        .port_id(0x00)
+        .parameter(RequestParameter::GetIdentifyDevice);
+
+        assert_eq!(request.try_encode(), Err(RdmError::InvalidPortId(0x00)));
+        assert_eq!(
+            RdmRequest {
+                port_id: 0x01,
+                ..request
+            }
+            .try_encode()
+            .map(|frame| frame[16]),
+            Ok(0x01)
+        );
+    }
+
+    #[test]
+    fn should_validate_real_time_clock() {
+        let real_time_clock = |month, day, second| RequestParameter::SetRealTimeClock {
+            year: 2024,
+            month,
+            day,
+            hour: 23,
+            minute: 59,
+            second,
+        };
+
+        assert_eq!(real_time_clock(12, 31, 60).validate(), Ok(()));
+        assert_eq!(
+            real_time_clock(13, 1, 0).validate(),
+            Err(RdmError::InvalidParameterData)
+        );
+        assert_eq!(
+            real_time_clock(1, 32, 0).validate(),
+            Err(RdmError::InvalidParameterData)
+        );
+        assert_eq!(
+            real_time_clock(0, 1, 0).validate(),
+            Err(RdmError::InvalidParameterData)
+        );
+        assert_eq!(
+            real_time_clock(1, 1, 61).validate(),
+            Err(RdmError::InvalidParameterData)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_not_encode_over_length_device_label() {
+        let request = |device_label: &str| {
+            RdmRequest::new(
+                DeviceUID::new(0x0102, 0x03040506),
+                DeviceUID::new(0x0605, 0x04030201),
+                0x00,
+                0x01,
+                SubDeviceId::RootDevice,
+                RequestParameter::SetDeviceLabel {
+                    device_label: device_label.to_string(),
+                },
+            )
+        };
+
+        assert_eq!(
+            request(&"a".repeat(40)).try_encode(),
+            Err(RdmError::InvalidParameterDataLength(40))
+        );
+        assert_eq!(
+            request(&"a".repeat(32)).try_encode().map(|frame| frame[23]),
+            Ok(32)
+        );
+        assert_eq!(
+            RequestParameter::SetDnsHostName {
+                host_name: "a".repeat(64),
+            }
+            .validate(),
+            Err(RdmError::InvalidParameterDataLength(64))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_not_encode_over_length_tcp_comms_status_scope() {
+        let request = |scope_string: &str| {
+            RdmRequest::new(
+                DeviceUID::new(0x0102, 0x03040506),
+                DeviceUID::new(0x0605, 0x04030201),
+                0x00,
+                0x01,
+                SubDeviceId::RootDevice,
+                RequestParameter::SetTcpCommsStatus {
+                    scope_string: scope_string.to_string(),
+                },
+            )
+        };
+
+        assert_eq!(
+            request(&"a".repeat(64)).try_encode(),
+            Err(RdmError::InvalidParameterDataLength(64))
+        );
+        assert_eq!(
+            request(&"a".repeat(63)).try_encode().map(|frame| frame[23]),
+            Ok(63)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_not_encode_language_of_invalid_length() {
+        let request = |language: &str| {
+            RdmRequest::new(
+                DeviceUID::new(0x0102, 0x03040506),
+                DeviceUID::new(0x0605, 0x04030201),
+                0x00,
+                0x01,
+                SubDeviceId::RootDevice,
+                RequestParameter::SetLanguage {
+                    language: language.to_string(),
+                },
+            )
+        };
+
+        assert_eq!(
+            request("eng").try_encode(),
+            Err(RdmError::InvalidParameterDataLength(3))
+        );
+        assert_eq!(
+            request("e").try_encode(),
+            Err(RdmError::InvalidParameterDataLength(1))
+        );
+        assert_eq!(request("en").try_encode().map(|frame| frame[23]), Ok(2));
+    }
+
+    #[test]
+    fn should_classify_discovery_request_parameters() {
+        assert!(RequestParameter::DiscMute.is_discovery());
+        assert!(RequestParameter::DiscUnMute.is_discovery());
+        assert!(RequestParameter::DiscUniqueBranch {
+            lower_bound_uid: DeviceUID::new(0x0000, 0x00000000),
+            upper_bound_uid: DeviceUID::new(0x7fff, 0xffffffff),
+        }
+        .is_discovery());
+        assert!(!RequestParameter::GetIdentifyDevice.is_discovery());
+        assert!(!RequestParameter::SetIdentifyDevice { identify: true }.is_discovery());
+    }
+
+    #[test]
+    fn should_round_trip_every_request_parameter() {
+        let endpoint_id = EndpointId::Device(0x0001);
+
+        for parameter in [
+            // E1.20
+            RequestParameter::DiscMute,
+            RequestParameter::DiscUnMute,
+            RequestParameter::DiscUniqueBranch {
+                lower_bound_uid: DeviceUID::new(0x0000, 0x00000000),
+                upper_bound_uid: DeviceUID::new(0x7fff, 0xffffffff),
+            },
+            RequestParameter::GetProxiedDeviceCount,
+            RequestParameter::GetProxiedDevices,
+            RequestParameter::GetCommsStatus,
+            RequestParameter::SetCommsStatus,
+            RequestParameter::GetQueuedMessage {
+                status_type: StatusType::Error,
+            },
+            RequestParameter::GetStatusMessages {
+                status_type: StatusType::Warning,
+            },
+            RequestParameter::GetStatusIdDescription { status_id: 0x0001 },
+            RequestParameter::SetClearStatusId,
+            RequestParameter::GetSubDeviceIdStatusReportThreshold,
+            RequestParameter::SetSubDeviceIdStatusReportThreshold {
+                status_type: StatusType::Advisory,
+            },
+            RequestParameter::GetSupportedParameters,
+            RequestParameter::GetParameterDescription {
+                parameter_id: 0x8000,
+            },
+            RequestParameter::GetDeviceInfo,
+            RequestParameter::GetProductDetailIdList,
+            RequestParameter::GetDeviceModelDescription,
+            RequestParameter::GetManufacturerLabel,
+            RequestParameter::GetDeviceLabel,
+            RequestParameter::SetDeviceLabel {
+                device_label: "Stage Left".parse().unwrap(),
+            },
+            RequestParameter::GetFactoryDefaults,
+            RequestParameter::SetFactoryDefaults,
+            RequestParameter::GetLanguageCapabilities,
+            RequestParameter::GetLanguage,
+            RequestParameter::SetLanguage {
+                language: "en".parse().unwrap(),
+            },
+            RequestParameter::GetSoftwareVersionLabel,
+            RequestParameter::GetBootSoftwareVersionId,
+            RequestParameter::GetBootSoftwareVersionLabel,
+            RequestParameter::GetDmxPersonality,
+            RequestParameter::SetDmxPersonality { personality_id: 2 },
+            RequestParameter::GetDmxPersonalityDescription { personality: 2 },
+            RequestParameter::GetDmxStartAddress,
+            RequestParameter::SetDmxStartAddress {
+                dmx_start_address: 512,
+            },
+            RequestParameter::GetSlotInfo,
+            RequestParameter::GetSlotDescription { slot_id: 0x0002 },
+            RequestParameter::GetDefaultSlotValue,
+            RequestParameter::GetSensorDefinition { sensor_id: 1 },
+            RequestParameter::GetSensorValue { sensor_id: 1 },
+            RequestParameter::SetSensorValue { sensor_id: 1 },
+            RequestParameter::SetRecordSensors { sensor_id: 0xff },
+            RequestParameter::GetDeviceHours,
+            RequestParameter::SetDeviceHours {
+                device_hours: 0x01020304,
+            },
+            RequestParameter::GetLampHours,
+            RequestParameter::SetLampHours {
+                lamp_hours: 0x01020304,
+            },
+            RequestParameter::GetLampStrikes,
+            RequestParameter::SetLampStrikes {
+                lamp_strikes: 0x01020304,
+            },
+            RequestParameter::GetLampState,
+            RequestParameter::SetLampState {
+                lamp_state: LampState::LampStandby,
+            },
+            RequestParameter::GetLampOnMode,
+            RequestParameter::SetLampOnMode {
+                lamp_on_mode: LampOnMode::AfterCal,
+            },
+            RequestParameter::GetDevicePowerCycles,
+            RequestParameter::SetDevicePowerCycles {
+                device_power_cycles: 0x01020304,
+            },
+            RequestParameter::GetDisplayInvert,
+            RequestParameter::SetDisplayInvert {
+                display_invert: DisplayInvertMode::Auto,
+            },
+            RequestParameter::GetDisplayLevel,
+            RequestParameter::SetDisplayLevel {
+                display_level: 0x80,
+            },
+            RequestParameter::GetPanInvert,
+            RequestParameter::SetPanInvert { pan_invert: true },
+            RequestParameter::GetTiltInvert,
+            RequestParameter::SetTiltInvert { tilt_invert: true },
+            RequestParameter::GetPanTiltSwap,
+            RequestParameter::SetPanTiltSwap {
+                pan_tilt_swap: true,
+            },
+            RequestParameter::GetRealTimeClock,
+            RequestParameter::SetRealTimeClock {
+                year: 2024,
+                month: 12,
+                day: 31,
+                hour: 23,
+                minute: 59,
+                second: 58,
+            },
+            RequestParameter::GetIdentifyDevice,
+            RequestParameter::SetIdentifyDevice { identify: true },
+            RequestParameter::SetResetDevice {
+                reset_device: ResetDeviceMode::Cold,
+            },
+            RequestParameter::GetPowerState,
+            RequestParameter::SetPowerState {
+                power_state: PowerState::Standby,
+            },
+            RequestParameter::GetPerformSelfTest,
+            RequestParameter::SetPerformSelfTest {
+                self_test_id: SelfTest::ManufacturerId(0x02),
+            },
+            RequestParameter::GetSelfTestDescription {
+                self_test_id: SelfTest::All,
+            },
+            RequestParameter::SetCapturePreset {
+                scene_id: 0x0001,
+                fade_times: None,
+            },
+            RequestParameter::SetCapturePreset {
+                scene_id: 0x0001,
+                fade_times: Some(FadeTimes {
+                    up_fade_time: 0x0010,
+                    down_fade_time: 0x0020,
+                    wait_time: 0x0030,
+                }),
+            },
+            RequestParameter::GetPresetPlayback,
+            RequestParameter::SetPresetPlayback {
+                mode: PresetPlaybackMode::Scene(0x0003),
+                level: 0xff,
+            },
+            // E1.37-1
+            RequestParameter::GetIdentifyMode,
+            RequestParameter::SetIdentifyMode {
+                identify_mode: IdentifyMode::Loud,
+            },
+            RequestParameter::GetDmxBlockAddress,
+            RequestParameter::SetDmxBlockAddress {
+                dmx_block_address: 0x0100,
+            },
+            RequestParameter::GetDmxFailMode,
+            RequestParameter::SetDmxFailMode {
+                scene_id: PresetPlaybackMode::Scene(0x0004),
+                loss_of_signal_delay_time: TimeMode::seconds(2),
+                hold_time: TimeMode::infinite(),
+                level: 0x40,
+            },
+            RequestParameter::GetDmxStartupMode,
+            RequestParameter::SetDmxStartupMode {
+                scene_id: PresetPlaybackMode::All,
+                startup_delay: TimeMode::none(),
+                hold_time: TimeMode::seconds(10),
+                level: 0xff,
+            },
+            RequestParameter::GetPowerOnSelfTest,
+            RequestParameter::SetPowerOnSelfTest { perform_test: true },
+            RequestParameter::GetLockState,
+            RequestParameter::SetLockState {
+                pin_code: PinCode::try_from(1234).unwrap(),
+                lock_state: 0x01,
+            },
+            RequestParameter::GetLockStateDescription { lock_state: 0x01 },
+            RequestParameter::GetLockPin,
+            RequestParameter::SetLockPin {
+                new_pin_code: PinCode::try_from(4321).unwrap(),
+                current_pin_code: PinCode::try_from(1234).unwrap(),
+            },
+            RequestParameter::GetBurnIn,
+            RequestParameter::SetBurnIn { hours: 100 },
+            RequestParameter::GetDimmerInfo,
+            RequestParameter::GetMinimumLevel,
+            RequestParameter::SetMinimumLevel {
+                minimum_level_increasing: 0x0100,
+                minimum_level_decreasing: 0x0080,
+                on_below_minimum: true,
+            },
+            RequestParameter::GetMaximumLevel,
+            RequestParameter::SetMaximumLevel {
+                maximum_level: 0xff00,
+            },
+            RequestParameter::GetCurve,
+            RequestParameter::SetCurve { curve_id: 2 },
+            RequestParameter::GetCurveDescription { curve_id: 2 },
+            RequestParameter::GetOutputResponseTime,
+            RequestParameter::SetOutputResponseTime {
+                output_response_time_id: 3,
+            },
+            RequestParameter::GetOutputResponseTimeDescription {
+                output_response_time_id: 3,
+            },
+            RequestParameter::GetModulationFrequency,
+            RequestParameter::SetModulationFrequency {
+                modulation_frequency_id: 4,
+            },
+            RequestParameter::GetModulationFrequencyDescription {
+                modulation_frequency_id: 4,
+            },
+            RequestParameter::GetPresetInfo,
+            RequestParameter::GetPresetStatus { scene_id: 0x0005 },
+            RequestParameter::SetPresetStatus {
+                scene_id: 0x0005,
+                up_fade_time: 0x0010,
+                down_fade_time: 0x0020,
+                wait_time: 0x0030,
+                clear_preset: true,
+            },
+            RequestParameter::GetPresetMergeMode,
+            RequestParameter::SetPresetMergeMode {
+                merge_mode: MergeMode::Ltp,
+            },
+            // E1.37-2
+            RequestParameter::GetListInterfaces,
+            RequestParameter::GetInterfaceLabel {
+                interface_id: 0x00000001,
+            },
+            RequestParameter::GetInterfaceHardwareAddressType1 {
+                interface_id: 0x00000001,
+            },
+            RequestParameter::GetIpV4DhcpMode {
+                interface_id: 0x00000001,
+            },
+            RequestParameter::SetIpV4DhcpMode {
+                interface_id: 0x00000001,
+                dhcp_mode: true,
+            },
+            RequestParameter::GetIpV4ZeroConfMode {
+                interface_id: 0x00000001,
+            },
+            RequestParameter::SetIpV4ZeroConfMode {
+                interface_id: 0x00000001,
+                zero_conf_mode: true,
+            },
+            RequestParameter::GetIpV4CurrentAddress {
+                interface_id: 0x00000001,
+            },
+            RequestParameter::GetIpV4StaticAddress {
+                interface_id: 0x00000001,
+            },
+            RequestParameter::SetIpV4StaticAddress {
+                interface_id: 0x00000001,
+                address: Ipv4Addr::new(192, 168, 0, 10).into(),
+                netmask: 24,
+            },
+            RequestParameter::SetInterfaceApplyConfiguration {
+                interface_id: 0x00000001,
+            },
+            RequestParameter::SetInterfaceRenewDhcp {
+                interface_id: 0x00000001,
+            },
+            RequestParameter::SetInterfaceReleaseDhcp {
+                interface_id: 0x00000001,
+            },
+            RequestParameter::GetIpV4DefaultRoute,
+            RequestParameter::SetIpV4DefaultRoute {
+                interface_id: 0x00000001,
+                ipv4_default_route: Ipv4Addr::new(192, 168, 0, 1).into(),
+            },
+            RequestParameter::GetDnsIpV4NameServer {
+                name_server_index: 1,
+            },
+            RequestParameter::SetDnsIpV4NameServer {
+                name_server_index: 1,
+                name_server_address: Ipv4Addr::new(1, 1, 1, 1).into(),
+            },
+            RequestParameter::GetDnsHostName,
+            RequestParameter::SetDnsHostName {
+                host_name: "dimmer-rack-1".parse().unwrap(),
+            },
+            RequestParameter::GetDnsDomainName,
+            RequestParameter::SetDnsDomainName {
+                domain_name: "example.com".parse().unwrap(),
+            },
+            // E1.37-7
+            RequestParameter::GetEndpointList,
+            RequestParameter::GetEndpointListChange,
+            RequestParameter::GetIdentifyEndpoint { endpoint_id },
+            RequestParameter::SetIdentifyEndpoint {
+                endpoint_id,
+                identify: true,
+            },
+            RequestParameter::GetEndpointToUniverse { endpoint_id },
+            RequestParameter::SetEndpointToUniverse {
+                endpoint_id,
+                universe: 0x0002,
+            },
+            RequestParameter::GetEndpointMode { endpoint_id },
+            RequestParameter::SetEndpointMode {
+                endpoint_id,
+                mode: EndpointMode::Output,
+            },
+            RequestParameter::GetEndpointLabel { endpoint_id },
+            RequestParameter::SetEndpointLabel {
+                endpoint_id,
+                label: "Port 1".parse().unwrap(),
+            },
+            RequestParameter::GetRdmTrafficEnable { endpoint_id },
+            RequestParameter::SetRdmTrafficEnable {
+                endpoint_id,
+                enable: true,
+            },
+            RequestParameter::GetDiscoveryState { endpoint_id },
+            RequestParameter::SetDiscoveryState {
+                endpoint_id,
+                state: DiscoveryState::Full,
+            },
+            RequestParameter::GetBackgroundDiscovery { endpoint_id },
+            RequestParameter::SetBackgroundDiscovery {
+                endpoint_id,
+                enable: true,
+            },
+            RequestParameter::GetEndpointTiming { endpoint_id },
+            RequestParameter::SetEndpointTiming {
+                endpoint_id,
+                setting_id: 1,
+            },
+            RequestParameter::GetEndpointTimingDescription { setting_id: 1 },
+            RequestParameter::GetEndpointResponders { endpoint_id },
+            RequestParameter::GetEndpointResponderListChange { endpoint_id },
+            RequestParameter::GetBindingControlFields {
+                endpoint_id,
+                uid: DeviceUID::new(0x0102, 0x03040506),
+            },
+            RequestParameter::GetBackgroundQueuedStatusPolicy,
+            RequestParameter::SetBackgroundQueuedStatusPolicy { policy_id: 1 },
+            RequestParameter::GetBackgroundQueuedStatusPolicyDescription { policy_id: 1 },
+            // E1.33
+            RequestParameter::GetSearchDomain,
+            RequestParameter::SetSearchDomain("example.com".parse().unwrap()),
+            RequestParameter::GetComponentScope { scope_slot: 0x0001 },
+            RequestParameter::SetComponentScope {
+                scope_slot: 0x0001,
+                scope_string: "default".parse().unwrap(),
+                static_config_type: StaticConfigType::StaticConfigIpv4,
+                static_broker_ipv4_address: Ipv4Addr::new(192, 168, 0, 2).into(),
+                static_broker_ipv6_address: Ipv6Address::Unconfigured,
+                static_broker_port: 8888,
+            },
+            RequestParameter::GetTcpCommsStatus,
+            RequestParameter::SetTcpCommsStatus {
+                scope_string: "default".parse().unwrap(),
+            },
+            RequestParameter::GetBrokerStatus,
+            RequestParameter::SetBrokerStatus {
+                broker_state: BrokerState::Active,
+            },
+            RequestParameter::ManufacturerSpecific {
+                command_class: CommandClass::SetCommand,
+                parameter_id: 0x8080,
+                parameter_data: [0x01, 0x02, 0x03].into_iter().collect(),
+            },
+        ] {
+            let request = RdmRequest::new(
+                DeviceUID::new(0x0102, 0x03040506),
+                DeviceUID::new(0x0605, 0x04030201),
+                0x00,
+                0x01,
+                SubDeviceId::RootDevice,
+                parameter,
+            );
+
+            assert_eq!(RdmRequest::decode(&request.encode()), Ok(request));
+        }
+    }
+
     #[test]
     fn should_encode_manufacturer_specific_rdm_request() {
         let encoded = RdmRequest::new(
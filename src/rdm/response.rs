@@ -46,23 +46,32 @@
 //! ```
 
 use super::{
-    bsd_16_crc,
+    bsd_16_crc, decode_euid, encode_euid, has_discovery_start, has_rdm_start,
     parameter::{
-        decode_string_bytes, BrokerState, DefaultSlotValue, DhcpMode, DiscoveryCountStatus,
-        DiscoveryState, DisplayInvertMode, EndpointId, EndpointMode, EndpointType, IdentifyMode, Ipv4Address,
-        Ipv4Route, Ipv6Address, LampOnMode, LampState, MergeMode, NetworkInterface,
-        ParameterDescription, ParameterId, PinCode, PowerState, PresetPlaybackMode,
-        PresetProgrammed, ProductCategory, ProductDetail, ProtocolVersion, SelfTest,
-        SensorDefinition, SensorValue, SlotInfo, StaticConfigType, StatusMessage, StatusType,
-        SupportedTimes, TimeMode,
+        decode_string_bytes, BootSoftwareVersion, BrokerState, CommsStatus, DefaultSlotValue,
+        DhcpMode, DiscControlField, DiscoveryCountStatus, DiscoveryState, DisplayInvertMode,
+        EndpointId, EndpointMode, EndpointType, IdentifyMode, Ipv4Address, Ipv4Route, Ipv6Address,
+        LampOnMode, LampState, MergeMode, NetworkInterface, ParameterDescription, ParameterId,
+        Personality, PinCode, PowerState, PresetPlaybackMode, PresetProgrammed, ProductCategory,
+        ProductDetail, ProtocolVersion, SelfTest, SensorDefinition, SensorValue, SlotInfo,
+        StaticConfigType, StatusMessage, StatusType, SupportedTimes, TimeMode,
     },
+    request::{RdmRequest, RequestParameter},
     CommandClass, DeviceUID, EncodedFrame, EncodedParameterData, RdmError, SubDeviceId,
     DISCOVERY_UNIQUE_BRANCH_PREAMBLE_BYTE, DISCOVERY_UNIQUE_BRANCH_PREAMBLE_SEPARATOR_BYTE,
     RDM_START_CODE_BYTE, RDM_SUB_START_CODE_BYTE,
 };
-use core::{fmt::Display, iter, result::Result};
+use core::{
+    fmt::{self, Display},
+    iter,
+    ops::RangeInclusive,
+    result::Result,
+};
 use macaddr::MacAddr6;
 
+#[cfg(feature = "alloc")]
+use std::collections::HashMap;
+
 #[cfg(not(feature = "alloc"))]
 use heapless::{String, Vec};
 
@@ -158,6 +167,19 @@ impl TryFrom<u8> for ResponseType {
     }
 }
 
+impl Display for ResponseType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let response_type = match self {
+            Self::Ack => "ACK",
+            Self::AckTimer => "ACK_TIMER",
+            Self::NackReason => "NACK_REASON",
+            Self::AckOverflow => "ACK_OVERFLOW",
+        };
+
+        f.write_str(response_type)
+    }
+}
+
 #[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, PartialEq)]
 pub enum ResponseData {
@@ -168,6 +190,14 @@ pub enum ResponseData {
 }
 
 impl ResponseData {
+    /// The parameter data of an ACK or ACK_OVERFLOW response, if any
+    pub fn parameter_data(&self) -> Option<&ResponseParameterData> {
+        match self {
+            Self::ParameterData(Some(data)) => Some(data),
+            _ => None,
+        }
+    }
+
     pub fn encode(&self) -> EncodedParameterData {
         #[cfg(feature = "alloc")]
         let mut buf = Vec::new();
@@ -202,6 +232,8 @@ impl ResponseData {
         buf
     }
 
+    /// An ACK with no parameter data, as sent for most SET commands, decodes to
+    /// `ResponseData::ParameterData(None)` for any parameter
     pub fn decode(
         response_type: ResponseType,
         command_class: CommandClass,
@@ -224,11 +256,15 @@ impl ResponseData {
                 Ok(ResponseData::ParameterData(parameter_data))
             }
             ResponseType::AckTimer => {
+                check_msg_len!(bytes, 2);
+
                 let estimated_response_time = u16::from_be_bytes(bytes[0..=1].try_into()?);
 
                 Ok(ResponseData::EstimateResponseTime(estimated_response_time))
             }
             ResponseType::NackReason => {
+                check_msg_len!(bytes, 2);
+
                 let nack_reason = u16::from_be_bytes(bytes[0..=1].try_into()?).try_into()?;
 
                 Ok(ResponseData::NackReason(nack_reason))
@@ -237,17 +273,480 @@ impl ResponseData {
     }
 }
 
+/// The variant of a `ResponseParameterData`, without its data
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ResponseKind {
+    // E1.20
+    DiscMute,
+    DiscUnMute,
+    GetProxiedDeviceCount,
+    GetProxiedDevices,
+    GetCommsStatus,
+    GetStatusMessages,
+    GetStatusIdDescription,
+    GetSubDeviceIdStatusReportThreshold,
+    GetSupportedParameters,
+    GetParameterDescription,
+    GetDeviceInfo,
+    GetProductDetailIdList,
+    GetDeviceModelDescription,
+    GetManufacturerLabel,
+    GetDeviceLabel,
+    GetFactoryDefaults,
+    GetLanguageCapabilities,
+    GetLanguage,
+    GetSoftwareVersionLabel,
+    GetBootSoftwareVersionId,
+    GetBootSoftwareVersionLabel,
+    GetDmxPersonality,
+    GetDmxPersonalityDescription,
+    GetDmxStartAddress,
+    GetSlotInfo,
+    GetSlotDescription,
+    GetDefaultSlotValue,
+    GetSensorDefinition,
+    GetSensorValue,
+    SetSensorValue,
+    GetDeviceHours,
+    GetLampHours,
+    GetLampStrikes,
+    GetLampState,
+    GetLampOnMode,
+    GetDevicePowerCycles,
+    GetDisplayInvert,
+    GetDisplayLevel,
+    GetPanInvert,
+    GetTiltInvert,
+    GetPanTiltSwap,
+    GetRealTimeClock,
+    GetIdentifyDevice,
+    GetPowerState,
+    GetPerformSelfTest,
+    GetSelfTestDescription,
+    GetPresetPlayback,
+    // E1.37-1
+    GetIdentifyMode,
+    GetDmxBlockAddress,
+    GetDmxFailMode,
+    GetDmxStartupMode,
+    GetPowerOnSelfTest,
+    GetLockState,
+    GetLockStateDescription,
+    GetLockPin,
+    GetBurnIn,
+    GetDimmerInfo,
+    GetMinimumLevel,
+    GetMaximumLevel,
+    GetCurve,
+    GetCurveDescription,
+    GetOutputResponseTime,
+    GetOutputResponseTimeDescription,
+    GetModulationFrequency,
+    GetModulationFrequencyDescription,
+    GetPresetInfo,
+    GetPresetStatus,
+    GetPresetMergeMode,
+    // E1.37-2
+    GetListInterfaces,
+    GetInterfaceLabel,
+    GetInterfaceHardwareAddressType1,
+    GetIpV4DhcpMode,
+    GetIpV4ZeroConfMode,
+    GetIpV4CurrentAddress,
+    GetIpV4StaticAddress,
+    GetIpV4DefaultRoute,
+    GetDnsIpV4NameServer,
+    GetDnsHostName,
+    GetDnsDomainName,
+    // E1.37-7
+    GetEndpointList,
+    GetEndpointListChange,
+    GetIdentifyEndpoint,
+    SetIdentifyEndpoint,
+    GetEndpointToUniverse,
+    SetEndpointToUniverse,
+    GetEndpointMode,
+    SetEndpointMode,
+    GetEndpointLabel,
+    SetEndpointLabel,
+    GetRdmTrafficEnable,
+    SetRdmTrafficEnable,
+    GetDiscoveryState,
+    SetDiscoveryState,
+    GetBackgroundDiscovery,
+    SetBackgroundDiscovery,
+    GetEndpointTiming,
+    SetEndpointTiming,
+    GetEndpointTimingDescription,
+    GetEndpointResponders,
+    GetEndpointResponderListChange,
+    GetBindingControlFields,
+    GetBackgroundQueuedStatusPolicy,
+    GetBackgroundQueuedStatusPolicyDescription,
+    // E1.33
+    GetComponentScope,
+    GetSearchDomain,
+    GetTcpCommsStatus,
+    GetBrokerStatus,
+    ManufacturerSpecific,
+    Unsupported,
+}
+
+impl ResponseKind {
+    /// The kind of parameter data a responder ACKs with for a response command class and
+    /// parameter, or `None` if the ACK carries no parameter data or the parameter is unsupported
+    pub fn expected(command_class: CommandClass, parameter_id: ParameterId) -> Option<Self> {
+        match (command_class, parameter_id) {
+            (CommandClass::DiscoveryCommandResponse, ParameterId::DiscMute) => Some(Self::DiscMute),
+            (CommandClass::DiscoveryCommandResponse, ParameterId::DiscUnMute) => {
+                Some(Self::DiscUnMute)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::ProxiedDeviceCount) => {
+                Some(Self::GetProxiedDeviceCount)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::ProxiedDevices) => {
+                Some(Self::GetProxiedDevices)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::CommsStatus) => {
+                Some(Self::GetCommsStatus)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::StatusMessages) => {
+                Some(Self::GetStatusMessages)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::StatusIdDescription) => {
+                Some(Self::GetStatusIdDescription)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::SubDeviceIdStatusReportThreshold) => {
+                Some(Self::GetSubDeviceIdStatusReportThreshold)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::SupportedParameters) => {
+                Some(Self::GetSupportedParameters)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::ParameterDescription) => {
+                Some(Self::GetParameterDescription)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DeviceInfo) => {
+                Some(Self::GetDeviceInfo)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::ProductDetailIdList) => {
+                Some(Self::GetProductDetailIdList)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DeviceModelDescription) => {
+                Some(Self::GetDeviceModelDescription)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::ManufacturerLabel) => {
+                Some(Self::GetManufacturerLabel)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DeviceLabel) => {
+                Some(Self::GetDeviceLabel)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::FactoryDefaults) => {
+                Some(Self::GetFactoryDefaults)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::LanguageCapabilities) => {
+                Some(Self::GetLanguageCapabilities)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::Language) => Some(Self::GetLanguage),
+            (CommandClass::GetCommandResponse, ParameterId::SoftwareVersionLabel) => {
+                Some(Self::GetSoftwareVersionLabel)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::BootSoftwareVersionId) => {
+                Some(Self::GetBootSoftwareVersionId)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::BootSoftwareVersionLabel) => {
+                Some(Self::GetBootSoftwareVersionLabel)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DmxPersonality) => {
+                Some(Self::GetDmxPersonality)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DmxPersonalityDescription) => {
+                Some(Self::GetDmxPersonalityDescription)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DmxStartAddress) => {
+                Some(Self::GetDmxStartAddress)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::SlotInfo) => Some(Self::GetSlotInfo),
+            (CommandClass::GetCommandResponse, ParameterId::SlotDescription) => {
+                Some(Self::GetSlotDescription)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DefaultSlotValue) => {
+                Some(Self::GetDefaultSlotValue)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::SensorDefinition) => {
+                Some(Self::GetSensorDefinition)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::SensorValue) => {
+                Some(Self::GetSensorValue)
+            }
+            (CommandClass::SetCommandResponse, ParameterId::SensorValue) => {
+                Some(Self::SetSensorValue)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DeviceHours) => {
+                Some(Self::GetDeviceHours)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::LampHours) => Some(Self::GetLampHours),
+            (CommandClass::GetCommandResponse, ParameterId::LampStrikes) => {
+                Some(Self::GetLampStrikes)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::LampState) => Some(Self::GetLampState),
+            (CommandClass::GetCommandResponse, ParameterId::LampOnMode) => {
+                Some(Self::GetLampOnMode)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DevicePowerCycles) => {
+                Some(Self::GetDevicePowerCycles)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DisplayInvert) => {
+                Some(Self::GetDisplayInvert)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DisplayLevel) => {
+                Some(Self::GetDisplayLevel)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::PanInvert) => Some(Self::GetPanInvert),
+            (CommandClass::GetCommandResponse, ParameterId::TiltInvert) => {
+                Some(Self::GetTiltInvert)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::PanTiltSwap) => {
+                Some(Self::GetPanTiltSwap)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::RealTimeClock) => {
+                Some(Self::GetRealTimeClock)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::IdentifyDevice) => {
+                Some(Self::GetIdentifyDevice)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::PowerState) => {
+                Some(Self::GetPowerState)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::PerformSelfTest) => {
+                Some(Self::GetPerformSelfTest)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::SelfTestDescription) => {
+                Some(Self::GetSelfTestDescription)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::PresetPlayback) => {
+                Some(Self::GetPresetPlayback)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::IdentifyMode) => {
+                Some(Self::GetIdentifyMode)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DmxBlockAddress) => {
+                Some(Self::GetDmxBlockAddress)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DmxFailMode) => {
+                Some(Self::GetDmxFailMode)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DmxStartupMode) => {
+                Some(Self::GetDmxStartupMode)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::PowerOnSelfTest) => {
+                Some(Self::GetPowerOnSelfTest)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::LockState) => Some(Self::GetLockState),
+            (CommandClass::GetCommandResponse, ParameterId::LockStateDescription) => {
+                Some(Self::GetLockStateDescription)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::LockPin) => Some(Self::GetLockPin),
+            (CommandClass::GetCommandResponse, ParameterId::BurnIn) => Some(Self::GetBurnIn),
+            (CommandClass::GetCommandResponse, ParameterId::DimmerInfo) => {
+                Some(Self::GetDimmerInfo)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::MinimumLevel) => {
+                Some(Self::GetMinimumLevel)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::MaximumLevel) => {
+                Some(Self::GetMaximumLevel)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::Curve) => Some(Self::GetCurve),
+            (CommandClass::GetCommandResponse, ParameterId::CurveDescription) => {
+                Some(Self::GetCurveDescription)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::OutputResponseTime) => {
+                Some(Self::GetOutputResponseTime)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::OutputResponseTimeDescription) => {
+                Some(Self::GetOutputResponseTimeDescription)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::ModulationFrequency) => {
+                Some(Self::GetModulationFrequency)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::ModulationFrequencyDescription) => {
+                Some(Self::GetModulationFrequencyDescription)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::PresetInfo) => {
+                Some(Self::GetPresetInfo)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::PresetStatus) => {
+                Some(Self::GetPresetStatus)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::PresetMergeMode) => {
+                Some(Self::GetPresetMergeMode)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::ListInterfaces) => {
+                Some(Self::GetListInterfaces)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::InterfaceLabel) => {
+                Some(Self::GetInterfaceLabel)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::InterfaceHardwareAddressType1) => {
+                Some(Self::GetInterfaceHardwareAddressType1)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::IpV4DhcpMode) => {
+                Some(Self::GetIpV4DhcpMode)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::IpV4ZeroConfMode) => {
+                Some(Self::GetIpV4ZeroConfMode)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::IpV4CurrentAddress) => {
+                Some(Self::GetIpV4CurrentAddress)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::IpV4StaticAddress) => {
+                Some(Self::GetIpV4StaticAddress)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::IpV4DefaultRoute) => {
+                Some(Self::GetIpV4DefaultRoute)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DnsIpV4NameServer) => {
+                Some(Self::GetDnsIpV4NameServer)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DnsHostName) => {
+                Some(Self::GetDnsHostName)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DnsDomainName) => {
+                Some(Self::GetDnsDomainName)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::EndpointList) => {
+                Some(Self::GetEndpointList)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::EndpointListChange) => {
+                Some(Self::GetEndpointListChange)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::IdentifyEndpoint) => {
+                Some(Self::GetIdentifyEndpoint)
+            }
+            (CommandClass::SetCommandResponse, ParameterId::IdentifyEndpoint) => {
+                Some(Self::SetIdentifyEndpoint)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::EndpointToUniverse) => {
+                Some(Self::GetEndpointToUniverse)
+            }
+            (CommandClass::SetCommandResponse, ParameterId::EndpointToUniverse) => {
+                Some(Self::SetEndpointToUniverse)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::EndpointMode) => {
+                Some(Self::GetEndpointMode)
+            }
+            (CommandClass::SetCommandResponse, ParameterId::EndpointMode) => {
+                Some(Self::SetEndpointMode)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::EndpointLabel) => {
+                Some(Self::GetEndpointLabel)
+            }
+            (CommandClass::SetCommandResponse, ParameterId::EndpointLabel) => {
+                Some(Self::SetEndpointLabel)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::RdmTrafficEnable) => {
+                Some(Self::GetRdmTrafficEnable)
+            }
+            (CommandClass::SetCommandResponse, ParameterId::RdmTrafficEnable) => {
+                Some(Self::SetRdmTrafficEnable)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DiscoveryState) => {
+                Some(Self::GetDiscoveryState)
+            }
+            (CommandClass::SetCommandResponse, ParameterId::DiscoveryState) => {
+                Some(Self::SetDiscoveryState)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::BackgroundDiscovery) => {
+                Some(Self::GetBackgroundDiscovery)
+            }
+            (CommandClass::SetCommandResponse, ParameterId::BackgroundDiscovery) => {
+                Some(Self::SetBackgroundDiscovery)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::EndpointTiming) => {
+                Some(Self::GetEndpointTiming)
+            }
+            (CommandClass::SetCommandResponse, ParameterId::EndpointTiming) => {
+                Some(Self::SetEndpointTiming)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::EndpointTimingDescription) => {
+                Some(Self::GetEndpointTimingDescription)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::EndpointResponders) => {
+                Some(Self::GetEndpointResponders)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::EndpointResponderListChange) => {
+                Some(Self::GetEndpointResponderListChange)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::BindingControlFields) => {
+                Some(Self::GetBindingControlFields)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::BackgroundQueuedStatusPolicy) => {
+                Some(Self::GetBackgroundQueuedStatusPolicy)
+            }
+            (
+                CommandClass::GetCommandResponse,
+                ParameterId::BackgroundQueuedStatusPolicyDescription,
+            ) => Some(Self::GetBackgroundQueuedStatusPolicyDescription),
+            (CommandClass::GetCommandResponse, ParameterId::ComponentScope) => {
+                Some(Self::GetComponentScope)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::SearchDomain) => {
+                Some(Self::GetSearchDomain)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::TcpCommsStatus) => {
+                Some(Self::GetTcpCommsStatus)
+            }
+            (CommandClass::GetCommandResponse, ParameterId::BrokerStatus) => {
+                Some(Self::GetBrokerStatus)
+            }
+            (_, ParameterId::ManufacturerSpecific(_)) => Some(Self::ManufacturerSpecific),
+            _ => None,
+        }
+    }
+}
+
+// The most fields of any variant, GetPresetInfo
+const MAX_RESPONSE_FIELDS: usize = 19;
+
+/// A field value from `ResponseParameterData::fields`, types without a primitive representation
+/// are rendered with their `Debug` implementation
+#[derive(Copy, Clone, Debug)]
+pub enum FieldValue<'a> {
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    Str(&'a str),
+    Uid(DeviceUID),
+    Other(&'a dyn fmt::Debug),
+}
+
+impl fmt::Display for FieldValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(value) => write!(f, "{}", value),
+            Self::U8(value) => write!(f, "{}", value),
+            Self::U16(value) => write!(f, "{}", value),
+            Self::U32(value) => write!(f, "{}", value),
+            Self::Str(value) => write!(f, "{}", value),
+            Self::Uid(value) => write!(f, "{}", value),
+            Self::Other(value) => write!(f, "{:?}", value),
+        }
+    }
+}
+
 #[allow(clippy::large_enum_variant)]
 #[non_exhaustive]
 #[derive(Clone, Debug, PartialEq)]
 pub enum ResponseParameterData {
     // E1.20
     DiscMute {
-        control_field: u16,
+        control_field: DiscControlField,
         binding_uid: Option<DeviceUID>,
     },
     DiscUnMute {
-        control_field: u16,
+        control_field: DiscControlField,
         binding_uid: Option<DeviceUID>,
     },
     GetProxiedDeviceCount {
@@ -318,7 +817,7 @@ pub enum ResponseParameterData {
         #[cfg(feature = "alloc")] String,
         #[cfg(not(feature = "alloc"))] String<32>,
     ),
-    GetBootSoftwareVersionId(u32),
+    GetBootSoftwareVersionId(BootSoftwareVersion),
     GetBootSoftwareVersionLabel(
         #[cfg(feature = "alloc")] String,
         #[cfg(not(feature = "alloc"))] String<32>,
@@ -697,162 +1196,315 @@ pub enum ResponseParameterData {
 }
 
 impl ResponseParameterData {
-    pub fn encode(&self) -> EncodedParameterData {
-        #[cfg(feature = "alloc")]
-        let mut buf = Vec::new();
-
-        #[cfg(not(feature = "alloc"))]
-        let mut buf = Vec::new();
+    /// Returns the label or description of string-bearing parameters
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::GetStatusIdDescription(string)
+            | Self::GetDeviceModelDescription(string)
+            | Self::GetManufacturerLabel(string)
+            | Self::GetDeviceLabel(string)
+            | Self::GetSoftwareVersionLabel(string)
+            | Self::GetBootSoftwareVersionLabel(string)
+            | Self::GetDmxPersonalityDescription {
+                description: string,
+                ..
+            }
+            | Self::GetSlotDescription {
+                description: string,
+                ..
+            }
+            | Self::GetSelfTestDescription {
+                description: string,
+                ..
+            }
+            | Self::GetLockStateDescription {
+                description: string,
+                ..
+            }
+            | Self::GetCurveDescription {
+                description: string,
+                ..
+            }
+            | Self::GetOutputResponseTimeDescription {
+                description: string,
+                ..
+            }
+            | Self::GetModulationFrequencyDescription {
+                description: string,
+                ..
+            }
+            | Self::GetInterfaceLabel {
+                interface_label: string,
+                ..
+            }
+            | Self::GetEndpointLabel { label: string, .. }
+            | Self::GetEndpointTimingDescription {
+                description: string,
+                ..
+            }
+            | Self::GetBackgroundQueuedStatusPolicyDescription {
+                description: string,
+                ..
+            } => Some(string),
+            Self::GetLanguage(string) => Some(string),
+            Self::GetDnsHostName(string) => Some(string),
+            Self::GetDnsDomainName(string) | Self::GetSearchDomain(string) => Some(string),
+            Self::GetComponentScope { scope_string, .. } => Some(scope_string),
+            Self::GetTcpCommsStatus { scope_string, .. } => Some(scope_string),
+            Self::GetParameterDescription(description) => Some(&description.description),
+            Self::GetSensorDefinition(definition) => Some(&definition.description),
+            _ => None,
+        }
+    }
 
+    /// The DMX512 addresses occupied by a device, from the start address and footprint
+    /// reported in DEVICE_INFO. Returns `None` for devices without a DMX footprint.
+    pub fn dmx_address_range(&self) -> Option<RangeInclusive<u16>> {
         match self {
-            Self::DiscMute {
-                control_field,
-                binding_uid,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(0x0e);
+            Self::GetDeviceInfo {
+                footprint,
+                start_address,
+                ..
+            } if *footprint > 0 && *start_address != 0xffff => {
+                Some(*start_address..=start_address.saturating_add(footprint - 1))
+            }
+            _ => None,
+        }
+    }
 
-                buf.extend(control_field.to_be_bytes());
+    /// Whether the sub-device count reported in DEVICE_INFO is within
+    /// `SubDeviceId::MAX_SUB_DEVICES`, a guard before allocating state for each sub-device of a
+    /// misbehaving responder. Returns `false` for any other parameter data.
+    pub fn valid_sub_device_count(&self) -> bool {
+        matches!(
+            self,
+            Self::GetDeviceInfo {
+                sub_device_count,
+                ..
+            } if *sub_device_count <= SubDeviceId::MAX_SUB_DEVICES
+        )
+    }
 
-                if let Some(binding_uid) = binding_uid {
-                    buf.extend(binding_uid.manufacturer_id.to_be_bytes());
-                    buf.extend(binding_uid.device_id.to_be_bytes());
-                }
+    /// Returns the variant of this parameter data, without its data
+    pub fn kind(&self) -> ResponseKind {
+        match self {
+            // E1.20
+            Self::DiscMute { .. } => ResponseKind::DiscMute,
+            Self::DiscUnMute { .. } => ResponseKind::DiscUnMute,
+            Self::GetProxiedDeviceCount { .. } => ResponseKind::GetProxiedDeviceCount,
+            Self::GetProxiedDevices { .. } => ResponseKind::GetProxiedDevices,
+            Self::GetCommsStatus { .. } => ResponseKind::GetCommsStatus,
+            Self::GetStatusMessages { .. } => ResponseKind::GetStatusMessages,
+            Self::GetStatusIdDescription { .. } => ResponseKind::GetStatusIdDescription,
+            Self::GetSubDeviceIdStatusReportThreshold { .. } => {
+                ResponseKind::GetSubDeviceIdStatusReportThreshold
+            }
+            Self::GetSupportedParameters { .. } => ResponseKind::GetSupportedParameters,
+            Self::GetParameterDescription { .. } => ResponseKind::GetParameterDescription,
+            Self::GetDeviceInfo { .. } => ResponseKind::GetDeviceInfo,
+            Self::GetProductDetailIdList { .. } => ResponseKind::GetProductDetailIdList,
+            Self::GetDeviceModelDescription { .. } => ResponseKind::GetDeviceModelDescription,
+            Self::GetManufacturerLabel { .. } => ResponseKind::GetManufacturerLabel,
+            Self::GetDeviceLabel { .. } => ResponseKind::GetDeviceLabel,
+            Self::GetFactoryDefaults { .. } => ResponseKind::GetFactoryDefaults,
+            Self::GetLanguageCapabilities { .. } => ResponseKind::GetLanguageCapabilities,
+            Self::GetLanguage { .. } => ResponseKind::GetLanguage,
+            Self::GetSoftwareVersionLabel { .. } => ResponseKind::GetSoftwareVersionLabel,
+            Self::GetBootSoftwareVersionId { .. } => ResponseKind::GetBootSoftwareVersionId,
+            Self::GetBootSoftwareVersionLabel { .. } => ResponseKind::GetBootSoftwareVersionLabel,
+            Self::GetDmxPersonality { .. } => ResponseKind::GetDmxPersonality,
+            Self::GetDmxPersonalityDescription { .. } => ResponseKind::GetDmxPersonalityDescription,
+            Self::GetDmxStartAddress { .. } => ResponseKind::GetDmxStartAddress,
+            Self::GetSlotInfo { .. } => ResponseKind::GetSlotInfo,
+            Self::GetSlotDescription { .. } => ResponseKind::GetSlotDescription,
+            Self::GetDefaultSlotValue { .. } => ResponseKind::GetDefaultSlotValue,
+            Self::GetSensorDefinition { .. } => ResponseKind::GetSensorDefinition,
+            Self::GetSensorValue { .. } => ResponseKind::GetSensorValue,
+            Self::SetSensorValue { .. } => ResponseKind::SetSensorValue,
+            Self::GetDeviceHours { .. } => ResponseKind::GetDeviceHours,
+            Self::GetLampHours { .. } => ResponseKind::GetLampHours,
+            Self::GetLampStrikes { .. } => ResponseKind::GetLampStrikes,
+            Self::GetLampState { .. } => ResponseKind::GetLampState,
+            Self::GetLampOnMode { .. } => ResponseKind::GetLampOnMode,
+            Self::GetDevicePowerCycles { .. } => ResponseKind::GetDevicePowerCycles,
+            Self::GetDisplayInvert { .. } => ResponseKind::GetDisplayInvert,
+            Self::GetDisplayLevel { .. } => ResponseKind::GetDisplayLevel,
+            Self::GetPanInvert { .. } => ResponseKind::GetPanInvert,
+            Self::GetTiltInvert { .. } => ResponseKind::GetTiltInvert,
+            Self::GetPanTiltSwap { .. } => ResponseKind::GetPanTiltSwap,
+            Self::GetRealTimeClock { .. } => ResponseKind::GetRealTimeClock,
+            Self::GetIdentifyDevice { .. } => ResponseKind::GetIdentifyDevice,
+            Self::GetPowerState { .. } => ResponseKind::GetPowerState,
+            Self::GetPerformSelfTest { .. } => ResponseKind::GetPerformSelfTest,
+            Self::GetSelfTestDescription { .. } => ResponseKind::GetSelfTestDescription,
+            Self::GetPresetPlayback { .. } => ResponseKind::GetPresetPlayback,
+            // E1.37-1
+            Self::GetIdentifyMode { .. } => ResponseKind::GetIdentifyMode,
+            Self::GetDmxBlockAddress { .. } => ResponseKind::GetDmxBlockAddress,
+            Self::GetDmxFailMode { .. } => ResponseKind::GetDmxFailMode,
+            Self::GetDmxStartupMode { .. } => ResponseKind::GetDmxStartupMode,
+            Self::GetPowerOnSelfTest { .. } => ResponseKind::GetPowerOnSelfTest,
+            Self::GetLockState { .. } => ResponseKind::GetLockState,
+            Self::GetLockStateDescription { .. } => ResponseKind::GetLockStateDescription,
+            Self::GetLockPin { .. } => ResponseKind::GetLockPin,
+            Self::GetBurnIn { .. } => ResponseKind::GetBurnIn,
+            Self::GetDimmerInfo { .. } => ResponseKind::GetDimmerInfo,
+            Self::GetMinimumLevel { .. } => ResponseKind::GetMinimumLevel,
+            Self::GetMaximumLevel { .. } => ResponseKind::GetMaximumLevel,
+            Self::GetCurve { .. } => ResponseKind::GetCurve,
+            Self::GetCurveDescription { .. } => ResponseKind::GetCurveDescription,
+            Self::GetOutputResponseTime { .. } => ResponseKind::GetOutputResponseTime,
+            Self::GetOutputResponseTimeDescription { .. } => {
+                ResponseKind::GetOutputResponseTimeDescription
+            }
+            Self::GetModulationFrequency { .. } => ResponseKind::GetModulationFrequency,
+            Self::GetModulationFrequencyDescription { .. } => {
+                ResponseKind::GetModulationFrequencyDescription
+            }
+            Self::GetPresetInfo { .. } => ResponseKind::GetPresetInfo,
+            Self::GetPresetStatus { .. } => ResponseKind::GetPresetStatus,
+            Self::GetPresetMergeMode { .. } => ResponseKind::GetPresetMergeMode,
+            // E1.37-2
+            Self::GetListInterfaces { .. } => ResponseKind::GetListInterfaces,
+            Self::GetInterfaceLabel { .. } => ResponseKind::GetInterfaceLabel,
+            Self::GetInterfaceHardwareAddressType1 { .. } => {
+                ResponseKind::GetInterfaceHardwareAddressType1
+            }
+            Self::GetIpV4DhcpMode { .. } => ResponseKind::GetIpV4DhcpMode,
+            Self::GetIpV4ZeroConfMode { .. } => ResponseKind::GetIpV4ZeroConfMode,
+            Self::GetIpV4CurrentAddress { .. } => ResponseKind::GetIpV4CurrentAddress,
+            Self::GetIpV4StaticAddress { .. } => ResponseKind::GetIpV4StaticAddress,
+            Self::GetIpV4DefaultRoute { .. } => ResponseKind::GetIpV4DefaultRoute,
+            Self::GetDnsIpV4NameServer { .. } => ResponseKind::GetDnsIpV4NameServer,
+            Self::GetDnsHostName { .. } => ResponseKind::GetDnsHostName,
+            Self::GetDnsDomainName { .. } => ResponseKind::GetDnsDomainName,
+            // E1.37-7
+            Self::GetEndpointList { .. } => ResponseKind::GetEndpointList,
+            Self::GetEndpointListChange { .. } => ResponseKind::GetEndpointListChange,
+            Self::GetIdentifyEndpoint { .. } => ResponseKind::GetIdentifyEndpoint,
+            Self::SetIdentifyEndpoint { .. } => ResponseKind::SetIdentifyEndpoint,
+            Self::GetEndpointToUniverse { .. } => ResponseKind::GetEndpointToUniverse,
+            Self::SetEndpointToUniverse { .. } => ResponseKind::SetEndpointToUniverse,
+            Self::GetEndpointMode { .. } => ResponseKind::GetEndpointMode,
+            Self::SetEndpointMode { .. } => ResponseKind::SetEndpointMode,
+            Self::GetEndpointLabel { .. } => ResponseKind::GetEndpointLabel,
+            Self::SetEndpointLabel { .. } => ResponseKind::SetEndpointLabel,
+            Self::GetRdmTrafficEnable { .. } => ResponseKind::GetRdmTrafficEnable,
+            Self::SetRdmTrafficEnable { .. } => ResponseKind::SetRdmTrafficEnable,
+            Self::GetDiscoveryState { .. } => ResponseKind::GetDiscoveryState,
+            Self::SetDiscoveryState { .. } => ResponseKind::SetDiscoveryState,
+            Self::GetBackgroundDiscovery { .. } => ResponseKind::GetBackgroundDiscovery,
+            Self::SetBackgroundDiscovery { .. } => ResponseKind::SetBackgroundDiscovery,
+            Self::GetEndpointTiming { .. } => ResponseKind::GetEndpointTiming,
+            Self::SetEndpointTiming { .. } => ResponseKind::SetEndpointTiming,
+            Self::GetEndpointTimingDescription { .. } => ResponseKind::GetEndpointTimingDescription,
+            Self::GetEndpointResponders { .. } => ResponseKind::GetEndpointResponders,
+            Self::GetEndpointResponderListChange { .. } => {
+                ResponseKind::GetEndpointResponderListChange
+            }
+            Self::GetBindingControlFields { .. } => ResponseKind::GetBindingControlFields,
+            Self::GetBackgroundQueuedStatusPolicy { .. } => {
+                ResponseKind::GetBackgroundQueuedStatusPolicy
+            }
+            Self::GetBackgroundQueuedStatusPolicyDescription { .. } => {
+                ResponseKind::GetBackgroundQueuedStatusPolicyDescription
             }
+            // E1.33
+            Self::GetComponentScope { .. } => ResponseKind::GetComponentScope,
+            Self::GetSearchDomain { .. } => ResponseKind::GetSearchDomain,
+            Self::GetTcpCommsStatus { .. } => ResponseKind::GetTcpCommsStatus,
+            Self::GetBrokerStatus { .. } => ResponseKind::GetBrokerStatus,
+            Self::ManufacturerSpecific { .. } => ResponseKind::ManufacturerSpecific,
+            Self::Unsupported { .. } => ResponseKind::Unsupported,
+        }
+    }
+
+    /// Returns the counters of a GET COMMS_STATUS response
+    pub fn comms_status(&self) -> Option<CommsStatus> {
+        match self {
+            Self::GetCommsStatus {
+                short_message,
+                length_mismatch,
+                checksum_fail,
+            } => Some(CommsStatus::new(
+                *short_message,
+                *length_mismatch,
+                *checksum_fail,
+            )),
+            _ => None,
+        }
+    }
+
+    pub fn personality(&self) -> Option<Personality> {
+        match self {
+            Self::GetDmxPersonalityDescription {
+                id,
+                dmx_slots_required,
+                description,
+            } => Some(Personality {
+                id: *id,
+                dmx_slots_required: *dmx_slots_required,
+                description: description.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Returns the name and value of each field, for logging responses without matching on
+    /// each variant. Tuple variants are named after the parameter.
+    pub fn fields(&self) -> impl Iterator<Item = (&'static str, FieldValue<'_>)> {
+        let mut fields = heapless::Vec::<_, MAX_RESPONSE_FIELDS>::new();
+
+        match self {
+            // E1.20
+            Self::DiscMute {
+                control_field,
+                binding_uid,
+            } => fields.extend([
+                ("control_field", FieldValue::Other(control_field)),
+                ("binding_uid", FieldValue::Other(binding_uid)),
+            ]),
             Self::DiscUnMute {
                 control_field,
                 binding_uid,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(0x0e);
-
-                buf.extend(control_field.to_be_bytes());
-
-                if let Some(binding_uid) = binding_uid {
-                    buf.extend(binding_uid.manufacturer_id.to_be_bytes());
-                    buf.extend(binding_uid.device_id.to_be_bytes());
-                }
-            }
+            } => fields.extend([
+                ("control_field", FieldValue::Other(control_field)),
+                ("binding_uid", FieldValue::Other(binding_uid)),
+            ]),
             Self::GetProxiedDeviceCount {
                 device_count,
                 list_change,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(0x03);
-
-                buf.extend(device_count.to_be_bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*list_change as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*list_change as u8).unwrap();
-            }
-            Self::GetProxiedDevices(devices) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(devices.len() * 6);
-
-                for device in devices {
-                    buf.extend(device.manufacturer_id.to_be_bytes());
-                    buf.extend(device.device_id.to_be_bytes());
-                }
+            } => fields.extend([
+                ("device_count", FieldValue::U16(*device_count)),
+                ("list_change", FieldValue::Bool(*list_change)),
+            ]),
+            Self::GetProxiedDevices(value) => {
+                fields.extend([("proxied_devices", FieldValue::Other(value))])
             }
             Self::GetCommsStatus {
                 short_message,
                 length_mismatch,
                 checksum_fail,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(6);
-
-                buf.extend(short_message.to_be_bytes());
-                buf.extend(length_mismatch.to_be_bytes());
-                buf.extend(checksum_fail.to_be_bytes());
-            }
-            Self::GetStatusMessages(messages) => {
-                for message in messages {
-                    buf.extend(u16::from(message.sub_device_id).to_be_bytes());
-
-                    #[cfg(feature = "alloc")]
-                    buf.push(message.status_type as u8);
-                    #[cfg(not(feature = "alloc"))]
-                    buf.push(message.status_type as u8).unwrap();
-
-                    buf.extend(message.status_message_id.to_be_bytes());
-                    buf.extend(message.data_value1.to_be_bytes());
-                    buf.extend(message.data_value2.to_be_bytes());
-
-                    if let Some(description) = &message.description {
-                        buf.extend(description.bytes());
-                    }
-                }
-            }
-            Self::GetStatusIdDescription(description) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(description.len());
-
-                buf.extend(description.bytes());
-            }
-            Self::GetSubDeviceIdStatusReportThreshold(status) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*status as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*status as u8).unwrap();
-            }
-            Self::GetSupportedParameters(parameters) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(parameters.len() * 2);
-
-                for parameter in parameters {
-                    buf.extend(parameter.to_be_bytes());
-                }
-            }
-            Self::GetParameterDescription(description) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(20 + description.description.len());
-
-                buf.extend(description.parameter_id.to_be_bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push(description.parameter_data_length);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(description.parameter_data_length).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(description.data_type.into());
-                #[cfg(not(feature = "alloc"))]
-                buf.push(description.data_type.into()).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(description.command_class as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(description.command_class as u8).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(description.command_class as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(description.command_class as u8).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(description.unit_type.into());
-                #[cfg(not(feature = "alloc"))]
-                buf.push(description.unit_type.into()).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(description.prefix as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(description.prefix as u8).unwrap();
-
-                buf.extend(description.raw_minimum_valid_value);
-                buf.extend(description.raw_maximum_valid_value);
-                buf.extend(description.raw_default_value);
-
-                #[cfg(feature = "alloc")]
-                buf.extend(description.description.bytes());
-                #[cfg(not(feature = "alloc"))]
-                buf.extend(description.description.bytes());
+            } => fields.extend([
+                ("short_message", FieldValue::U16(*short_message)),
+                ("length_mismatch", FieldValue::U16(*length_mismatch)),
+                ("checksum_fail", FieldValue::U16(*checksum_fail)),
+            ]),
+            Self::GetStatusMessages(value) => {
+                fields.extend([("status_messages", FieldValue::Other(value))])
+            }
+            Self::GetStatusIdDescription(value) => {
+                fields.extend([("status_id_description", FieldValue::Str(value))])
+            }
+            Self::GetSubDeviceIdStatusReportThreshold(value) => fields.extend([(
+                "sub_device_id_status_report_threshold",
+                FieldValue::Other(value),
+            )]),
+            Self::GetSupportedParameters(value) => {
+                fields.extend([("supported_parameters", FieldValue::Other(value))])
+            }
+            Self::GetParameterDescription(value) => {
+                fields.extend([("parameter_description", FieldValue::Other(value))])
             }
             Self::GetDeviceInfo {
                 protocol_version,
@@ -865,336 +1517,111 @@ impl ResponseParameterData {
                 start_address,
                 sub_device_count,
                 sensor_count,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(21);
-
-                buf.extend(u16::from(*protocol_version).to_be_bytes());
-
-                buf.extend(model_id.to_be_bytes());
-                buf.extend(u16::from(*product_category).to_be_bytes());
-                buf.extend(software_version_id.to_be_bytes());
-                buf.extend(footprint.to_be_bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*current_personality);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*current_personality).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(*personality_count);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*personality_count).unwrap();
-
-                buf.extend(start_address.to_be_bytes());
-                buf.extend(sub_device_count.to_be_bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*sensor_count);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*sensor_count).unwrap();
+            } => fields.extend([
+                ("protocol_version", FieldValue::Other(protocol_version)),
+                ("model_id", FieldValue::U16(*model_id)),
+                ("product_category", FieldValue::Other(product_category)),
+                ("software_version_id", FieldValue::U32(*software_version_id)),
+                ("footprint", FieldValue::U16(*footprint)),
+                ("current_personality", FieldValue::U8(*current_personality)),
+                ("personality_count", FieldValue::U8(*personality_count)),
+                ("start_address", FieldValue::U16(*start_address)),
+                ("sub_device_count", FieldValue::U16(*sub_device_count)),
+                ("sensor_count", FieldValue::U8(*sensor_count)),
+            ]),
+            Self::GetProductDetailIdList(value) => {
+                fields.extend([("product_detail_id_list", FieldValue::Other(value))])
+            }
+            Self::GetDeviceModelDescription(value) => {
+                fields.extend([("device_model_description", FieldValue::Str(value))])
+            }
+            Self::GetManufacturerLabel(value) => {
+                fields.extend([("manufacturer_label", FieldValue::Str(value))])
+            }
+            Self::GetDeviceLabel(value) => {
+                fields.extend([("device_label", FieldValue::Str(value))])
+            }
+            Self::GetFactoryDefaults(value) => {
+                fields.extend([("factory_defaults", FieldValue::Bool(*value))])
+            }
+            Self::GetLanguageCapabilities(value) => {
+                fields.extend([("language_capabilities", FieldValue::Other(value))])
+            }
+            Self::GetLanguage(value) => fields.extend([("language", FieldValue::Str(value))]),
+            Self::GetSoftwareVersionLabel(value) => {
+                fields.extend([("software_version_label", FieldValue::Str(value))])
+            }
+            Self::GetBootSoftwareVersionId(value) => {
+                fields.extend([("boot_software_version_id", FieldValue::Other(value))])
+            }
+            Self::GetBootSoftwareVersionLabel(value) => {
+                fields.extend([("boot_software_version_label", FieldValue::Str(value))])
             }
-            Self::GetProductDetailIdList(details) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(details.len() * 2);
-
-                for &detail in details {
-                    buf.extend(u16::from(detail).to_be_bytes());
-                }
+            Self::GetDmxPersonality {
+                current_personality,
+                personality_count,
+            } => fields.extend([
+                ("current_personality", FieldValue::U8(*current_personality)),
+                ("personality_count", FieldValue::U8(*personality_count)),
+            ]),
+            Self::GetDmxPersonalityDescription {
+                id,
+                dmx_slots_required,
+                description,
+            } => fields.extend([
+                ("id", FieldValue::U8(*id)),
+                ("dmx_slots_required", FieldValue::U16(*dmx_slots_required)),
+                ("description", FieldValue::Str(description)),
+            ]),
+            Self::GetDmxStartAddress(value) => {
+                fields.extend([("dmx_start_address", FieldValue::U16(*value))])
+            }
+            Self::GetSlotInfo(value) => fields.extend([("slot_info", FieldValue::Other(value))]),
+            Self::GetSlotDescription {
+                slot_id,
+                description,
+            } => fields.extend([
+                ("slot_id", FieldValue::U16(*slot_id)),
+                ("description", FieldValue::Str(description)),
+            ]),
+            Self::GetDefaultSlotValue(value) => {
+                fields.extend([("default_slot_value", FieldValue::Other(value))])
             }
-            Self::GetDeviceModelDescription(description) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(description.len());
-
-                buf.extend(description.bytes());
+            Self::GetSensorDefinition(value) => {
+                fields.extend([("sensor_definition", FieldValue::Other(value))])
             }
-            Self::GetManufacturerLabel(label) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(label.len());
-
-                buf.extend(label.bytes());
+            Self::GetSensorValue(value) => {
+                fields.extend([("sensor_value", FieldValue::Other(value))])
             }
-            Self::GetDeviceLabel(label) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(label.len());
-
-                buf.extend(label.bytes());
+            Self::SetSensorValue(value) => {
+                fields.extend([("sensor_value", FieldValue::Other(value))])
             }
-            Self::GetFactoryDefaults(defaults) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*defaults as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*defaults as u8).unwrap();
+            Self::GetDeviceHours(value) => {
+                fields.extend([("device_hours", FieldValue::U32(*value))])
             }
-            Self::GetLanguageCapabilities(languages) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(languages.len() * 2);
-
-                for language in languages {
-                    buf.extend(language.bytes());
-                }
+            Self::GetLampHours(value) => fields.extend([("lamp_hours", FieldValue::U32(*value))]),
+            Self::GetLampStrikes(value) => {
+                fields.extend([("lamp_strikes", FieldValue::U32(*value))])
             }
-            Self::GetLanguage(language) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(2);
-
-                buf.extend(language.bytes());
+            Self::GetLampState(value) => fields.extend([("lamp_state", FieldValue::Other(value))]),
+            Self::GetLampOnMode(value) => {
+                fields.extend([("lamp_on_mode", FieldValue::Other(value))])
             }
-            Self::GetSoftwareVersionLabel(label) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(label.len());
-
-                buf.extend(label.bytes());
+            Self::GetDevicePowerCycles(value) => {
+                fields.extend([("device_power_cycles", FieldValue::U32(*value))])
             }
-            Self::GetBootSoftwareVersionId(version_id) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(4);
-
-                buf.extend(version_id.to_be_bytes());
+            Self::GetDisplayInvert(value) => {
+                fields.extend([("display_invert", FieldValue::Other(value))])
             }
-            Self::GetBootSoftwareVersionLabel(label) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(label.len());
-
-                buf.extend(label.bytes());
+            Self::GetDisplayLevel(value) => {
+                fields.extend([("display_level", FieldValue::U8(*value))])
             }
-            Self::GetDmxPersonality {
-                current_personality,
-                personality_count,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(2);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*current_personality);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*current_personality).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(*personality_count);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*personality_count).unwrap();
-            }
-            Self::GetDmxPersonalityDescription {
-                id,
-                dmx_slots_required,
-                description,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(3 + description.len());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*id);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*id).unwrap();
-
-                buf.extend(dmx_slots_required.to_be_bytes());
-                buf.extend(description.bytes());
-            }
-            Self::GetDmxStartAddress(address) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(2);
-
-                buf.extend(address.to_be_bytes());
-            }
-            Self::GetSlotInfo(slots) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(slots.len() * 5);
-
-                for slot in slots {
-                    buf.extend(slot.id.to_be_bytes());
-
-                    #[cfg(feature = "alloc")]
-                    buf.push(slot.r#type.into());
-                    #[cfg(not(feature = "alloc"))]
-                    buf.push(slot.r#type.into()).unwrap();
-
-                    buf.extend(slot.label_id.to_be_bytes());
-                }
-            }
-            Self::GetSlotDescription {
-                slot_id,
-                description,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(2 + description.len());
-
-                buf.extend(slot_id.to_be_bytes());
-                buf.extend(description.bytes());
-            }
-            Self::GetDefaultSlotValue(values) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(values.len() * 3);
-
-                for slot in values {
-                    buf.extend(slot.id.to_be_bytes());
-
-                    #[cfg(feature = "alloc")]
-                    buf.push(slot.value);
-                    #[cfg(not(feature = "alloc"))]
-                    buf.push(slot.value).unwrap();
-                }
-            }
-            Self::GetSensorDefinition(definition) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(14 + definition.description.len());
-
-                #[cfg(feature = "alloc")]
-                buf.push(definition.kind.into());
-                #[cfg(not(feature = "alloc"))]
-                buf.push(definition.kind.into()).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(definition.unit.into());
-                #[cfg(not(feature = "alloc"))]
-                buf.push(definition.unit.into()).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(definition.prefix as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(definition.prefix as u8).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(definition.prefix as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(definition.prefix as u8).unwrap();
-
-                buf.extend(definition.range_minimum_value.to_be_bytes());
-                buf.extend(definition.range_maximum_value.to_be_bytes());
-                buf.extend(definition.normal_minimum_value.to_be_bytes());
-                buf.extend(definition.normal_maximum_value.to_be_bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push(definition.is_lowest_highest_detected_value_supported as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(definition.is_lowest_highest_detected_value_supported as u8)
-                    .unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(definition.is_recorded_value_supported as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(definition.is_recorded_value_supported as u8)
-                    .unwrap();
-
-                buf.extend(definition.description.bytes());
-            }
-            Self::GetSensorValue(sensor_value) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(9);
-
-                #[cfg(feature = "alloc")]
-                buf.push(sensor_value.sensor_id);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(sensor_value.sensor_id).unwrap();
-
-                buf.extend(sensor_value.current_value.to_be_bytes());
-                buf.extend(sensor_value.lowest_detected_value.to_be_bytes());
-                buf.extend(sensor_value.highest_detected_value.to_be_bytes());
-                buf.extend(sensor_value.recorded_value.to_be_bytes());
-            }
-            Self::SetSensorValue(sensor_value) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(9);
-
-                #[cfg(feature = "alloc")]
-                buf.push(sensor_value.sensor_id);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(sensor_value.sensor_id).unwrap();
-
-                buf.extend(sensor_value.current_value.to_be_bytes());
-                buf.extend(sensor_value.lowest_detected_value.to_be_bytes());
-                buf.extend(sensor_value.highest_detected_value.to_be_bytes());
-                buf.extend(sensor_value.recorded_value.to_be_bytes());
-            }
-            Self::GetDeviceHours(hours) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(4);
-
-                buf.extend(hours.to_be_bytes());
-            }
-            Self::GetLampHours(hours) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(4);
-
-                buf.extend(hours.to_be_bytes());
-            }
-            Self::GetLampStrikes(strikes) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(4);
-
-                buf.extend(strikes.to_be_bytes());
-            }
-            Self::GetLampState(state) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1);
-
-                #[cfg(feature = "alloc")]
-                buf.push((*state).into());
-                #[cfg(not(feature = "alloc"))]
-                buf.push((*state).into()).unwrap();
-            }
-            Self::GetLampOnMode(mode) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1);
-
-                #[cfg(feature = "alloc")]
-                buf.push((*mode).into());
-                #[cfg(not(feature = "alloc"))]
-                buf.push((*mode).into()).unwrap();
-            }
-            Self::GetDevicePowerCycles(cycles) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(4);
-
-                buf.extend(cycles.to_be_bytes());
-            }
-            Self::GetDisplayInvert(mode) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*mode as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*mode as u8).unwrap();
-            }
-            Self::GetDisplayLevel(level) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*level);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*level).unwrap();
-            }
-            Self::GetPanInvert(invert) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*invert as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*invert as u8).unwrap();
-            }
-            Self::GetTiltInvert(invert) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*invert as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*invert as u8).unwrap();
+            Self::GetPanInvert(value) => fields.extend([("pan_invert", FieldValue::Bool(*value))]),
+            Self::GetTiltInvert(value) => {
+                fields.extend([("tilt_invert", FieldValue::Bool(*value))])
             }
-            Self::GetPanTiltSwap(swap) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*swap as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*swap as u8).unwrap();
+            Self::GetPanTiltSwap(value) => {
+                fields.extend([("pan_tilt_swap", FieldValue::Bool(*value))])
             }
             Self::GetRealTimeClock {
                 year,
@@ -1203,199 +1630,92 @@ impl ResponseParameterData {
                 hour,
                 minute,
                 second,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(0x07);
-
-                buf.extend((*year).to_be_bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*month);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*month).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(*day);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*day).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(*hour);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*hour).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(*minute);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*minute).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(*second);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*second).unwrap();
-            }
-            Self::GetIdentifyDevice(identifying) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*identifying as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*identifying as u8).unwrap();
-            }
-            Self::GetPowerState(state) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*state as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*state as u8).unwrap();
-            }
-            Self::GetPerformSelfTest(test) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*test as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*test as u8).unwrap();
+            } => fields.extend([
+                ("year", FieldValue::U16(*year)),
+                ("month", FieldValue::U8(*month)),
+                ("day", FieldValue::U8(*day)),
+                ("hour", FieldValue::U8(*hour)),
+                ("minute", FieldValue::U8(*minute)),
+                ("second", FieldValue::U8(*second)),
+            ]),
+            Self::GetIdentifyDevice(value) => {
+                fields.extend([("identify_device", FieldValue::Bool(*value))])
+            }
+            Self::GetPowerState(value) => {
+                fields.extend([("power_state", FieldValue::Other(value))])
+            }
+            Self::GetPerformSelfTest(value) => {
+                fields.extend([("perform_self_test", FieldValue::Bool(*value))])
             }
             Self::GetSelfTestDescription {
                 self_test_id,
                 description,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1 + description.len());
-
-                #[cfg(feature = "alloc")]
-                buf.push((*self_test_id).into());
-                #[cfg(not(feature = "alloc"))]
-                buf.push((*self_test_id).into()).unwrap();
-
-                buf.extend(description.bytes());
-            }
-            Self::GetPresetPlayback { mode, level } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(3);
-
-                buf.extend(u16::from(*mode).to_be_bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*level);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*level).unwrap();
+            } => fields.extend([
+                ("self_test_id", FieldValue::Other(self_test_id)),
+                ("description", FieldValue::Str(description)),
+            ]),
+            Self::GetPresetPlayback { mode, level } => fields.extend([
+                ("mode", FieldValue::Other(mode)),
+                ("level", FieldValue::U8(*level)),
+            ]),
+            // E1.37-1
+            Self::GetIdentifyMode(value) => {
+                fields.extend([("identify_mode", FieldValue::Other(value))])
             }
-            Self::GetIdentifyMode(identify_mode) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*identify_mode as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*identify_mode as u8).unwrap();
-            },
             Self::GetDmxBlockAddress {
                 total_sub_device_footprint,
                 base_dmx_address,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(4);
-
-                buf.extend(total_sub_device_footprint.to_be_bytes());
-                buf.extend(base_dmx_address.to_be_bytes());
-            }
+            } => fields.extend([
+                (
+                    "total_sub_device_footprint",
+                    FieldValue::U16(*total_sub_device_footprint),
+                ),
+                ("base_dmx_address", FieldValue::U16(*base_dmx_address)),
+            ]),
             Self::GetDmxFailMode {
                 scene_id,
                 loss_of_signal_delay,
                 hold_time,
                 level,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(7);
-
-                buf.extend(u16::from(*scene_id).to_be_bytes());
-                buf.extend(u16::from(*loss_of_signal_delay).to_be_bytes());
-                buf.extend(u16::from(*hold_time).to_be_bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*level);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*level).unwrap();
-            }
+            } => fields.extend([
+                ("scene_id", FieldValue::Other(scene_id)),
+                (
+                    "loss_of_signal_delay",
+                    FieldValue::Other(loss_of_signal_delay),
+                ),
+                ("hold_time", FieldValue::Other(hold_time)),
+                ("level", FieldValue::U8(*level)),
+            ]),
             Self::GetDmxStartupMode {
                 scene_id,
                 startup_delay,
                 hold_time,
                 level,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(7);
-
-                buf.extend(u16::from(*scene_id).to_be_bytes());
-                buf.extend(u16::from(*startup_delay).to_be_bytes());
-                buf.extend(u16::from(*hold_time).to_be_bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*level);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*level).unwrap();
-            }
-            Self::GetPowerOnSelfTest(test) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*test as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*test as u8).unwrap();
+            } => fields.extend([
+                ("scene_id", FieldValue::Other(scene_id)),
+                ("startup_delay", FieldValue::Other(startup_delay)),
+                ("hold_time", FieldValue::Other(hold_time)),
+                ("level", FieldValue::U8(*level)),
+            ]),
+            Self::GetPowerOnSelfTest(value) => {
+                fields.extend([("power_on_self_test", FieldValue::Bool(*value))])
             }
             Self::GetLockState {
                 lock_state_id,
                 lock_state_count,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(2);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*lock_state_id);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*lock_state_id).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(*lock_state_count);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*lock_state_count).unwrap();
-            }
+            } => fields.extend([
+                ("lock_state_id", FieldValue::U8(*lock_state_id)),
+                ("lock_state_count", FieldValue::U8(*lock_state_count)),
+            ]),
             Self::GetLockStateDescription {
                 lock_state_id,
                 description,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1 + description.len());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*lock_state_id);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*lock_state_id).unwrap();
-
-                buf.extend(description.bytes());
-            }
-            Self::GetLockPin(pin) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(4);
-
-                buf.extend(pin.0.to_be_bytes());
-            }
-            Self::GetBurnIn(hours) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*hours);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*hours).unwrap();
-            }
+            } => fields.extend([
+                ("lock_state_id", FieldValue::U8(*lock_state_id)),
+                ("description", FieldValue::Str(description)),
+            ]),
+            Self::GetLockPin(value) => fields.extend([("lock_pin", FieldValue::Other(value))]),
+            Self::GetBurnIn(value) => fields.extend([("burn_in", FieldValue::U8(*value))]),
             Self::GetDimmerInfo {
                 minimum_level_lower_limit,
                 minimum_level_upper_limit,
@@ -1404,148 +1724,104 @@ impl ResponseParameterData {
                 number_of_supported_curves,
                 levels_resolution,
                 minimum_level_split_levels_supported,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(11);
-
-                buf.extend(minimum_level_lower_limit.to_be_bytes());
-                buf.extend(minimum_level_upper_limit.to_be_bytes());
-                buf.extend(maximum_level_lower_limit.to_be_bytes());
-                buf.extend(maximum_level_upper_limit.to_be_bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*number_of_supported_curves);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*number_of_supported_curves).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(*levels_resolution);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*levels_resolution).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(*minimum_level_split_levels_supported as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*minimum_level_split_levels_supported as u8)
-                    .unwrap();
-            }
+            } => fields.extend([
+                (
+                    "minimum_level_lower_limit",
+                    FieldValue::U16(*minimum_level_lower_limit),
+                ),
+                (
+                    "minimum_level_upper_limit",
+                    FieldValue::U16(*minimum_level_upper_limit),
+                ),
+                (
+                    "maximum_level_lower_limit",
+                    FieldValue::U16(*maximum_level_lower_limit),
+                ),
+                (
+                    "maximum_level_upper_limit",
+                    FieldValue::U16(*maximum_level_upper_limit),
+                ),
+                (
+                    "number_of_supported_curves",
+                    FieldValue::U8(*number_of_supported_curves),
+                ),
+                ("levels_resolution", FieldValue::U8(*levels_resolution)),
+                (
+                    "minimum_level_split_levels_supported",
+                    FieldValue::Bool(*minimum_level_split_levels_supported),
+                ),
+            ]),
             Self::GetMinimumLevel {
                 minimum_level_increasing,
                 minimum_level_decreasing,
                 on_below_minimum,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(5);
-
-                buf.extend(minimum_level_increasing.to_be_bytes());
-                buf.extend(minimum_level_decreasing.to_be_bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*on_below_minimum as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*on_below_minimum as u8).unwrap();
-            }
-            Self::GetMaximumLevel(level) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(2);
-
-                buf.extend(level.to_be_bytes());
+            } => fields.extend([
+                (
+                    "minimum_level_increasing",
+                    FieldValue::U16(*minimum_level_increasing),
+                ),
+                (
+                    "minimum_level_decreasing",
+                    FieldValue::U16(*minimum_level_decreasing),
+                ),
+                ("on_below_minimum", FieldValue::Bool(*on_below_minimum)),
+            ]),
+            Self::GetMaximumLevel(value) => {
+                fields.extend([("maximum_level", FieldValue::U16(*value))])
             }
             Self::GetCurve {
                 curve_id,
                 curve_count,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(2);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*curve_id);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*curve_id).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(*curve_count);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*curve_count).unwrap();
-            }
+            } => fields.extend([
+                ("curve_id", FieldValue::U8(*curve_id)),
+                ("curve_count", FieldValue::U8(*curve_count)),
+            ]),
             Self::GetCurveDescription {
                 curve_id,
                 description,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1 + description.len());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*curve_id);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*curve_id).unwrap();
-
-                buf.extend(description.bytes());
-            }
+            } => fields.extend([
+                ("curve_id", FieldValue::U8(*curve_id)),
+                ("description", FieldValue::Str(description)),
+            ]),
             Self::GetOutputResponseTime {
                 response_time_id,
                 response_time_count,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(2);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*response_time_id);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*response_time_id).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(*response_time_count);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*response_time_count).unwrap();
-            }
+            } => fields.extend([
+                ("response_time_id", FieldValue::U8(*response_time_id)),
+                ("response_time_count", FieldValue::U8(*response_time_count)),
+            ]),
             Self::GetOutputResponseTimeDescription {
                 response_time_id,
                 description,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1 + description.len());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*response_time_id);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*response_time_id).unwrap();
-
-                buf.extend(description.bytes());
-            }
+            } => fields.extend([
+                ("response_time_id", FieldValue::U8(*response_time_id)),
+                ("description", FieldValue::Str(description)),
+            ]),
             Self::GetModulationFrequency {
                 modulation_frequency_id,
                 modulation_frequency_count,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(2);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*modulation_frequency_id);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*modulation_frequency_id).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(*modulation_frequency_count);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*modulation_frequency_count).unwrap();
-            }
+            } => fields.extend([
+                (
+                    "modulation_frequency_id",
+                    FieldValue::U8(*modulation_frequency_id),
+                ),
+                (
+                    "modulation_frequency_count",
+                    FieldValue::U8(*modulation_frequency_count),
+                ),
+            ]),
             Self::GetModulationFrequencyDescription {
                 modulation_frequency_id,
                 frequency,
                 description,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(5 + description.len());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*modulation_frequency_id);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*modulation_frequency_id).unwrap();
-
-                buf.extend(frequency.to_be_bytes());
-                buf.extend(description.bytes());
-            }
+            } => fields.extend([
+                (
+                    "modulation_frequency_id",
+                    FieldValue::U8(*modulation_frequency_id),
+                ),
+                ("frequency", FieldValue::U32(*frequency)),
+                ("description", FieldValue::Str(description)),
+            ]),
             Self::GetPresetInfo {
                 level_field_supported,
                 preset_sequence_supported,
@@ -1566,477 +1842,309 @@ impl ResponseParameterData {
                 maximum_startup_delay_time_supported,
                 minimum_startup_hold_time_supported,
                 maximum_startup_hold_time_supported,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(38);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*level_field_supported as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*level_field_supported as u8).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(*preset_sequence_supported as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*preset_sequence_supported as u8).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(*split_times_supported as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*split_times_supported as u8).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(*dmx_fail_infinite_delay_time_supported as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*dmx_fail_infinite_delay_time_supported as u8)
-                    .unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(*dmx_fail_infinite_hold_time_supported as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*dmx_fail_infinite_hold_time_supported as u8)
-                    .unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(*startup_infinite_hold_time_supported as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*startup_infinite_hold_time_supported as u8)
-                    .unwrap();
-
-                buf.extend(maximum_scene_number.to_be_bytes());
-                buf.extend(minimum_preset_fade_time_supported.to_be_bytes());
-                buf.extend(maximum_preset_fade_time_supported.to_be_bytes());
-                buf.extend(minimum_preset_wait_time_supported.to_be_bytes());
-                buf.extend(maximum_preset_wait_time_supported.to_be_bytes());
-
-                buf.extend(u16::from(*minimum_dmx_fail_delay_time_supported).to_be_bytes());
-                buf.extend(u16::from(*maximum_dmx_fail_delay_time_supported).to_be_bytes());
-                buf.extend(u16::from(*minimum_dmx_fail_hold_time_supported).to_be_bytes());
-                buf.extend(u16::from(*maximum_dmx_fail_hold_time_supported).to_be_bytes());
-                buf.extend(u16::from(*minimum_startup_delay_time_supported).to_be_bytes());
-                buf.extend(u16::from(*maximum_startup_delay_time_supported).to_be_bytes());
-                buf.extend(u16::from(*minimum_startup_hold_time_supported).to_be_bytes());
-                buf.extend(u16::from(*maximum_startup_hold_time_supported).to_be_bytes());
-            }
+            } => fields.extend([
+                (
+                    "level_field_supported",
+                    FieldValue::Bool(*level_field_supported),
+                ),
+                (
+                    "preset_sequence_supported",
+                    FieldValue::Bool(*preset_sequence_supported),
+                ),
+                (
+                    "split_times_supported",
+                    FieldValue::Bool(*split_times_supported),
+                ),
+                (
+                    "dmx_fail_infinite_delay_time_supported",
+                    FieldValue::Bool(*dmx_fail_infinite_delay_time_supported),
+                ),
+                (
+                    "dmx_fail_infinite_hold_time_supported",
+                    FieldValue::Bool(*dmx_fail_infinite_hold_time_supported),
+                ),
+                (
+                    "startup_infinite_hold_time_supported",
+                    FieldValue::Bool(*startup_infinite_hold_time_supported),
+                ),
+                (
+                    "maximum_scene_number",
+                    FieldValue::U16(*maximum_scene_number),
+                ),
+                (
+                    "minimum_preset_fade_time_supported",
+                    FieldValue::U16(*minimum_preset_fade_time_supported),
+                ),
+                (
+                    "maximum_preset_fade_time_supported",
+                    FieldValue::U16(*maximum_preset_fade_time_supported),
+                ),
+                (
+                    "minimum_preset_wait_time_supported",
+                    FieldValue::U16(*minimum_preset_wait_time_supported),
+                ),
+                (
+                    "maximum_preset_wait_time_supported",
+                    FieldValue::U16(*maximum_preset_wait_time_supported),
+                ),
+                (
+                    "minimum_dmx_fail_delay_time_supported",
+                    FieldValue::Other(minimum_dmx_fail_delay_time_supported),
+                ),
+                (
+                    "maximum_dmx_fail_delay_time_supported",
+                    FieldValue::Other(maximum_dmx_fail_delay_time_supported),
+                ),
+                (
+                    "minimum_dmx_fail_hold_time_supported",
+                    FieldValue::Other(minimum_dmx_fail_hold_time_supported),
+                ),
+                (
+                    "maximum_dmx_fail_hold_time_supported",
+                    FieldValue::Other(maximum_dmx_fail_hold_time_supported),
+                ),
+                (
+                    "minimum_startup_delay_time_supported",
+                    FieldValue::Other(minimum_startup_delay_time_supported),
+                ),
+                (
+                    "maximum_startup_delay_time_supported",
+                    FieldValue::Other(maximum_startup_delay_time_supported),
+                ),
+                (
+                    "minimum_startup_hold_time_supported",
+                    FieldValue::Other(minimum_startup_hold_time_supported),
+                ),
+                (
+                    "maximum_startup_hold_time_supported",
+                    FieldValue::Other(maximum_startup_hold_time_supported),
+                ),
+            ]),
             Self::GetPresetStatus {
                 scene_id,
                 up_fade_time,
                 down_fade_time,
                 wait_time,
                 programmed,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(9);
-
-                buf.extend(scene_id.to_be_bytes());
-                buf.extend(up_fade_time.to_be_bytes());
-                buf.extend(down_fade_time.to_be_bytes());
-                buf.extend(wait_time.to_be_bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*programmed as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*programmed as u8).unwrap();
-            }
-            Self::GetPresetMergeMode(mode) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*mode as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*mode as u8).unwrap();
+            } => fields.extend([
+                ("scene_id", FieldValue::U16(*scene_id)),
+                ("up_fade_time", FieldValue::U16(*up_fade_time)),
+                ("down_fade_time", FieldValue::U16(*down_fade_time)),
+                ("wait_time", FieldValue::U16(*wait_time)),
+                ("programmed", FieldValue::Other(programmed)),
+            ]),
+            Self::GetPresetMergeMode(value) => {
+                fields.extend([("preset_merge_mode", FieldValue::Other(value))])
             }
-            Self::GetListInterfaces(interfaces) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(interfaces.len() * 6);
-
-                for interface in interfaces {
-                    buf.extend(interface.interface_id.to_be_bytes());
-                    buf.extend(u16::from(interface.hardware_type).to_be_bytes());
-                }
+            // E1.37-2
+            Self::GetListInterfaces(value) => {
+                fields.extend([("list_interfaces", FieldValue::Other(value))])
             }
             Self::GetInterfaceLabel {
                 interface_id,
                 interface_label,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(4 + interface_label.len());
-
-                buf.extend(interface_id.to_be_bytes());
-                buf.extend(interface_label.bytes());
-            }
+            } => fields.extend([
+                ("interface_id", FieldValue::U32(*interface_id)),
+                ("interface_label", FieldValue::Str(interface_label)),
+            ]),
             Self::GetInterfaceHardwareAddressType1 {
                 interface_id,
                 hardware_address,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(10);
-
-                buf.extend(interface_id.to_be_bytes());
-                buf.extend(hardware_address.into_array());
-            }
+            } => fields.extend([
+                ("interface_id", FieldValue::U32(*interface_id)),
+                ("hardware_address", FieldValue::Other(hardware_address)),
+            ]),
             Self::GetIpV4DhcpMode {
                 interface_id,
                 dhcp_mode,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(5);
-
-                buf.extend(interface_id.to_be_bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*dhcp_mode as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*dhcp_mode as u8).unwrap();
-            }
+            } => fields.extend([
+                ("interface_id", FieldValue::U32(*interface_id)),
+                ("dhcp_mode", FieldValue::Bool(*dhcp_mode)),
+            ]),
             Self::GetIpV4ZeroConfMode {
                 interface_id,
                 zero_conf_mode,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(5);
-
-                buf.extend(interface_id.to_be_bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*zero_conf_mode as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*zero_conf_mode as u8).unwrap();
-            }
+            } => fields.extend([
+                ("interface_id", FieldValue::U32(*interface_id)),
+                ("zero_conf_mode", FieldValue::Bool(*zero_conf_mode)),
+            ]),
             Self::GetIpV4CurrentAddress {
                 interface_id,
                 address,
                 netmask,
                 dhcp_status,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(13);
-
-                buf.extend(interface_id.to_be_bytes());
-                buf.extend(<[u8; 4]>::from(*address));
-
-                #[cfg(feature = "alloc")]
-                buf.push(*netmask);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*netmask).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(*dhcp_status as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*dhcp_status as u8).unwrap();
-            }
+            } => fields.extend([
+                ("interface_id", FieldValue::U32(*interface_id)),
+                ("address", FieldValue::Other(address)),
+                ("netmask", FieldValue::U8(*netmask)),
+                ("dhcp_status", FieldValue::Other(dhcp_status)),
+            ]),
             Self::GetIpV4StaticAddress {
                 interface_id,
                 address,
                 netmask,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(10);
-
-                buf.extend(interface_id.to_be_bytes());
-                buf.extend(<[u8; 4]>::from(*address));
-
-                #[cfg(feature = "alloc")]
-                buf.push(*netmask);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*netmask).unwrap();
-            }
+            } => fields.extend([
+                ("interface_id", FieldValue::U32(*interface_id)),
+                ("address", FieldValue::Other(address)),
+                ("netmask", FieldValue::U8(*netmask)),
+            ]),
             Self::GetIpV4DefaultRoute {
                 interface_id,
                 address,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(6);
-
-                buf.extend(interface_id.to_be_bytes());
-                buf.extend(<[u8; 4]>::from(*address));
-            }
+            } => fields.extend([
+                ("interface_id", FieldValue::U32(*interface_id)),
+                ("address", FieldValue::Other(address)),
+            ]),
             Self::GetDnsIpV4NameServer {
                 name_server_index,
                 address,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(5);
-
-                buf.extend(name_server_index.to_be_bytes());
-                buf.extend(<[u8; 4]>::from(*address));
-            }
-            Self::GetDnsHostName(host_name) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(host_name.len());
-
-                buf.extend(host_name.bytes());
+            } => fields.extend([
+                ("name_server_index", FieldValue::U8(*name_server_index)),
+                ("address", FieldValue::Other(address)),
+            ]),
+            Self::GetDnsHostName(value) => {
+                fields.extend([("dns_host_name", FieldValue::Str(value))])
             }
-            Self::GetDnsDomainName(domain_name) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(domain_name.len());
-
-                buf.extend(domain_name.bytes());
+            Self::GetDnsDomainName(value) => {
+                fields.extend([("dns_domain_name", FieldValue::Str(value))])
             }
             // E1.37-7
             Self::GetEndpointList {
                 list_change_number,
                 endpoint_list,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(4 + (endpoint_list.len() * 3));
-
-                buf.extend(list_change_number.to_be_bytes());
-
-                for (endpoint_id, endpoint_type) in endpoint_list {
-                    buf.extend(u16::from(*endpoint_id).to_be_bytes());
-
-                    #[cfg(feature = "alloc")]
-                    buf.push(*endpoint_type as u8);
-                    #[cfg(not(feature = "alloc"))]
-                    buf.push(*endpoint_type as u8).unwrap();
-                }
-            }
+            } => fields.extend([
+                ("list_change_number", FieldValue::U32(*list_change_number)),
+                ("endpoint_list", FieldValue::Other(endpoint_list)),
+            ]),
             Self::GetEndpointListChange { list_change_number } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(4);
-
-                buf.extend(list_change_number.to_be_bytes());
+                fields.extend([("list_change_number", FieldValue::U32(*list_change_number))])
             }
             Self::GetIdentifyEndpoint {
                 endpoint_id,
                 identify,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(3);
-
-                buf.extend(u16::from(*endpoint_id).to_be_bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*identify as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*identify as u8).unwrap();
-            }
+            } => fields.extend([
+                ("endpoint_id", FieldValue::Other(endpoint_id)),
+                ("identify", FieldValue::Bool(*identify)),
+            ]),
             Self::SetIdentifyEndpoint { endpoint_id } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(2);
-
-                buf.extend(u16::from(*endpoint_id).to_be_bytes());
+                fields.extend([("endpoint_id", FieldValue::Other(endpoint_id))])
             }
             Self::GetEndpointToUniverse {
                 endpoint_id,
                 universe,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(4);
-
-                buf.extend(u16::from(*endpoint_id).to_be_bytes());
-                buf.extend(universe.to_be_bytes());
-            }
+            } => fields.extend([
+                ("endpoint_id", FieldValue::Other(endpoint_id)),
+                ("universe", FieldValue::U16(*universe)),
+            ]),
             Self::SetEndpointToUniverse { endpoint_id } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(2);
-
-                buf.extend(u16::from(*endpoint_id).to_be_bytes());
-            }
-            Self::GetEndpointMode { endpoint_id, mode } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(3);
-
-                buf.extend(u16::from(*endpoint_id).to_be_bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*mode as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*mode as u8).unwrap();
+                fields.extend([("endpoint_id", FieldValue::Other(endpoint_id))])
             }
+            Self::GetEndpointMode { endpoint_id, mode } => fields.extend([
+                ("endpoint_id", FieldValue::Other(endpoint_id)),
+                ("mode", FieldValue::Other(mode)),
+            ]),
             Self::SetEndpointMode { endpoint_id } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(2);
-
-                buf.extend(u16::from(*endpoint_id).to_be_bytes());
-            }
-            Self::GetEndpointLabel { endpoint_id, label } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(3 + label.len());
-
-                buf.extend(u16::from(*endpoint_id).to_be_bytes());
-                buf.extend(label.bytes());
+                fields.extend([("endpoint_id", FieldValue::Other(endpoint_id))])
             }
+            Self::GetEndpointLabel { endpoint_id, label } => fields.extend([
+                ("endpoint_id", FieldValue::Other(endpoint_id)),
+                ("label", FieldValue::Str(label)),
+            ]),
             Self::SetEndpointLabel { endpoint_id } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(2);
-
-                buf.extend(u16::from(*endpoint_id).to_be_bytes());
+                fields.extend([("endpoint_id", FieldValue::Other(endpoint_id))])
             }
             Self::GetRdmTrafficEnable {
                 endpoint_id,
                 enable,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(3);
-
-                buf.extend(u16::from(*endpoint_id).to_be_bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*enable as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*enable as u8).unwrap();
-            }
+            } => fields.extend([
+                ("endpoint_id", FieldValue::Other(endpoint_id)),
+                ("enable", FieldValue::Bool(*enable)),
+            ]),
             Self::SetRdmTrafficEnable { endpoint_id } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(2);
-
-                buf.extend(u16::from(*endpoint_id).to_be_bytes());
+                fields.extend([("endpoint_id", FieldValue::Other(endpoint_id))])
             }
             Self::GetDiscoveryState {
                 endpoint_id,
                 device_count,
                 discovery_state,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(5);
-
-                buf.extend(u16::from(*endpoint_id).to_be_bytes());
-                buf.extend(u16::from(*device_count).to_be_bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push((*discovery_state).into());
-                #[cfg(not(feature = "alloc"))]
-                buf.push((*discovery_state).into()).unwrap();
-            }
+            } => fields.extend([
+                ("endpoint_id", FieldValue::Other(endpoint_id)),
+                ("device_count", FieldValue::Other(device_count)),
+                ("discovery_state", FieldValue::Other(discovery_state)),
+            ]),
             Self::SetDiscoveryState { endpoint_id } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(2);
-
-                buf.extend(u16::from(*endpoint_id).to_be_bytes());
+                fields.extend([("endpoint_id", FieldValue::Other(endpoint_id))])
             }
             Self::GetBackgroundDiscovery {
                 endpoint_id,
                 enabled,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(3);
-
-                buf.extend(u16::from(*endpoint_id).to_be_bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*enabled as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*enabled as u8).unwrap();
-            }
+            } => fields.extend([
+                ("endpoint_id", FieldValue::Other(endpoint_id)),
+                ("enabled", FieldValue::Bool(*enabled)),
+            ]),
             Self::SetBackgroundDiscovery { endpoint_id } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(2);
-
-                buf.extend(u16::from(*endpoint_id).to_be_bytes());
+                fields.extend([("endpoint_id", FieldValue::Other(endpoint_id))])
             }
             Self::GetEndpointTiming {
                 endpoint_id,
                 current_setting_id,
                 setting_count,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(4);
-
-                buf.extend(u16::from(*endpoint_id).to_be_bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*current_setting_id);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*current_setting_id).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(*setting_count);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*setting_count).unwrap();
-            }
+            } => fields.extend([
+                ("endpoint_id", FieldValue::Other(endpoint_id)),
+                ("current_setting_id", FieldValue::U8(*current_setting_id)),
+                ("setting_count", FieldValue::U8(*setting_count)),
+            ]),
             Self::SetEndpointTiming { endpoint_id } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(2);
-
-                buf.extend(u16::from(*endpoint_id).to_be_bytes());
+                fields.extend([("endpoint_id", FieldValue::Other(endpoint_id))])
             }
             Self::GetEndpointTimingDescription {
                 setting_id,
                 description,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1 + description.len());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*setting_id);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*setting_id).unwrap();
-
-                buf.extend(description.bytes());
-            }
+            } => fields.extend([
+                ("setting_id", FieldValue::U8(*setting_id)),
+                ("description", FieldValue::Str(description)),
+            ]),
             Self::GetEndpointResponders {
                 endpoint_id,
                 list_change_number,
                 responders,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(6 + (responders.len() * 6));
-
-                buf.extend(u16::from(*endpoint_id).to_be_bytes());
-                buf.extend(list_change_number.to_be_bytes());
-
-                for responder in responders {
-                    buf.extend(<[u8; 6]>::from(*responder));
-                }
-            }
+            } => fields.extend([
+                ("endpoint_id", FieldValue::Other(endpoint_id)),
+                ("list_change_number", FieldValue::U32(*list_change_number)),
+                ("responders", FieldValue::Other(responders)),
+            ]),
             Self::GetEndpointResponderListChange {
                 endpoint_id,
                 list_change_number,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(6);
-
-                buf.extend(u16::from(*endpoint_id).to_be_bytes());
-                buf.extend(list_change_number.to_be_bytes());
-            }
+            } => fields.extend([
+                ("endpoint_id", FieldValue::Other(endpoint_id)),
+                ("list_change_number", FieldValue::U32(*list_change_number)),
+            ]),
             Self::GetBindingControlFields {
                 endpoint_id,
                 uid,
                 control_field,
                 binding_uid,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(3);
-
-                buf.extend(u16::from(*endpoint_id).to_be_bytes());
-                buf.extend(<[u8; 6]>::from(*uid));
-                buf.extend((*control_field).to_be_bytes());
-                buf.extend(<[u8; 6]>::from(*binding_uid));
-            }
+            } => fields.extend([
+                ("endpoint_id", FieldValue::Other(endpoint_id)),
+                ("uid", FieldValue::Uid(*uid)),
+                ("control_field", FieldValue::U16(*control_field)),
+                ("binding_uid", FieldValue::Uid(*binding_uid)),
+            ]),
             Self::GetBackgroundQueuedStatusPolicy {
                 current_policy_id,
                 policy_count,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(2);
-
-                #[cfg(feature = "alloc")]
-                buf.push(*current_policy_id);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*current_policy_id).unwrap();
-
-                #[cfg(feature = "alloc")]
-                buf.push(*policy_count);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*policy_count).unwrap();
-            }
+            } => fields.extend([
+                ("current_policy_id", FieldValue::U8(*current_policy_id)),
+                ("policy_count", FieldValue::U8(*policy_count)),
+            ]),
             Self::GetBackgroundQueuedStatusPolicyDescription {
                 policy_id,
                 description,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(1 + description.len());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*policy_id);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*policy_id).unwrap();
-
-                buf.extend(description.bytes());
-            }
+            } => fields.extend([
+                ("policy_id", FieldValue::U8(*policy_id)),
+                ("description", FieldValue::Str(description)),
+            ]),
             // E1.33
             Self::GetComponentScope {
                 scope_slot,
@@ -2045,27 +2153,22 @@ impl ResponseParameterData {
                 static_ipv4_address,
                 static_ipv6_address,
                 static_port,
-            } => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(25 + scope_string.len());
-
-                buf.extend(scope_slot.to_be_bytes());
-                buf.extend(scope_string.bytes());
-
-                #[cfg(feature = "alloc")]
-                buf.push(*static_config_type as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*static_config_type as u8).unwrap();
-
-                buf.extend(<[u8; 4]>::from(*static_ipv4_address));
-                buf.extend(<[u8; 16]>::from(*static_ipv6_address));
-                buf.extend(static_port.to_be_bytes());
-            }
-            Self::GetSearchDomain(search_domain) => {
-                #[cfg(feature = "alloc")]
-                buf.reserve(25 + search_domain.len());
-
-                buf.extend(search_domain.bytes());
+            } => fields.extend([
+                ("scope_slot", FieldValue::U16(*scope_slot)),
+                ("scope_string", FieldValue::Str(scope_string)),
+                ("static_config_type", FieldValue::Other(static_config_type)),
+                (
+                    "static_ipv4_address",
+                    FieldValue::Other(static_ipv4_address),
+                ),
+                (
+                    "static_ipv6_address",
+                    FieldValue::Other(static_ipv6_address),
+                ),
+                ("static_port", FieldValue::U16(*static_port)),
+            ]),
+            Self::GetSearchDomain(value) => {
+                fields.extend([("search_domain", FieldValue::Str(value))])
             }
             Self::GetTcpCommsStatus {
                 scope_string,
@@ -2073,1299 +2176,4059 @@ impl ResponseParameterData {
                 broker_ipv6_address,
                 broker_port,
                 unhealthy_tcp_events,
+            } => fields.extend([
+                ("scope_string", FieldValue::Str(scope_string)),
+                (
+                    "broker_ipv4_address",
+                    FieldValue::Other(broker_ipv4_address),
+                ),
+                (
+                    "broker_ipv6_address",
+                    FieldValue::Other(broker_ipv6_address),
+                ),
+                ("broker_port", FieldValue::U16(*broker_port)),
+                (
+                    "unhealthy_tcp_events",
+                    FieldValue::U16(*unhealthy_tcp_events),
+                ),
+            ]),
+            Self::GetBrokerStatus {
+                is_allowing_set_commands,
+                broker_state,
+            } => fields.extend([
+                (
+                    "is_allowing_set_commands",
+                    FieldValue::Bool(*is_allowing_set_commands),
+                ),
+                ("broker_state", FieldValue::Other(broker_state)),
+            ]),
+            Self::ManufacturerSpecific(value) => {
+                fields.extend([("data", FieldValue::Other(value))])
+            }
+            Self::Unsupported(value) => fields.extend([("data", FieldValue::Other(value))]),
+        }
+
+        fields.into_iter()
+    }
+
+    /// The uids of the devices behind a proxy, from a PROXIED_DEVICES response
+    pub fn proxied_devices(&self) -> Option<&[DeviceUID]> {
+        match self {
+            Self::GetProxiedDevices(devices) => Some(devices),
+            _ => None,
+        }
+    }
+
+    pub fn encode(&self) -> EncodedParameterData {
+        #[cfg(feature = "alloc")]
+        let mut buf = Vec::new();
+
+        #[cfg(not(feature = "alloc"))]
+        let mut buf = Vec::new();
+
+        match self {
+            Self::DiscMute {
+                control_field,
+                binding_uid,
             } => {
                 #[cfg(feature = "alloc")]
-                buf.reserve(24 + scope_string.len());
+                buf.reserve(0x0e);
 
-                buf.extend(scope_string.bytes());
+                buf.extend(u16::from(*control_field).to_be_bytes());
 
-                buf.extend(<[u8; 4]>::from(*broker_ipv4_address));
-                buf.extend(<[u8; 16]>::from(*broker_ipv6_address));
-                buf.extend(broker_port.to_be_bytes());
-                buf.extend(unhealthy_tcp_events.to_be_bytes());
+                if let Some(binding_uid) = binding_uid {
+                    buf.extend(binding_uid.manufacturer_id.to_be_bytes());
+                    buf.extend(binding_uid.device_id.to_be_bytes());
+                }
             }
-            Self::GetBrokerStatus {
-                is_allowing_set_commands,
-                broker_state,
+            Self::DiscUnMute {
+                control_field,
+                binding_uid,
             } => {
                 #[cfg(feature = "alloc")]
-                buf.reserve(2);
+                buf.reserve(0x0e);
 
-                #[cfg(feature = "alloc")]
-                buf.push(*is_allowing_set_commands as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*is_allowing_set_commands as u8).unwrap();
+                buf.extend(u16::from(*control_field).to_be_bytes());
 
-                #[cfg(feature = "alloc")]
-                buf.push(*broker_state as u8);
-                #[cfg(not(feature = "alloc"))]
-                buf.push(*broker_state as u8).unwrap();
+                if let Some(binding_uid) = binding_uid {
+                    buf.extend(binding_uid.manufacturer_id.to_be_bytes());
+                    buf.extend(binding_uid.device_id.to_be_bytes());
+                }
             }
-            Self::ManufacturerSpecific(data) => {
+            Self::GetProxiedDeviceCount {
+                device_count,
+                list_change,
+            } => {
                 #[cfg(feature = "alloc")]
-                buf.reserve(data.len());
+                buf.reserve(0x03);
+
+                buf.extend(device_count.to_be_bytes());
 
                 #[cfg(feature = "alloc")]
-                buf.extend(data);
+                buf.push(*list_change as u8);
                 #[cfg(not(feature = "alloc"))]
-                buf.extend_from_slice(data).unwrap();
+                buf.push(*list_change as u8).unwrap();
             }
-            Self::Unsupported(data) => {
+            Self::GetProxiedDevices(devices) => {
                 #[cfg(feature = "alloc")]
-                buf.reserve(data.len());
+                buf.reserve(devices.len() * 6);
 
-                #[cfg(feature = "alloc")]
-                buf.extend(data);
-                #[cfg(not(feature = "alloc"))]
-                buf.extend_from_slice(data).unwrap();
+                for device in devices {
+                    buf.extend(device.manufacturer_id.to_be_bytes());
+                    buf.extend(device.device_id.to_be_bytes());
+                }
             }
-        }
-
-        buf
-    }
+            Self::GetCommsStatus {
+                short_message,
+                length_mismatch,
+                checksum_fail,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(6);
 
-    pub fn decode(
-        command_class: CommandClass,
-        parameter_id: ParameterId,
-        bytes: &[u8],
-    ) -> Result<Self, RdmError> {
-        match (command_class, parameter_id) {
-            (CommandClass::DiscoveryCommandResponse, ParameterId::DiscMute) => {
-                check_msg_len!(bytes, 2);
-                let binding_uid = if bytes.len() >= 8 {
-                    Some(DeviceUID::from(<[u8; 6]>::try_from(&bytes[2..=7])?))
-                } else {
-                    None
-                };
+                buf.extend(short_message.to_be_bytes());
+                buf.extend(length_mismatch.to_be_bytes());
+                buf.extend(checksum_fail.to_be_bytes());
+            }
+            Self::GetStatusMessages(messages) => {
+                for message in messages {
+                    buf.extend(u16::from(message.sub_device_id).to_be_bytes());
 
-                Ok(Self::DiscMute {
-                    control_field: u16::from_be_bytes(bytes[..=1].try_into()?),
-                    binding_uid,
-                })
+                    #[cfg(feature = "alloc")]
+                    buf.push(message.status_type as u8);
+                    #[cfg(not(feature = "alloc"))]
+                    buf.push(message.status_type as u8).unwrap();
+
+                    buf.extend(message.status_message_id.to_be_bytes());
+                    buf.extend(message.data_value1.to_be_bytes());
+                    buf.extend(message.data_value2.to_be_bytes());
+
+                    if let Some(description) = &message.description {
+                        buf.extend(description.bytes());
+                    }
+                }
             }
-            (CommandClass::DiscoveryCommandResponse, ParameterId::DiscUnMute) => {
-                check_msg_len!(bytes, 2);
-                let binding_uid = if bytes.len() >= 8 {
-                    Some(DeviceUID::from(<[u8; 6]>::try_from(&bytes[2..=7])?))
-                } else {
-                    None
-                };
+            Self::GetStatusIdDescription(description) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(description.len());
 
-                Ok(Self::DiscUnMute {
-                    control_field: u16::from_be_bytes(bytes[..=1].try_into()?),
-                    binding_uid,
-                })
+                buf.extend(description.bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::ProxiedDeviceCount) => {
-                check_msg_len!(bytes, 3);
-                Ok(Self::GetProxiedDeviceCount {
-                    device_count: u16::from_be_bytes(bytes[0..=1].try_into()?),
-                    list_change: bytes[2] == 1,
-                })
+            Self::GetSubDeviceIdStatusReportThreshold(status) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*status as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*status as u8).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::ProxiedDevices) => {
-                Ok(Self::GetProxiedDevices(
-                    #[cfg(feature = "alloc")]
-                    bytes
-                        .chunks(6)
-                        .map(|chunk| Ok(DeviceUID::from(<[u8; 6]>::try_from(&chunk[0..=5])?)))
-                        .collect::<Result<Vec<DeviceUID>, RdmError>>()?,
-                    #[cfg(not(feature = "alloc"))]
-                    bytes
-                        .chunks(6)
-                        .map(|chunk| Ok(DeviceUID::from(<[u8; 6]>::try_from(&chunk[0..=5])?)))
-                        .collect::<Result<Vec<DeviceUID, 38>, RdmError>>()?,
-                ))
+            Self::GetSupportedParameters(parameters) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(parameters.len() * 2);
+
+                for parameter in parameters {
+                    buf.extend(parameter.to_be_bytes());
+                }
             }
-            (CommandClass::GetCommandResponse, ParameterId::CommsStatus) => {
-                check_msg_len!(bytes, 6);
-                Ok(Self::GetCommsStatus {
-                    short_message: u16::from_be_bytes(bytes[0..=1].try_into()?),
-                    length_mismatch: u16::from_be_bytes(bytes[2..=3].try_into()?),
-                    checksum_fail: u16::from_be_bytes(bytes[4..=5].try_into()?),
-                })
+            Self::GetParameterDescription(description) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(20 + description.description.len());
+
+                buf.extend(description.parameter_id.to_be_bytes());
+
+                #[cfg(feature = "alloc")]
+                buf.push(description.parameter_data_length);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(description.parameter_data_length).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(description.data_type.into());
+                #[cfg(not(feature = "alloc"))]
+                buf.push(description.data_type.into()).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(description.command_class as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(description.command_class as u8).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(description.command_class as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(description.command_class as u8).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(description.unit_type.into());
+                #[cfg(not(feature = "alloc"))]
+                buf.push(description.unit_type.into()).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(description.prefix as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(description.prefix as u8).unwrap();
+
+                buf.extend(description.raw_minimum_valid_value);
+                buf.extend(description.raw_maximum_valid_value);
+                buf.extend(description.raw_default_value);
+
+                #[cfg(feature = "alloc")]
+                buf.extend(description.description.bytes());
+                #[cfg(not(feature = "alloc"))]
+                buf.extend(description.description.bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::StatusMessages) => {
-                Ok(Self::GetStatusMessages(
-                    #[cfg(feature = "alloc")]
-                    bytes
-                        .chunks(9)
-                        .map(|chunk| {
-                            Ok(StatusMessage::new(
-                                u16::from_be_bytes(chunk[0..=1].try_into()?).into(),
-                                chunk[2].try_into()?,
-                                u16::from_be_bytes(chunk[3..=4].try_into()?),
-                                u16::from_be_bytes(chunk[5..=6].try_into()?),
-                                u16::from_be_bytes(chunk[7..=8].try_into()?),
-                            ))
-                        })
-                        .collect::<Result<Vec<StatusMessage>, RdmError>>()?,
-                    #[cfg(not(feature = "alloc"))]
-                    bytes
-                        .chunks(9)
-                        .map(|chunk| {
-                            Ok(StatusMessage::new(
-                                u16::from_be_bytes(chunk[0..=1].try_into()?).into(),
-                                chunk[2].try_into()?,
-                                u16::from_be_bytes(chunk[3..=4].try_into()?),
-                                u16::from_be_bytes(chunk[5..=6].try_into()?),
-                                u16::from_be_bytes(chunk[7..=8].try_into()?),
-                            ))
-                        })
-                        .collect::<Result<Vec<StatusMessage, 25>, RdmError>>()?,
-                ))
+            Self::GetDeviceInfo {
+                protocol_version,
+                model_id,
+                product_category,
+                software_version_id,
+                footprint,
+                current_personality,
+                personality_count,
+                start_address,
+                sub_device_count,
+                sensor_count,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(21);
+
+                buf.extend(u16::from(*protocol_version).to_be_bytes());
+
+                buf.extend(model_id.to_be_bytes());
+                buf.extend(u16::from(*product_category).to_be_bytes());
+                buf.extend(software_version_id.to_be_bytes());
+                buf.extend(footprint.to_be_bytes());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*current_personality);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*current_personality).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*personality_count);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*personality_count).unwrap();
+
+                buf.extend(start_address.to_be_bytes());
+                buf.extend(sub_device_count.to_be_bytes());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*sensor_count);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*sensor_count).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::StatusIdDescription) => {
-                Ok(Self::GetStatusIdDescription(decode_string_bytes(&bytes[..bytes.len().min(32)])?))
+            Self::GetProductDetailIdList(details) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(details.len() * 2);
+
+                for &detail in details {
+                    buf.extend(u16::from(detail).to_be_bytes());
+                }
             }
-            (CommandClass::GetCommandResponse, ParameterId::SubDeviceIdStatusReportThreshold) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetSubDeviceIdStatusReportThreshold(
-                    bytes[0].try_into()?,
-                ))
+            Self::GetDeviceModelDescription(description) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(description.len());
+
+                buf.extend(description.bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::SupportedParameters) => {
-                let parameters = bytes
-                    .chunks(2)
-                    .map(|chunk| Ok(u16::from_be_bytes(chunk.try_into()?)))
-                    .filter_map(|parameter_id: Result<u16, RdmError>| parameter_id.ok());
+            Self::GetManufacturerLabel(label) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(label.len());
 
-                Ok(Self::GetSupportedParameters(
-                    #[cfg(feature = "alloc")]
-                    parameters.collect::<Vec<u16>>(),
-                    #[cfg(not(feature = "alloc"))]
-                    parameters.collect::<Vec<u16, 115>>(),
-                ))
+                buf.extend(label.bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::ParameterDescription) => {
-                check_msg_len!(bytes, 20);
-                Ok(Self::GetParameterDescription(ParameterDescription {
-                    parameter_id: u16::from_be_bytes(bytes[0..=1].try_into()?),
-                    parameter_data_length: bytes[2],
-                    data_type: bytes[3].try_into()?,
-                    command_class: bytes[4].try_into()?,
-                    unit_type: bytes[6].try_into()?,
-                    prefix: bytes[7].try_into()?,
-                    raw_minimum_valid_value: bytes[8..=11].try_into()?,
-                    raw_maximum_valid_value: bytes[12..=15].try_into()?,
-                    raw_default_value: bytes[16..=19].try_into()?,
-                    description: decode_string_bytes(&bytes[20..bytes.len().min(20+32)])?,
-                }))
+            Self::GetDeviceLabel(label) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(label.len());
+
+                buf.extend(label.bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::DeviceInfo) => {
-                check_msg_len!(bytes, 19);
-                Ok(Self::GetDeviceInfo {
-                    protocol_version: ProtocolVersion::new(bytes[0], bytes[1]),
-                    model_id: u16::from_be_bytes(bytes[2..=3].try_into()?),
-                    product_category: u16::from_be_bytes(bytes[4..=5].try_into()?).into(),
-                    software_version_id: u32::from_be_bytes(bytes[6..=9].try_into()?),
-                    footprint: u16::from_be_bytes(bytes[10..=11].try_into()?),
-                    current_personality: bytes[12],
-                    personality_count: bytes[13],
-                    start_address: u16::from_be_bytes(bytes[14..=15].try_into()?),
-                    sub_device_count: u16::from_be_bytes(bytes[16..=17].try_into()?),
-                    sensor_count: u8::from_be(bytes[18]),
-                })
+            Self::GetFactoryDefaults(defaults) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*defaults as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*defaults as u8).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::ProductDetailIdList) => {
-                Ok(Self::GetProductDetailIdList(
-                    #[cfg(feature = "alloc")]
-                    bytes
-                        .chunks(2)
-                        .map(|chunk| Ok(u16::from_be_bytes(chunk.try_into()?).into()))
-                        .collect::<Result<Vec<ProductDetail>, RdmError>>()?,
-                    #[cfg(not(feature = "alloc"))]
-                    bytes
-                        .chunks(2)
-                        .map(|chunk| Ok(u16::from_be_bytes(chunk.try_into()?).into()))
-                        .collect::<Result<Vec<ProductDetail, 115>, RdmError>>()?,
-                ))
+            Self::GetLanguageCapabilities(languages) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(languages.len() * 2);
+
+                for language in languages {
+                    buf.extend(language.bytes());
+                }
             }
-            (CommandClass::GetCommandResponse, ParameterId::DeviceModelDescription) => {
-                Ok(Self::GetDeviceModelDescription(decode_string_bytes(&bytes[..bytes.len().min(32)])?))
+            Self::GetLanguage(language) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(2);
+
+                buf.extend(language.bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::ManufacturerLabel) => {
-                Ok(Self::GetManufacturerLabel(decode_string_bytes(&bytes[..bytes.len().min(32)])?))
+            Self::GetSoftwareVersionLabel(label) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(label.len());
+
+                buf.extend(label.bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::DeviceLabel) => {
-                Ok(Self::GetDeviceLabel(decode_string_bytes(&bytes[..bytes.len().min(32)])?))
+            Self::GetBootSoftwareVersionId(version_id) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(4);
+
+                buf.extend(version_id.to_bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::FactoryDefaults) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetFactoryDefaults(bytes[0] == 1))
+            Self::GetBootSoftwareVersionLabel(label) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(label.len());
+
+                buf.extend(label.bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::LanguageCapabilities) => {
-                Ok(Self::GetLanguageCapabilities(
+            Self::GetDmxPersonality {
+                current_personality,
+                personality_count,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(2);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*current_personality);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*current_personality).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*personality_count);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*personality_count).unwrap();
+            }
+            Self::GetDmxPersonalityDescription {
+                id,
+                dmx_slots_required,
+                description,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(3 + description.len());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*id);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*id).unwrap();
+
+                buf.extend(dmx_slots_required.to_be_bytes());
+                buf.extend(description.bytes());
+            }
+            Self::GetDmxStartAddress(address) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(2);
+
+                buf.extend(address.to_be_bytes());
+            }
+            Self::GetSlotInfo(slots) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(slots.len() * 5);
+
+                for slot in slots {
+                    buf.extend(slot.id.to_be_bytes());
+
                     #[cfg(feature = "alloc")]
-                    bytes
-                        .chunks(2)
-                        .map(|chunk| Ok(core::str::from_utf8(chunk)?.to_string()))
-                        .collect::<Result<Vec<String>, RdmError>>()?,
+                    buf.push(slot.r#type.into());
                     #[cfg(not(feature = "alloc"))]
-                    bytes
-                        .chunks(2)
-                        .map(|chunk| {
-                            Ok(String::from_utf8(Vec::<u8, 2>::from_slice(chunk).unwrap())?)
-                        })
-                        .collect::<Result<Vec<String<2>, 115>, RdmError>>()?,
-                ))
+                    buf.push(slot.r#type.into()).unwrap();
+
+                    buf.extend(slot.label_id.to_be_bytes());
+                }
             }
-            (CommandClass::GetCommandResponse, ParameterId::Language) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::GetLanguage(
+            Self::GetSlotDescription {
+                slot_id,
+                description,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(2 + description.len());
+
+                buf.extend(slot_id.to_be_bytes());
+                buf.extend(description.bytes());
+            }
+            Self::GetDefaultSlotValue(values) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(values.len() * 3);
+
+                for slot in values {
+                    buf.extend(slot.id.to_be_bytes());
+
                     #[cfg(feature = "alloc")]
-                    core::str::from_utf8(&bytes[0..=1])?.to_string(),
+                    buf.push(slot.value);
                     #[cfg(not(feature = "alloc"))]
-                    String::from_utf8(Vec::<u8, 2>::from_slice(&bytes[0..=1]).unwrap())?,
-                ))
-            }
-            (CommandClass::GetCommandResponse, ParameterId::SoftwareVersionLabel) => {
-                Ok(Self::GetSoftwareVersionLabel(decode_string_bytes(&bytes[..bytes.len().min(32)])?))
+                    buf.push(slot.value).unwrap();
+                }
             }
-            (CommandClass::GetCommandResponse, ParameterId::BootSoftwareVersionId) => {
-                check_msg_len!(bytes, 4);
-                Ok(Self::GetBootSoftwareVersionId(
-                    u32::from_be_bytes(bytes[0..=3].try_into()?)
-                ))
+            Self::GetSensorDefinition(definition) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(13 + definition.description.len());
+
+                #[cfg(feature = "alloc")]
+                buf.push(definition.id);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(definition.id).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(definition.kind.into());
+                #[cfg(not(feature = "alloc"))]
+                buf.push(definition.kind.into()).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(definition.unit.into());
+                #[cfg(not(feature = "alloc"))]
+                buf.push(definition.unit.into()).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(definition.prefix as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(definition.prefix as u8).unwrap();
+
+                buf.extend(definition.range_minimum_value.to_be_bytes());
+                buf.extend(definition.range_maximum_value.to_be_bytes());
+                buf.extend(definition.normal_minimum_value.to_be_bytes());
+                buf.extend(definition.normal_maximum_value.to_be_bytes());
+
+                let recorded_value_support =
+                    (definition.is_lowest_highest_detected_value_supported as u8) << 1
+                        | definition.is_recorded_value_supported as u8;
+
+                #[cfg(feature = "alloc")]
+                buf.push(recorded_value_support);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(recorded_value_support).unwrap();
+
+                buf.extend(definition.description.bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::BootSoftwareVersionLabel) => {
-                Ok(Self::GetBootSoftwareVersionLabel(decode_string_bytes(&bytes[..bytes.len().min(32)])?))
+            Self::GetSensorValue(sensor_value) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(9);
+
+                #[cfg(feature = "alloc")]
+                buf.push(sensor_value.sensor_id);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(sensor_value.sensor_id).unwrap();
+
+                buf.extend(sensor_value.current_value.to_be_bytes());
+                buf.extend(sensor_value.lowest_detected_value.to_be_bytes());
+                buf.extend(sensor_value.highest_detected_value.to_be_bytes());
+                buf.extend(sensor_value.recorded_value.to_be_bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::DmxPersonality) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::GetDmxPersonality {
-                    current_personality: bytes[0],
-                    personality_count: bytes[1],
-                })
+            Self::SetSensorValue(sensor_value) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(9);
+
+                #[cfg(feature = "alloc")]
+                buf.push(sensor_value.sensor_id);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(sensor_value.sensor_id).unwrap();
+
+                buf.extend(sensor_value.current_value.to_be_bytes());
+                buf.extend(sensor_value.lowest_detected_value.to_be_bytes());
+                buf.extend(sensor_value.highest_detected_value.to_be_bytes());
+                buf.extend(sensor_value.recorded_value.to_be_bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::DmxPersonalityDescription) => {
-                check_msg_len!(bytes, 3);
-                Ok(Self::GetDmxPersonalityDescription {
-                    id: bytes[0],
-                    dmx_slots_required: u16::from_be_bytes(bytes[1..=2].try_into()?),
-                    description: decode_string_bytes(&bytes[3..bytes.len().min(3+32)])?,
-                })
+            Self::GetDeviceHours(hours) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(4);
+
+                buf.extend(hours.to_be_bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::DmxStartAddress) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::GetDmxStartAddress(
-                    u16::from_be_bytes(bytes[0..=1].try_into()?)
-                ))
+            Self::GetLampHours(hours) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(4);
+
+                buf.extend(hours.to_be_bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::SlotInfo) => Ok(Self::GetSlotInfo(
-                #[cfg(feature = "alloc")]
-                bytes
-                    .chunks(5)
-                    .map(|chunk| {
-                        Ok(SlotInfo::new(
-                            u16::from_be_bytes(chunk[0..=1].try_into()?),
-                            chunk[2].into(),
-                            u16::from_be_bytes(chunk[3..=4].try_into()?),
-                        ))
-                    })
-                    .collect::<Result<Vec<SlotInfo>, RdmError>>()?,
-                #[cfg(not(feature = "alloc"))]
-                bytes
-                    .chunks(5)
-                    .map(|chunk| {
-                        Ok(SlotInfo::new(
-                            u16::from_be_bytes(chunk[0..=1].try_into()?),
-                            chunk[2].into(),
-                            u16::from_be_bytes(chunk[3..=4].try_into()?),
-                        ))
-                    })
-                    .collect::<Result<Vec<SlotInfo, 46>, RdmError>>()?,
-            )),
-            (CommandClass::GetCommandResponse, ParameterId::SlotDescription) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::GetSlotDescription {
-                    slot_id: u16::from_be_bytes(bytes[0..=1].try_into()?),
-                    description: decode_string_bytes(&bytes[2..bytes.len().min(2+32)])?,
-                })
-            }
-            (CommandClass::GetCommandResponse, ParameterId::DefaultSlotValue) => {
-                Ok(Self::GetDefaultSlotValue(
-                    #[cfg(feature = "alloc")]
-                    bytes
-                        .chunks(3)
-                        .map(|chunk| {
-                            Ok(DefaultSlotValue::new(
-                                u16::from_be_bytes(chunk[0..=1].try_into()?),
-                                chunk[2],
-                            ))
-                        })
-                        .collect::<Result<Vec<DefaultSlotValue>, RdmError>>()?,
-                    #[cfg(not(feature = "alloc"))]
-                    bytes
-                        .chunks(3)
-                        .map(|chunk| {
-                            Ok(DefaultSlotValue::new(
-                                u16::from_be_bytes(chunk[0..=1].try_into()?),
-                                chunk[2],
-                            ))
-                        })
-                        .collect::<Result<Vec<DefaultSlotValue, 77>, RdmError>>()?,
-                ))
+            Self::GetLampStrikes(strikes) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(4);
+
+                buf.extend(strikes.to_be_bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::SensorDefinition) => {
-                check_msg_len!(bytes, 13);
-                Ok(Self::GetSensorDefinition(SensorDefinition {
-                    id: bytes[0],
-                    kind: bytes[1].try_into()?,
-                    unit: bytes[2].try_into()?,
-                    prefix: bytes[3].try_into()?,
-                    range_minimum_value: i16::from_be_bytes(bytes[4..=5].try_into()?),
-                    range_maximum_value: i16::from_be_bytes(bytes[6..=7].try_into()?),
-                    normal_minimum_value: i16::from_be_bytes(bytes[8..=9].try_into()?),
-                    normal_maximum_value: i16::from_be_bytes(bytes[10..=11].try_into()?),
-                    is_lowest_highest_detected_value_supported: bytes[12] >> 1 & 1 == 1,
-                    is_recorded_value_supported: bytes[12] & 1 == 1,
-                    description: decode_string_bytes(&bytes[13..bytes.len().min(13+32)])?,
-                }))
+            Self::GetLampState(state) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1);
+
+                #[cfg(feature = "alloc")]
+                buf.push((*state).into());
+                #[cfg(not(feature = "alloc"))]
+                buf.push((*state).into()).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::SensorValue) => {
-                check_msg_len!(bytes, 9);
-                Ok(Self::GetSensorValue(SensorValue::new(
-                    bytes[0],
-                    i16::from_be_bytes(bytes[1..=2].try_into()?),
-                    i16::from_be_bytes(bytes[3..=4].try_into()?),
-                    i16::from_be_bytes(bytes[5..=6].try_into()?),
-                    i16::from_be_bytes(bytes[7..=8].try_into()?),
-                )))
+            Self::GetLampOnMode(mode) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1);
+
+                #[cfg(feature = "alloc")]
+                buf.push((*mode).into());
+                #[cfg(not(feature = "alloc"))]
+                buf.push((*mode).into()).unwrap();
             }
-            (CommandClass::SetCommandResponse, ParameterId::SensorValue) => {
-                check_msg_len!(bytes, 9);
-                Ok(Self::SetSensorValue(SensorValue::new(
-                    bytes[0],
-                    i16::from_be_bytes(bytes[1..=2].try_into()?),
-                    i16::from_be_bytes(bytes[3..=4].try_into()?),
-                    i16::from_be_bytes(bytes[5..=6].try_into()?),
-                    i16::from_be_bytes(bytes[7..=8].try_into()?),
-                )))
+            Self::GetDevicePowerCycles(cycles) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(4);
+
+                buf.extend(cycles.to_be_bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::DeviceHours) => {
-                check_msg_len!(bytes, 4);
-                Ok(Self::GetDeviceHours(
-                    u32::from_be_bytes(bytes[0..=3].try_into()?)
-                ))
+            Self::GetDisplayInvert(mode) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*mode as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*mode as u8).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::LampHours) => {
-                check_msg_len!(bytes, 4);
-                Ok(Self::GetLampHours(
-                    u32::from_be_bytes(bytes[0..=3].try_into()?),
-                ))
+            Self::GetDisplayLevel(level) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*level);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*level).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::LampStrikes) => {
-                check_msg_len!(bytes, 4);
-                Ok(Self::GetLampStrikes(
-                    u32::from_be_bytes(bytes[0..=3].try_into()?)
-                ))
+            Self::GetPanInvert(invert) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*invert as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*invert as u8).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::LampState) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetLampState(bytes[0].try_into()?))
+            Self::GetTiltInvert(invert) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*invert as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*invert as u8).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::LampOnMode) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetLampOnMode(bytes[0].try_into()?))
+            Self::GetPanTiltSwap(swap) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*swap as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*swap as u8).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::DevicePowerCycles) => {
-                check_msg_len!(bytes, 4);
-                Ok(Self::GetDevicePowerCycles(
-                    u32::from_be_bytes(bytes[0..=3].try_into()?)
-                ))
+            Self::GetRealTimeClock {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(0x07);
+
+                buf.extend((*year).to_be_bytes());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*month);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*month).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*day);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*day).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*hour);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*hour).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*minute);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*minute).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*second);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*second).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::DisplayInvert) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetDisplayInvert(bytes[0].try_into()?))
+            Self::GetIdentifyDevice(identifying) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*identifying as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*identifying as u8).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::DisplayLevel) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetDisplayLevel(bytes[0]))
+            Self::GetPowerState(state) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*state as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*state as u8).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::PanInvert) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetPanInvert(bytes[0] == 1))
+            Self::GetPerformSelfTest(test) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*test as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*test as u8).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::TiltInvert) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetTiltInvert(bytes[0] == 1))
+            Self::GetSelfTestDescription {
+                self_test_id,
+                description,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1 + description.len());
+
+                #[cfg(feature = "alloc")]
+                buf.push((*self_test_id).into());
+                #[cfg(not(feature = "alloc"))]
+                buf.push((*self_test_id).into()).unwrap();
+
+                buf.extend(description.bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::PanTiltSwap) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetPanTiltSwap(bytes[0] == 1))
+            Self::GetPresetPlayback { mode, level } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(3);
+
+                buf.extend(u16::from(*mode).to_be_bytes());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*level);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*level).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::RealTimeClock) => {
-                check_msg_len!(bytes, 7);
-                Ok(Self::GetRealTimeClock {
-                    year: u16::from_be_bytes(bytes[0..=1].try_into()?),
-                    month: bytes[2],
-                    day: bytes[3],
-                    hour: bytes[4],
-                    minute: bytes[5],
-                    second: bytes[6],
-                })
-            }
-            (CommandClass::GetCommandResponse, ParameterId::IdentifyDevice) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetIdentifyDevice(bytes[0] == 1))
-            }
-            (CommandClass::GetCommandResponse, ParameterId::PowerState) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetPowerState(bytes[0].try_into()?))
+            Self::GetIdentifyMode(identify_mode) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*identify_mode as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*identify_mode as u8).unwrap();
+            },
+            Self::GetDmxBlockAddress {
+                total_sub_device_footprint,
+                base_dmx_address,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(4);
+
+                buf.extend(total_sub_device_footprint.to_be_bytes());
+                buf.extend(base_dmx_address.to_be_bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::PerformSelfTest) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetPerformSelfTest(bytes[0] == 1))
+            Self::GetDmxFailMode {
+                scene_id,
+                loss_of_signal_delay,
+                hold_time,
+                level,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(7);
+
+                buf.extend(u16::from(*scene_id).to_be_bytes());
+                buf.extend(u16::from(*loss_of_signal_delay).to_be_bytes());
+                buf.extend(u16::from(*hold_time).to_be_bytes());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*level);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*level).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::SelfTestDescription) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetSelfTestDescription {
-                    self_test_id: bytes[0].into(),
-                    description: decode_string_bytes(&bytes[1..bytes.len().min(1+32)])?,
-                })
+            Self::GetDmxStartupMode {
+                scene_id,
+                startup_delay,
+                hold_time,
+                level,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(7);
+
+                buf.extend(u16::from(*scene_id).to_be_bytes());
+                buf.extend(u16::from(*startup_delay).to_be_bytes());
+                buf.extend(u16::from(*hold_time).to_be_bytes());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*level);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*level).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::PresetPlayback) => {
-                check_msg_len!(bytes, 3);
-                Ok(Self::GetPresetPlayback {
-                    mode: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                    level: bytes[2],
-                })
+            Self::GetPowerOnSelfTest(test) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*test as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*test as u8).unwrap();
             }
-            // E1.37-1
-            (CommandClass::GetCommandResponse, ParameterId::IdentifyMode) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetIdentifyMode(
-                    bytes[0].try_into()?
-                ))
-            },
-            (CommandClass::GetCommandResponse, ParameterId::DmxBlockAddress) => {
-                check_msg_len!(bytes, 4);
-                Ok(Self::GetDmxBlockAddress {
-                    total_sub_device_footprint: u16::from_be_bytes(bytes[0..=1].try_into()?),
-                    base_dmx_address: u16::from_be_bytes(bytes[2..=3].try_into()?),
-                })
+            Self::GetLockState {
+                lock_state_id,
+                lock_state_count,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(2);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*lock_state_id);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*lock_state_id).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*lock_state_count);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*lock_state_count).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::DmxFailMode) => {
-                check_msg_len!(bytes, 7);
-                Ok(Self::GetDmxFailMode {
-                    scene_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                    loss_of_signal_delay: u16::from_be_bytes(bytes[2..=3].try_into()?).into(),
-                    hold_time: u16::from_be_bytes(bytes[4..=5].try_into()?).into(),
-                    level: bytes[6],
-                })
+            Self::GetLockStateDescription {
+                lock_state_id,
+                description,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1 + description.len());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*lock_state_id);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*lock_state_id).unwrap();
+
+                buf.extend(description.bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::DmxStartupMode) => {
-                check_msg_len!(bytes, 7);
-                Ok(Self::GetDmxStartupMode {
-                    scene_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                    startup_delay: u16::from_be_bytes(bytes[2..=3].try_into()?).into(),
-                    hold_time: u16::from_be_bytes(bytes[4..=5].try_into()?).into(),
-                    level: bytes[6],
-                })
+            Self::GetLockPin(pin) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(4);
+
+                buf.extend(pin.0.to_be_bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::PowerOnSelfTest) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetPowerOnSelfTest(bytes[0] == 1))
+            Self::GetBurnIn(hours) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*hours);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*hours).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::LockState) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::GetLockState {
-                    lock_state_id: bytes[0],
-                    lock_state_count: bytes[1],
-                })
+            Self::GetDimmerInfo {
+                minimum_level_lower_limit,
+                minimum_level_upper_limit,
+                maximum_level_lower_limit,
+                maximum_level_upper_limit,
+                number_of_supported_curves,
+                levels_resolution,
+                minimum_level_split_levels_supported,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(11);
+
+                buf.extend(minimum_level_lower_limit.to_be_bytes());
+                buf.extend(minimum_level_upper_limit.to_be_bytes());
+                buf.extend(maximum_level_lower_limit.to_be_bytes());
+                buf.extend(maximum_level_upper_limit.to_be_bytes());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*number_of_supported_curves);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*number_of_supported_curves).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*levels_resolution);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*levels_resolution).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*minimum_level_split_levels_supported as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*minimum_level_split_levels_supported as u8)
+                    .unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::LockStateDescription) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetLockStateDescription {
-                    lock_state_id: bytes[0],
-                    description: decode_string_bytes(&bytes[1..bytes.len().min(1+32)])?,
-                })
+            Self::GetMinimumLevel {
+                minimum_level_increasing,
+                minimum_level_decreasing,
+                on_below_minimum,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(5);
+
+                buf.extend(minimum_level_increasing.to_be_bytes());
+                buf.extend(minimum_level_decreasing.to_be_bytes());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*on_below_minimum as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*on_below_minimum as u8).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::LockPin) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::GetLockPin(
-                    PinCode::try_from(u16::from_be_bytes(bytes[0..=1].try_into()?))?,
-                ))
+            Self::GetMaximumLevel(level) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(2);
+
+                buf.extend(level.to_be_bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::BurnIn) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetBurnIn(bytes[0]))
+            Self::GetCurve {
+                curve_id,
+                curve_count,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(2);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*curve_id);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*curve_id).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*curve_count);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*curve_count).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::DimmerInfo) => {
-                check_msg_len!(bytes, 11);
-                Ok(Self::GetDimmerInfo {
-                    minimum_level_lower_limit: u16::from_be_bytes(bytes[0..=1].try_into()?),
-                    minimum_level_upper_limit: u16::from_be_bytes(bytes[2..=3].try_into()?),
-                    maximum_level_lower_limit: u16::from_be_bytes(bytes[4..=5].try_into()?),
-                    maximum_level_upper_limit: u16::from_be_bytes(bytes[6..=7].try_into()?),
-                    number_of_supported_curves: bytes[8],
-                    levels_resolution: bytes[9],
-                    minimum_level_split_levels_supported: bytes[10] == 1,
-                })
+            Self::GetCurveDescription {
+                curve_id,
+                description,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1 + description.len());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*curve_id);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*curve_id).unwrap();
+
+                buf.extend(description.bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::MinimumLevel) => {
-                check_msg_len!(bytes, 5);
-                Ok(Self::GetMinimumLevel {
-                    minimum_level_increasing: u16::from_be_bytes(bytes[0..=1].try_into()?),
-                    minimum_level_decreasing: u16::from_be_bytes(bytes[2..=3].try_into()?),
-                    on_below_minimum: bytes[4] == 1,
-                })
+            Self::GetOutputResponseTime {
+                response_time_id,
+                response_time_count,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(2);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*response_time_id);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*response_time_id).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*response_time_count);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*response_time_count).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::MaximumLevel) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::GetMaximumLevel(
-                    u16::from_be_bytes(bytes[0..=1].try_into()?)
-                ))
+            Self::GetOutputResponseTimeDescription {
+                response_time_id,
+                description,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1 + description.len());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*response_time_id);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*response_time_id).unwrap();
+
+                buf.extend(description.bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::Curve) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::GetCurve {
-                    curve_id: bytes[0],
-                    curve_count: bytes[1],
-                })
+            Self::GetModulationFrequency {
+                modulation_frequency_id,
+                modulation_frequency_count,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(2);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*modulation_frequency_id);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*modulation_frequency_id).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*modulation_frequency_count);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*modulation_frequency_count).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::CurveDescription) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetCurveDescription {
-                    curve_id: bytes[0],
-                    description: decode_string_bytes(&bytes[1..bytes.len().min(1+32)])?,
-                })
+            Self::GetModulationFrequencyDescription {
+                modulation_frequency_id,
+                frequency,
+                description,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(5 + description.len());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*modulation_frequency_id);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*modulation_frequency_id).unwrap();
+
+                buf.extend(frequency.to_be_bytes());
+                buf.extend(description.bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::OutputResponseTime) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::GetOutputResponseTime {
-                    response_time_id: bytes[0],
-                    response_time_count: bytes[1],
-                })
+            Self::GetPresetInfo {
+                level_field_supported,
+                preset_sequence_supported,
+                split_times_supported,
+                dmx_fail_infinite_delay_time_supported,
+                dmx_fail_infinite_hold_time_supported,
+                startup_infinite_hold_time_supported,
+                maximum_scene_number,
+                minimum_preset_fade_time_supported,
+                maximum_preset_fade_time_supported,
+                minimum_preset_wait_time_supported,
+                maximum_preset_wait_time_supported,
+                minimum_dmx_fail_delay_time_supported,
+                maximum_dmx_fail_delay_time_supported,
+                minimum_dmx_fail_hold_time_supported,
+                maximum_dmx_fail_hold_time_supported,
+                minimum_startup_delay_time_supported,
+                maximum_startup_delay_time_supported,
+                minimum_startup_hold_time_supported,
+                maximum_startup_hold_time_supported,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(38);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*level_field_supported as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*level_field_supported as u8).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*preset_sequence_supported as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*preset_sequence_supported as u8).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*split_times_supported as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*split_times_supported as u8).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*dmx_fail_infinite_delay_time_supported as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*dmx_fail_infinite_delay_time_supported as u8)
+                    .unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*dmx_fail_infinite_hold_time_supported as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*dmx_fail_infinite_hold_time_supported as u8)
+                    .unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*startup_infinite_hold_time_supported as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*startup_infinite_hold_time_supported as u8)
+                    .unwrap();
+
+                buf.extend(maximum_scene_number.to_be_bytes());
+                buf.extend(minimum_preset_fade_time_supported.to_be_bytes());
+                buf.extend(maximum_preset_fade_time_supported.to_be_bytes());
+                buf.extend(minimum_preset_wait_time_supported.to_be_bytes());
+                buf.extend(maximum_preset_wait_time_supported.to_be_bytes());
+
+                buf.extend(u16::from(*minimum_dmx_fail_delay_time_supported).to_be_bytes());
+                buf.extend(u16::from(*maximum_dmx_fail_delay_time_supported).to_be_bytes());
+                buf.extend(u16::from(*minimum_dmx_fail_hold_time_supported).to_be_bytes());
+                buf.extend(u16::from(*maximum_dmx_fail_hold_time_supported).to_be_bytes());
+                buf.extend(u16::from(*minimum_startup_delay_time_supported).to_be_bytes());
+                buf.extend(u16::from(*maximum_startup_delay_time_supported).to_be_bytes());
+                buf.extend(u16::from(*minimum_startup_hold_time_supported).to_be_bytes());
+                buf.extend(u16::from(*maximum_startup_hold_time_supported).to_be_bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::OutputResponseTimeDescription) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetOutputResponseTimeDescription {
-                    response_time_id: bytes[0],
-                    description: decode_string_bytes(&bytes[1..bytes.len().min(1+32)])?,
-                })
+            Self::GetPresetStatus {
+                scene_id,
+                up_fade_time,
+                down_fade_time,
+                wait_time,
+                programmed,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(9);
+
+                buf.extend(scene_id.to_be_bytes());
+                buf.extend(up_fade_time.to_be_bytes());
+                buf.extend(down_fade_time.to_be_bytes());
+                buf.extend(wait_time.to_be_bytes());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*programmed as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*programmed as u8).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::ModulationFrequency) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::GetModulationFrequency {
-                    modulation_frequency_id: bytes[0],
-                    modulation_frequency_count: bytes[1],
-                })
+            Self::GetPresetMergeMode(mode) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*mode as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*mode as u8).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::ModulationFrequencyDescription) => {
-                check_msg_len!(bytes, 5);
-                Ok(Self::GetModulationFrequencyDescription {
-                    modulation_frequency_id: bytes[0],
-                    frequency: u32::from_be_bytes(bytes[1..=4].try_into()?),
-                    description: decode_string_bytes(&bytes[5..bytes.len().min(5+32)])?,
-                })
+            Self::GetListInterfaces(interfaces) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(interfaces.len() * 6);
+
+                for interface in interfaces {
+                    buf.extend(interface.interface_id.to_be_bytes());
+                    buf.extend(u16::from(interface.hardware_type).to_be_bytes());
+                }
             }
-            (CommandClass::GetCommandResponse, ParameterId::PresetInfo) => {
-                check_msg_len!(bytes, 32);
-                Ok(Self::GetPresetInfo {
-                    level_field_supported: bytes[0] == 1,
-                    preset_sequence_supported: bytes[1] == 1,
-                    split_times_supported: bytes[2] == 1,
-                    dmx_fail_infinite_delay_time_supported: bytes[3] == 1,
-                    dmx_fail_infinite_hold_time_supported: bytes[4] == 1,
-                    startup_infinite_hold_time_supported: bytes[5] == 1,
-                    maximum_scene_number: u16::from_be_bytes(bytes[6..=7].try_into()?),
-                    minimum_preset_fade_time_supported: u16::from_be_bytes(
-                        bytes[8..=9].try_into()?,
-                    ),
-                    maximum_preset_fade_time_supported: u16::from_be_bytes(
-                        bytes[10..=11].try_into()?,
-                    ),
-                    minimum_preset_wait_time_supported: u16::from_be_bytes(
-                        bytes[12..=13].try_into()?,
-                    ),
-                    maximum_preset_wait_time_supported: u16::from_be_bytes(
-                        bytes[14..=15].try_into()?,
-                    ),
-                    minimum_dmx_fail_delay_time_supported: SupportedTimes::from(
-                        u16::from_be_bytes(bytes[16..=17].try_into()?),
-                    ),
-                    maximum_dmx_fail_delay_time_supported: SupportedTimes::from(
-                        u16::from_be_bytes(bytes[18..=19].try_into()?),
-                    ),
-                    minimum_dmx_fail_hold_time_supported: SupportedTimes::from(u16::from_be_bytes(
-                        bytes[20..=21].try_into()?,
-                    )),
-                    maximum_dmx_fail_hold_time_supported: SupportedTimes::from(u16::from_be_bytes(
-                        bytes[22..=23].try_into()?,
-                    )),
-                    minimum_startup_delay_time_supported: SupportedTimes::from(u16::from_be_bytes(
-                        bytes[24..=25].try_into()?,
-                    )),
-                    maximum_startup_delay_time_supported: SupportedTimes::from(u16::from_be_bytes(
-                        bytes[26..=27].try_into()?,
-                    )),
-                    minimum_startup_hold_time_supported: SupportedTimes::from(u16::from_be_bytes(
-                        bytes[28..=29].try_into()?,
-                    )),
-                    maximum_startup_hold_time_supported: SupportedTimes::from(u16::from_be_bytes(
-                        bytes[30..=31].try_into()?,
-                    )),
-                })
-            }
-            (CommandClass::GetCommandResponse, ParameterId::PresetStatus) => {
-                check_msg_len!(bytes, 9);
-                Ok(Self::GetPresetStatus {
-                    scene_id: u16::from_be_bytes(bytes[0..=1].try_into()?),
-                    up_fade_time: u16::from_be_bytes(bytes[2..=3].try_into()?),
-                    down_fade_time: u16::from_be_bytes(bytes[4..=5].try_into()?),
-                    wait_time: u16::from_be_bytes(bytes[6..=7].try_into()?),
-                    programmed: PresetProgrammed::try_from(bytes[8])?,
-                })
-            }
-            (CommandClass::GetCommandResponse, ParameterId::PresetMergeMode) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetPresetMergeMode(MergeMode::try_from(bytes[0])?))
+            Self::GetInterfaceLabel {
+                interface_id,
+                interface_label,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(4 + interface_label.len());
+
+                buf.extend(interface_id.to_be_bytes());
+                buf.extend(interface_label.bytes());
             }
-            // E1.37-2
-            (CommandClass::GetCommandResponse, ParameterId::ListInterfaces) => {
-                Ok(Self::GetListInterfaces(
-                    #[cfg(feature = "alloc")]
-                    bytes
-                        .chunks(6)
-                        .map(|chunk| {
-                            Ok(NetworkInterface {
-                                interface_id: u32::from_be_bytes(chunk[0..=3].try_into()?),
-                                hardware_type: u16::from_be_bytes(chunk[4..=5].try_into()?).into(),
-                            })
-                        })
-                        .collect::<Result<Vec<NetworkInterface>, RdmError>>()?,
-                    #[cfg(not(feature = "alloc"))]
-                    bytes
-                        .chunks(6)
-                        .map(|chunk| {
-                            Ok(NetworkInterface {
-                                interface_id: u32::from_be_bytes(chunk[0..=3].try_into()?),
-                                hardware_type: u16::from_be_bytes(chunk[4..=5].try_into()?).into(),
-                            })
-                        })
-                        .collect::<Result<Vec<NetworkInterface, 38>, RdmError>>()?,
-                ))
+            Self::GetInterfaceHardwareAddressType1 {
+                interface_id,
+                hardware_address,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(10);
+
+                buf.extend(interface_id.to_be_bytes());
+                buf.extend(hardware_address.into_array());
             }
-            (CommandClass::GetCommandResponse, ParameterId::InterfaceLabel) => {
-                check_msg_len!(bytes, 4);
-                Ok(Self::GetInterfaceLabel {
-                    interface_id: u32::from_be_bytes(bytes[0..=3].try_into()?),
-                    interface_label: decode_string_bytes(&bytes[4..bytes.len().min(4+32)])?,
-                })
+            Self::GetIpV4DhcpMode {
+                interface_id,
+                dhcp_mode,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(5);
+
+                buf.extend(interface_id.to_be_bytes());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*dhcp_mode as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*dhcp_mode as u8).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::InterfaceHardwareAddressType1) => {
-                check_msg_len!(bytes, 10);
-                Ok(Self::GetInterfaceHardwareAddressType1 {
-                    interface_id: u32::from_be_bytes(bytes[0..=3].try_into()?),
-                    hardware_address: <[u8; 6]>::try_from(&bytes[4..=9])?.into(),
-                })
+            Self::GetIpV4ZeroConfMode {
+                interface_id,
+                zero_conf_mode,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(5);
+
+                buf.extend(interface_id.to_be_bytes());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*zero_conf_mode as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*zero_conf_mode as u8).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::IpV4DhcpMode) => {
-                check_msg_len!(bytes, 5);
-                Ok(Self::GetIpV4DhcpMode {
-                    interface_id: u32::from_be_bytes(bytes[0..=3].try_into()?),
-                    dhcp_mode: bytes[4] == 1,
-                })
+            Self::GetIpV4CurrentAddress {
+                interface_id,
+                address,
+                netmask,
+                dhcp_status,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(13);
+
+                buf.extend(interface_id.to_be_bytes());
+                buf.extend(<[u8; 4]>::from(*address));
+
+                #[cfg(feature = "alloc")]
+                buf.push(*netmask);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*netmask).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*dhcp_status as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*dhcp_status as u8).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::IpV4ZeroConfMode) => {
-                check_msg_len!(bytes, 5);
-                Ok(Self::GetIpV4ZeroConfMode {
-                    interface_id: u32::from_be_bytes(bytes[0..=3].try_into()?),
-                    zero_conf_mode: bytes[4] == 1,
-                })
+            Self::GetIpV4StaticAddress {
+                interface_id,
+                address,
+                netmask,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(10);
+
+                buf.extend(interface_id.to_be_bytes());
+                buf.extend(<[u8; 4]>::from(*address));
+
+                #[cfg(feature = "alloc")]
+                buf.push(*netmask);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*netmask).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::IpV4CurrentAddress) => {
-                check_msg_len!(bytes, 10);
-                Ok(Self::GetIpV4CurrentAddress {
-                    interface_id: u32::from_be_bytes(bytes[0..=3].try_into()?),
-                    address: <[u8; 4]>::try_from(&bytes[4..=7])?.into(),
-                    netmask: bytes[8],
-                    dhcp_status: DhcpMode::try_from(bytes[9])?,
-                })
+            Self::GetIpV4DefaultRoute {
+                interface_id,
+                address,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(6);
+
+                buf.extend(interface_id.to_be_bytes());
+                buf.extend(<[u8; 4]>::from(*address));
             }
-            (CommandClass::GetCommandResponse, ParameterId::IpV4StaticAddress) => {
-                check_msg_len!(bytes, 9);
-                Ok(Self::GetIpV4StaticAddress {
-                    interface_id: u32::from_be_bytes(bytes[0..=3].try_into()?),
-                    address: <[u8; 4]>::try_from(&bytes[4..=7])?.into(),
-                    netmask: bytes[8],
-                })
+            Self::GetDnsIpV4NameServer {
+                name_server_index,
+                address,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(5);
+
+                buf.extend(name_server_index.to_be_bytes());
+                buf.extend(<[u8; 4]>::from(*address));
             }
-            (CommandClass::GetCommandResponse, ParameterId::IpV4DefaultRoute) => {
-                check_msg_len!(bytes, 8);
-                Ok(Self::GetIpV4DefaultRoute {
-                    interface_id: u32::from_be_bytes(bytes[0..=3].try_into()?),
-                    address: <[u8; 4]>::try_from(&bytes[4..=7])?.into(),
-                })
+            Self::GetDnsHostName(host_name) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(host_name.len());
+
+                buf.extend(host_name.bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::DnsIpV4NameServer) => {
-                check_msg_len!(bytes, 5);
-                Ok(Self::GetDnsIpV4NameServer {
-                    name_server_index: bytes[0],
-                    address: <[u8; 4]>::try_from(&bytes[1..=4])?.into(),
-                })
+            Self::GetDnsDomainName(domain_name) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(domain_name.len());
+
+                buf.extend(domain_name.bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::DnsHostName) => {
-                Ok(Self::GetDnsHostName(decode_string_bytes(&bytes[..bytes.len().min(63)])?))
-            },
-            (CommandClass::GetCommandResponse, ParameterId::DnsDomainName) => {
-                Ok(Self::GetDnsHostName(decode_string_bytes(&bytes[..bytes.len().min(231)])?))
-            },
             // E1.37-7
-            (CommandClass::GetCommandResponse, ParameterId::EndpointList) => {
-                check_msg_len!(bytes, 4);
-                Ok(Self::GetEndpointList {
-                    list_change_number: u32::from_be_bytes(bytes[0..=3].try_into()?),
+            Self::GetEndpointList {
+                list_change_number,
+                endpoint_list,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(4 + (endpoint_list.len() * 3));
+
+                buf.extend(list_change_number.to_be_bytes());
+
+                for (endpoint_id, endpoint_type) in endpoint_list {
+                    buf.extend(u16::from(*endpoint_id).to_be_bytes());
+
                     #[cfg(feature = "alloc")]
-                    endpoint_list: bytes[4..]
-                        .chunks(3)
-                        .map(|chunk| {
-                            Ok((
-                                u16::from_be_bytes(chunk[0..=1].try_into()?).into(),
-                                chunk[1].try_into()?,
-                            ))
-                        })
-                        .collect::<Result<Vec<(EndpointId, EndpointType)>, RdmError>>()?,
+                    buf.push(*endpoint_type as u8);
                     #[cfg(not(feature = "alloc"))]
-                    endpoint_list: bytes[4..]
-                        .chunks(6)
-                        .map(|chunk| {
-                            Ok((
-                                u16::from_be_bytes(chunk[0..=1].try_into()?).into(),
-                                chunk[1].try_into()?,
-                            ))
-                        })
-                        .collect::<Result<Vec<(EndpointId, EndpointType), 75>, RdmError>>()?,
-                })
-            }
-            (CommandClass::GetCommandResponse, ParameterId::EndpointListChange) => {
-                check_msg_len!(bytes, 4);
-                Ok(Self::GetEndpointListChange {
-                    list_change_number: u32::from_be_bytes(bytes[0..=3].try_into()?),
-                })
+                    buf.push(*endpoint_type as u8).unwrap();
+                }
             }
-            (CommandClass::GetCommandResponse, ParameterId::IdentifyEndpoint) => {
-                check_msg_len!(bytes, 3);
-                Ok(Self::GetIdentifyEndpoint {
-                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                    identify: bytes[2] == 1,
-                })
+            Self::GetEndpointListChange { list_change_number } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(4);
+
+                buf.extend(list_change_number.to_be_bytes());
             }
-            (CommandClass::SetCommandResponse, ParameterId::IdentifyEndpoint) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::SetIdentifyEndpoint {
-                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                })
+            Self::GetIdentifyEndpoint {
+                endpoint_id,
+                identify,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(3);
+
+                buf.extend(u16::from(*endpoint_id).to_be_bytes());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*identify as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*identify as u8).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::EndpointToUniverse) => {
-                check_msg_len!(bytes, 4);
-                Ok(Self::GetEndpointToUniverse {
-                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                    universe: u16::from_be_bytes(bytes[2..=3].try_into()?),
-                })
+            Self::SetIdentifyEndpoint { endpoint_id } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(2);
+
+                buf.extend(u16::from(*endpoint_id).to_be_bytes());
             }
-            (CommandClass::SetCommandResponse, ParameterId::EndpointToUniverse) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::SetEndpointToUniverse {
-                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                })
+            Self::GetEndpointToUniverse {
+                endpoint_id,
+                universe,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(4);
+
+                buf.extend(u16::from(*endpoint_id).to_be_bytes());
+                buf.extend(universe.to_be_bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::EndpointMode) => {
-                check_msg_len!(bytes, 3);
-                Ok(Self::GetEndpointMode {
-                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                    mode: bytes[2].try_into()?,
-                })
+            Self::SetEndpointToUniverse { endpoint_id } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(2);
+
+                buf.extend(u16::from(*endpoint_id).to_be_bytes());
             }
-            (CommandClass::SetCommandResponse, ParameterId::EndpointMode) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::SetEndpointMode {
-                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                })
+            Self::GetEndpointMode { endpoint_id, mode } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(3);
+
+                buf.extend(u16::from(*endpoint_id).to_be_bytes());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*mode as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*mode as u8).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::EndpointLabel) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::GetEndpointLabel {
-                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                    label: decode_string_bytes(&bytes[2..bytes.len().min(1+32)])?,
-                })
+            Self::SetEndpointMode { endpoint_id } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(2);
+
+                buf.extend(u16::from(*endpoint_id).to_be_bytes());
             }
-            (CommandClass::SetCommandResponse, ParameterId::EndpointLabel) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::SetEndpointLabel {
-                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                })
+            Self::GetEndpointLabel { endpoint_id, label } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(3 + label.len());
+
+                buf.extend(u16::from(*endpoint_id).to_be_bytes());
+                buf.extend(label.bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::RdmTrafficEnable) => {
-                check_msg_len!(bytes, 3);
-                Ok(Self::GetRdmTrafficEnable {
-                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                    enable: bytes[2] == 1,
-                })
+            Self::SetEndpointLabel { endpoint_id } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(2);
+
+                buf.extend(u16::from(*endpoint_id).to_be_bytes());
             }
-            (CommandClass::SetCommandResponse, ParameterId::RdmTrafficEnable) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::SetRdmTrafficEnable {
-                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                })
+            Self::GetRdmTrafficEnable {
+                endpoint_id,
+                enable,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(3);
+
+                buf.extend(u16::from(*endpoint_id).to_be_bytes());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*enable as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*enable as u8).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::DiscoveryState) => {
-                check_msg_len!(bytes, 5);
-                Ok(Self::GetDiscoveryState {
-                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                    device_count: u16::from_be_bytes(bytes[2..=3].try_into()?).into(),
-                    discovery_state: bytes[4].try_into()?,
-                })
+            Self::SetRdmTrafficEnable { endpoint_id } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(2);
+
+                buf.extend(u16::from(*endpoint_id).to_be_bytes());
             }
-            (CommandClass::SetCommandResponse, ParameterId::DiscoveryState) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::SetDiscoveryState {
-                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                })
+            Self::GetDiscoveryState {
+                endpoint_id,
+                device_count,
+                discovery_state,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(5);
+
+                buf.extend(u16::from(*endpoint_id).to_be_bytes());
+                buf.extend(u16::from(*device_count).to_be_bytes());
+
+                #[cfg(feature = "alloc")]
+                buf.push((*discovery_state).into());
+                #[cfg(not(feature = "alloc"))]
+                buf.push((*discovery_state).into()).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::BackgroundDiscovery) => {
-                check_msg_len!(bytes, 3);
-                Ok(Self::GetBackgroundDiscovery {
-                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                    enabled: bytes[2] == 1,
-                })
+            Self::SetDiscoveryState { endpoint_id } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(2);
+
+                buf.extend(u16::from(*endpoint_id).to_be_bytes());
             }
-            (CommandClass::SetCommandResponse, ParameterId::BackgroundDiscovery) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::SetBackgroundDiscovery {
-                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                })
+            Self::GetBackgroundDiscovery {
+                endpoint_id,
+                enabled,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(3);
+
+                buf.extend(u16::from(*endpoint_id).to_be_bytes());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*enabled as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*enabled as u8).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::EndpointTiming) => {
-                check_msg_len!(bytes, 4);
-                Ok(Self::GetEndpointTiming {
-                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                    current_setting_id: bytes[2],
-                    setting_count: bytes[3],
-                })
+            Self::SetBackgroundDiscovery { endpoint_id } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(2);
+
+                buf.extend(u16::from(*endpoint_id).to_be_bytes());
             }
-            (CommandClass::SetCommandResponse, ParameterId::EndpointTiming) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::SetEndpointTiming {
-                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                })
+            Self::GetEndpointTiming {
+                endpoint_id,
+                current_setting_id,
+                setting_count,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(4);
+
+                buf.extend(u16::from(*endpoint_id).to_be_bytes());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*current_setting_id);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*current_setting_id).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*setting_count);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*setting_count).unwrap();
             }
-            (CommandClass::GetCommandResponse, ParameterId::EndpointTimingDescription) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetEndpointTimingDescription {
-                    setting_id: bytes[0],
-                    description: decode_string_bytes(&bytes[1..bytes.len().min(1+32)])?,
-                })
+            Self::SetEndpointTiming { endpoint_id } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(2);
+
+                buf.extend(u16::from(*endpoint_id).to_be_bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::EndpointResponders) => {
-                check_msg_len!(bytes, 6);
-                Ok(Self::GetEndpointResponders {
-                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                    list_change_number: u32::from_be_bytes(bytes[2..=5].try_into()?),
-                    #[cfg(feature = "alloc")]
-                    responders: bytes[6..]
-                        .chunks(6)
-                        .map(|chunk| {
-                            Ok(DeviceUID::new(
-                                u16::from_be_bytes(chunk[0..=1].try_into()?),
-                                u32::from_be_bytes(chunk[2..=5].try_into()?),
-                            ))
-                        })
-                        .collect::<Result<Vec<DeviceUID>, RdmError>>()?,
-                    #[cfg(not(feature = "alloc"))]
-                    responders: bytes[6..]
-                        .chunks(6)
-                        .map(|chunk| {
-                            Ok(DeviceUID::new(
-                                u16::from_be_bytes(chunk[0..=1].try_into()?),
-                                u32::from_be_bytes(chunk[2..=5].try_into()?),
-                            ))
-                        })
-                        .collect::<Result<Vec<DeviceUID, 37>, RdmError>>()?,
-                })
+            Self::GetEndpointTimingDescription {
+                setting_id,
+                description,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1 + description.len());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*setting_id);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*setting_id).unwrap();
+
+                buf.extend(description.bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::EndpointResponderListChange) => {
-                check_msg_len!(bytes, 6);
-                Ok(Self::GetEndpointResponderListChange {
-                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                    list_change_number: u32::from_be_bytes(bytes[2..=5].try_into()?),
-                })
+            Self::GetEndpointResponders {
+                endpoint_id,
+                list_change_number,
+                responders,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(6 + (responders.len() * 6));
+
+                buf.extend(u16::from(*endpoint_id).to_be_bytes());
+                buf.extend(list_change_number.to_be_bytes());
+
+                for responder in responders {
+                    buf.extend(<[u8; 6]>::from(*responder));
+                }
             }
-            (CommandClass::GetCommandResponse, ParameterId::BindingControlFields) => {
-                check_msg_len!(bytes, 16);
-                Ok(Self::GetBindingControlFields {
-                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
-                    uid: DeviceUID::new(
-                        u16::from_be_bytes(bytes[2..=3].try_into()?),
-                        u32::from_be_bytes(bytes[4..=7].try_into()?),
-                    ),
-                    control_field: u16::from_be_bytes(bytes[8..=9].try_into()?),
-                    binding_uid: DeviceUID::new(
-                        u16::from_be_bytes(bytes[10..=11].try_into()?),
-                        u32::from_be_bytes(bytes[12..=15].try_into()?),
-                    ),
-                })
+            Self::GetEndpointResponderListChange {
+                endpoint_id,
+                list_change_number,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(6);
+
+                buf.extend(u16::from(*endpoint_id).to_be_bytes());
+                buf.extend(list_change_number.to_be_bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::BackgroundQueuedStatusPolicy) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::GetBackgroundQueuedStatusPolicy {
-                    current_policy_id: bytes[0],
-                    policy_count: bytes[1],
-                })
+            Self::GetBindingControlFields {
+                endpoint_id,
+                uid,
+                control_field,
+                binding_uid,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(3);
+
+                buf.extend(u16::from(*endpoint_id).to_be_bytes());
+                buf.extend(<[u8; 6]>::from(*uid));
+                buf.extend((*control_field).to_be_bytes());
+                buf.extend(<[u8; 6]>::from(*binding_uid));
             }
-            (CommandClass::GetCommandResponse, ParameterId::BackgroundQueuedStatusPolicyDescription) => {
-                check_msg_len!(bytes, 1);
-                Ok(Self::GetBackgroundQueuedStatusPolicyDescription {
-                    policy_id: bytes[0],
-                    description: decode_string_bytes(&bytes[1..])?,
-                })
+            Self::GetBackgroundQueuedStatusPolicy {
+                current_policy_id,
+                policy_count,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(2);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*current_policy_id);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*current_policy_id).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*policy_count);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*policy_count).unwrap();
+            }
+            Self::GetBackgroundQueuedStatusPolicyDescription {
+                policy_id,
+                description,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(1 + description.len());
+
+                #[cfg(feature = "alloc")]
+                buf.push(*policy_id);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*policy_id).unwrap();
+
+                buf.extend(description.bytes());
             }
             // E1.33
-            (CommandClass::GetCommandResponse, ParameterId::ComponentScope) => {
-                check_msg_len!(bytes, 86);
-                Ok(Self::GetComponentScope {
-                    scope_slot: u16::from_be_bytes(bytes[0..=1].try_into()?),
-                    scope_string: decode_string_bytes(&bytes[2..=64])?,
-                    static_config_type: bytes[65].try_into()?,
-                    static_ipv4_address: <[u8; 4]>::try_from(&bytes[66..=69])?.into(),
-                    static_ipv6_address: <[u8; 16]>::try_from(&bytes[70..=85])?.into(),
-                    static_port: u16::from_be_bytes(bytes[2..=3].try_into()?),
-                })
+            Self::GetComponentScope {
+                scope_slot,
+                scope_string,
+                static_config_type,
+                static_ipv4_address,
+                static_ipv6_address,
+                static_port,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(88);
+
+                buf.extend(scope_slot.to_be_bytes());
+                // Scope string is a fixed 63 byte field, null padded
+                buf.extend(scope_string.bytes().chain(iter::repeat(0)).take(63));
+
+                #[cfg(feature = "alloc")]
+                buf.push(*static_config_type as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*static_config_type as u8).unwrap();
+
+                buf.extend(<[u8; 4]>::from(*static_ipv4_address));
+                buf.extend(<[u8; 16]>::from(*static_ipv6_address));
+                buf.extend(static_port.to_be_bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::SearchDomain) => {
-                Ok(Self::GetSearchDomain(decode_string_bytes(&bytes[..bytes.len().min(231)])?))
+            Self::GetSearchDomain(search_domain) => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(25 + search_domain.len());
+
+                buf.extend(search_domain.bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::TcpCommsStatus) => {
-                check_msg_len!(bytes, 87);
-                Ok(Self::GetTcpCommsStatus {
-                    scope_string: decode_string_bytes(&bytes[0..=62])?,
-                    broker_ipv4_address: <[u8; 4]>::try_from(&bytes[63..=66])?.into(),
-                    broker_ipv6_address: <[u8; 16]>::try_from(&bytes[67..=82])?.into(),
-                    broker_port: u16::from_be_bytes(bytes[83..=84].try_into()?),
-                    unhealthy_tcp_events: u16::from_be_bytes(bytes[85..=86].try_into()?),
-                })
+            Self::GetTcpCommsStatus {
+                scope_string,
+                broker_ipv4_address,
+                broker_ipv6_address,
+                broker_port,
+                unhealthy_tcp_events,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(87);
+
+                // Scope string is a fixed 63 byte field, null padded
+                buf.extend(scope_string.bytes().chain(iter::repeat(0)).take(63));
+
+                buf.extend(<[u8; 4]>::from(*broker_ipv4_address));
+                buf.extend(<[u8; 16]>::from(*broker_ipv6_address));
+                buf.extend(broker_port.to_be_bytes());
+                buf.extend(unhealthy_tcp_events.to_be_bytes());
             }
-            (CommandClass::GetCommandResponse, ParameterId::BrokerStatus) => {
-                check_msg_len!(bytes, 2);
-                Ok(Self::GetBrokerStatus {
-                    is_allowing_set_commands: bytes[0] == 1,
-                    broker_state: bytes[1].try_into()?,
-                })
+            Self::GetBrokerStatus {
+                is_allowing_set_commands,
+                broker_state,
+            } => {
+                #[cfg(feature = "alloc")]
+                buf.reserve(2);
+
+                #[cfg(feature = "alloc")]
+                buf.push(*is_allowing_set_commands as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*is_allowing_set_commands as u8).unwrap();
+
+                #[cfg(feature = "alloc")]
+                buf.push(*broker_state as u8);
+                #[cfg(not(feature = "alloc"))]
+                buf.push(*broker_state as u8).unwrap();
             }
-            (_, ParameterId::ManufacturerSpecific(_)) => Ok(Self::ManufacturerSpecific(
+            Self::ManufacturerSpecific(data) => {
                 #[cfg(feature = "alloc")]
-                bytes[..bytes.len().min(231)].to_vec(),
+                buf.reserve(data.len());
+
+                #[cfg(feature = "alloc")]
+                buf.extend(data);
                 #[cfg(not(feature = "alloc"))]
-                Vec::<u8, 231>::from_slice(&bytes[..bytes.len().min(231)]).unwrap(),
-            )),
-            (_, _) => Ok(Self::Unsupported(
+                buf.extend_from_slice(data).unwrap();
+            }
+            Self::Unsupported(data) => {
                 #[cfg(feature = "alloc")]
-                bytes[..bytes.len().min(231)].to_vec(),
+                buf.reserve(data.len());
+
+                #[cfg(feature = "alloc")]
+                buf.extend(data);
                 #[cfg(not(feature = "alloc"))]
-                Vec::<u8, 231>::from_slice(&bytes[..bytes.len().min(231)]).unwrap(),
+                buf.extend_from_slice(data).unwrap();
+            }
+        }
+
+        buf
+    }
+
+    pub fn decode(
+        command_class: CommandClass,
+        parameter_id: ParameterId,
+        bytes: &[u8],
+    ) -> Result<Self, RdmError> {
+        match (command_class, parameter_id) {
+            (CommandClass::DiscoveryCommandResponse, ParameterId::DiscMute) => {
+                check_msg_len!(bytes, 2);
+                let binding_uid = if bytes.len() >= 8 {
+                    Some(DeviceUID::from(<[u8; 6]>::try_from(&bytes[2..=7])?))
+                } else {
+                    None
+                };
+
+                Ok(Self::DiscMute {
+                    control_field: u16::from_be_bytes(bytes[..=1].try_into()?).into(),
+                    binding_uid,
+                })
+            }
+            (CommandClass::DiscoveryCommandResponse, ParameterId::DiscUnMute) => {
+                check_msg_len!(bytes, 2);
+                let binding_uid = if bytes.len() >= 8 {
+                    Some(DeviceUID::from(<[u8; 6]>::try_from(&bytes[2..=7])?))
+                } else {
+                    None
+                };
+
+                Ok(Self::DiscUnMute {
+                    control_field: u16::from_be_bytes(bytes[..=1].try_into()?).into(),
+                    binding_uid,
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::ProxiedDeviceCount) => {
+                check_msg_len!(bytes, 3);
+                Ok(Self::GetProxiedDeviceCount {
+                    device_count: u16::from_be_bytes(bytes[0..=1].try_into()?),
+                    list_change: bytes[2] == 1,
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::ProxiedDevices) => {
+                check_max_entries!(bytes, 6, 38);
+                check_whole_entries!(bytes, 6);
+                Ok(Self::GetProxiedDevices(
+                    #[cfg(feature = "alloc")]
+                    bytes
+                        .chunks(6)
+                        .map(|chunk| Ok(DeviceUID::from(<[u8; 6]>::try_from(&chunk[0..=5])?)))
+                        .collect::<Result<Vec<DeviceUID>, RdmError>>()?,
+                    #[cfg(not(feature = "alloc"))]
+                    bytes
+                        .chunks(6)
+                        .map(|chunk| Ok(DeviceUID::from(<[u8; 6]>::try_from(&chunk[0..=5])?)))
+                        .collect::<Result<Vec<DeviceUID, 38>, RdmError>>()?,
+                ))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::CommsStatus) => {
+                check_msg_len!(bytes, 6);
+                Ok(Self::GetCommsStatus {
+                    short_message: u16::from_be_bytes(bytes[0..=1].try_into()?),
+                    length_mismatch: u16::from_be_bytes(bytes[2..=3].try_into()?),
+                    checksum_fail: u16::from_be_bytes(bytes[4..=5].try_into()?),
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::StatusMessages) => {
+                check_max_entries!(bytes, 9, 25);
+                check_whole_entries!(bytes, 9);
+                Ok(Self::GetStatusMessages(
+                    #[cfg(feature = "alloc")]
+                    bytes
+                        .chunks(9)
+                        .map(|chunk| {
+                            Ok(StatusMessage::new(
+                                u16::from_be_bytes(chunk[0..=1].try_into()?).into(),
+                                chunk[2].try_into()?,
+                                u16::from_be_bytes(chunk[3..=4].try_into()?),
+                                u16::from_be_bytes(chunk[5..=6].try_into()?),
+                                u16::from_be_bytes(chunk[7..=8].try_into()?),
+                            ))
+                        })
+                        .collect::<Result<Vec<StatusMessage>, RdmError>>()?,
+                    #[cfg(not(feature = "alloc"))]
+                    bytes
+                        .chunks(9)
+                        .map(|chunk| {
+                            Ok(StatusMessage::new(
+                                u16::from_be_bytes(chunk[0..=1].try_into()?).into(),
+                                chunk[2].try_into()?,
+                                u16::from_be_bytes(chunk[3..=4].try_into()?),
+                                u16::from_be_bytes(chunk[5..=6].try_into()?),
+                                u16::from_be_bytes(chunk[7..=8].try_into()?),
+                            ))
+                        })
+                        .collect::<Result<Vec<StatusMessage, 25>, RdmError>>()?,
+                ))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::StatusIdDescription) => {
+                Ok(Self::GetStatusIdDescription(decode_string_bytes(&bytes[..bytes.len().min(32)])?))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::SubDeviceIdStatusReportThreshold) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetSubDeviceIdStatusReportThreshold(
+                    bytes[0].try_into()?,
+                ))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::SupportedParameters) => {
+                check_max_entries!(bytes, 2, 115);
+                let parameters = bytes
+                    .chunks(2)
+                    .map(|chunk| Ok(u16::from_be_bytes(chunk.try_into()?)))
+                    .filter_map(|parameter_id: Result<u16, RdmError>| parameter_id.ok());
+
+                Ok(Self::GetSupportedParameters(
+                    #[cfg(feature = "alloc")]
+                    parameters.collect::<Vec<u16>>(),
+                    #[cfg(not(feature = "alloc"))]
+                    parameters.collect::<Vec<u16, 115>>(),
+                ))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::ParameterDescription) => {
+                check_msg_len!(bytes, 20);
+                Ok(Self::GetParameterDescription(ParameterDescription {
+                    parameter_id: u16::from_be_bytes(bytes[0..=1].try_into()?),
+                    parameter_data_length: bytes[2],
+                    data_type: bytes[3].try_into()?,
+                    command_class: bytes[4].try_into()?,
+                    unit_type: bytes[6].try_into()?,
+                    prefix: bytes[7].try_into()?,
+                    raw_minimum_valid_value: bytes[8..=11].try_into()?,
+                    raw_maximum_valid_value: bytes[12..=15].try_into()?,
+                    raw_default_value: bytes[16..=19].try_into()?,
+                    description: decode_string_bytes(&bytes[20..bytes.len().min(20+32)])?,
+                }))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DeviceInfo) => {
+                check_msg_len!(bytes, 19);
+                Ok(Self::GetDeviceInfo {
+                    protocol_version: ProtocolVersion::new(bytes[0], bytes[1]),
+                    model_id: u16::from_be_bytes(bytes[2..=3].try_into()?),
+                    product_category: u16::from_be_bytes(bytes[4..=5].try_into()?).into(),
+                    software_version_id: u32::from_be_bytes(bytes[6..=9].try_into()?),
+                    footprint: u16::from_be_bytes(bytes[10..=11].try_into()?),
+                    current_personality: bytes[12],
+                    personality_count: bytes[13],
+                    start_address: u16::from_be_bytes(bytes[14..=15].try_into()?),
+                    sub_device_count: u16::from_be_bytes(bytes[16..=17].try_into()?),
+                    sensor_count: u8::from_be(bytes[18]),
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::ProductDetailIdList) => {
+                check_max_entries!(bytes, 2, 115);
+                Ok(Self::GetProductDetailIdList(
+                    #[cfg(feature = "alloc")]
+                    bytes
+                        .chunks(2)
+                        .map(|chunk| Ok(u16::from_be_bytes(chunk.try_into()?).into()))
+                        .collect::<Result<Vec<ProductDetail>, RdmError>>()?,
+                    #[cfg(not(feature = "alloc"))]
+                    bytes
+                        .chunks(2)
+                        .map(|chunk| Ok(u16::from_be_bytes(chunk.try_into()?).into()))
+                        .collect::<Result<Vec<ProductDetail, 115>, RdmError>>()?,
+                ))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DeviceModelDescription) => {
+                Ok(Self::GetDeviceModelDescription(decode_string_bytes(&bytes[..bytes.len().min(32)])?))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::ManufacturerLabel) => {
+                Ok(Self::GetManufacturerLabel(decode_string_bytes(&bytes[..bytes.len().min(32)])?))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DeviceLabel) => {
+                Ok(Self::GetDeviceLabel(decode_string_bytes(&bytes[..bytes.len().min(32)])?))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::FactoryDefaults) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetFactoryDefaults(bytes[0] == 1))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::LanguageCapabilities) => {
+                check_max_entries!(bytes, 2, 115);
+                Ok(Self::GetLanguageCapabilities(
+                    #[cfg(feature = "alloc")]
+                    bytes
+                        .chunks(2)
+                        .map(|chunk| Ok(core::str::from_utf8(chunk)?.to_string()))
+                        .collect::<Result<Vec<String>, RdmError>>()?,
+                    #[cfg(not(feature = "alloc"))]
+                    bytes
+                        .chunks(2)
+                        .map(|chunk| {
+                            Ok(String::from_utf8(Vec::<u8, 2>::from_slice(chunk).unwrap())?)
+                        })
+                        .collect::<Result<Vec<String<2>, 115>, RdmError>>()?,
+                ))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::Language) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::GetLanguage(
+                    #[cfg(feature = "alloc")]
+                    core::str::from_utf8(&bytes[0..=1])?.to_string(),
+                    #[cfg(not(feature = "alloc"))]
+                    String::from_utf8(Vec::<u8, 2>::from_slice(&bytes[0..=1]).unwrap())?,
+                ))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::SoftwareVersionLabel) => {
+                Ok(Self::GetSoftwareVersionLabel(decode_string_bytes(&bytes[..bytes.len().min(32)])?))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::BootSoftwareVersionId) => {
+                check_msg_len!(bytes, 4);
+                Ok(Self::GetBootSoftwareVersionId(
+                    u32::from_be_bytes(bytes[0..=3].try_into()?).into()
+                ))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::BootSoftwareVersionLabel) => {
+                Ok(Self::GetBootSoftwareVersionLabel(decode_string_bytes(&bytes[..bytes.len().min(32)])?))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DmxPersonality) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::GetDmxPersonality {
+                    current_personality: bytes[0],
+                    personality_count: bytes[1],
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DmxPersonalityDescription) => {
+                check_msg_len!(bytes, 3);
+                Ok(Self::GetDmxPersonalityDescription {
+                    id: bytes[0],
+                    dmx_slots_required: u16::from_be_bytes(bytes[1..=2].try_into()?),
+                    description: decode_string_bytes(&bytes[3..bytes.len().min(3+32)])?,
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DmxStartAddress) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::GetDmxStartAddress(
+                    u16::from_be_bytes(bytes[0..=1].try_into()?)
+                ))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::SlotInfo) => {
+                check_max_entries!(bytes, 5, 46);
+                check_whole_entries!(bytes, 5);
+                Ok(Self::GetSlotInfo(
+                    #[cfg(feature = "alloc")]
+                    bytes
+                        .chunks(5)
+                        .map(|chunk| {
+                            Ok(SlotInfo::new(
+                                u16::from_be_bytes(chunk[0..=1].try_into()?),
+                                chunk[2].into(),
+                                u16::from_be_bytes(chunk[3..=4].try_into()?),
+                            ))
+                        })
+                        .collect::<Result<Vec<SlotInfo>, RdmError>>()?,
+                    #[cfg(not(feature = "alloc"))]
+                    bytes
+                        .chunks(5)
+                        .map(|chunk| {
+                            Ok(SlotInfo::new(
+                                u16::from_be_bytes(chunk[0..=1].try_into()?),
+                                chunk[2].into(),
+                                u16::from_be_bytes(chunk[3..=4].try_into()?),
+                            ))
+                        })
+                        .collect::<Result<Vec<SlotInfo, 46>, RdmError>>()?,
+                ))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::SlotDescription) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::GetSlotDescription {
+                    slot_id: u16::from_be_bytes(bytes[0..=1].try_into()?),
+                    description: decode_string_bytes(&bytes[2..bytes.len().min(2+32)])?,
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DefaultSlotValue) => {
+                check_max_entries!(bytes, 3, 77);
+                check_whole_entries!(bytes, 3);
+                Ok(Self::GetDefaultSlotValue(
+                    #[cfg(feature = "alloc")]
+                    bytes
+                        .chunks(3)
+                        .map(|chunk| {
+                            Ok(DefaultSlotValue::new(
+                                u16::from_be_bytes(chunk[0..=1].try_into()?),
+                                chunk[2],
+                            ))
+                        })
+                        .collect::<Result<Vec<DefaultSlotValue>, RdmError>>()?,
+                    #[cfg(not(feature = "alloc"))]
+                    bytes
+                        .chunks(3)
+                        .map(|chunk| {
+                            Ok(DefaultSlotValue::new(
+                                u16::from_be_bytes(chunk[0..=1].try_into()?),
+                                chunk[2],
+                            ))
+                        })
+                        .collect::<Result<Vec<DefaultSlotValue, 77>, RdmError>>()?,
+                ))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::SensorDefinition) => {
+                check_msg_len!(bytes, 13);
+                Ok(Self::GetSensorDefinition(SensorDefinition {
+                    id: bytes[0],
+                    kind: bytes[1].try_into()?,
+                    unit: bytes[2].try_into()?,
+                    prefix: bytes[3].try_into()?,
+                    range_minimum_value: i16::from_be_bytes(bytes[4..=5].try_into()?),
+                    range_maximum_value: i16::from_be_bytes(bytes[6..=7].try_into()?),
+                    normal_minimum_value: i16::from_be_bytes(bytes[8..=9].try_into()?),
+                    normal_maximum_value: i16::from_be_bytes(bytes[10..=11].try_into()?),
+                    is_lowest_highest_detected_value_supported: bytes[12] >> 1 & 1 == 1,
+                    is_recorded_value_supported: bytes[12] & 1 == 1,
+                    description: decode_string_bytes(&bytes[13..bytes.len().min(13+32)])?,
+                }))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::SensorValue) => {
+                check_msg_len!(bytes, 9);
+                Ok(Self::GetSensorValue(SensorValue::new(
+                    bytes[0],
+                    i16::from_be_bytes(bytes[1..=2].try_into()?),
+                    i16::from_be_bytes(bytes[3..=4].try_into()?),
+                    i16::from_be_bytes(bytes[5..=6].try_into()?),
+                    i16::from_be_bytes(bytes[7..=8].try_into()?),
+                )))
+            }
+            (CommandClass::SetCommandResponse, ParameterId::SensorValue) => {
+                check_msg_len!(bytes, 9);
+                Ok(Self::SetSensorValue(SensorValue::new(
+                    bytes[0],
+                    i16::from_be_bytes(bytes[1..=2].try_into()?),
+                    i16::from_be_bytes(bytes[3..=4].try_into()?),
+                    i16::from_be_bytes(bytes[5..=6].try_into()?),
+                    i16::from_be_bytes(bytes[7..=8].try_into()?),
+                )))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DeviceHours) => {
+                check_msg_len!(bytes, 4);
+                Ok(Self::GetDeviceHours(
+                    u32::from_be_bytes(bytes[0..=3].try_into()?)
+                ))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::LampHours) => {
+                check_msg_len!(bytes, 4);
+                Ok(Self::GetLampHours(
+                    u32::from_be_bytes(bytes[0..=3].try_into()?),
+                ))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::LampStrikes) => {
+                check_msg_len!(bytes, 4);
+                Ok(Self::GetLampStrikes(
+                    u32::from_be_bytes(bytes[0..=3].try_into()?)
+                ))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::LampState) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetLampState(bytes[0].try_into()?))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::LampOnMode) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetLampOnMode(bytes[0].try_into()?))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DevicePowerCycles) => {
+                check_msg_len!(bytes, 4);
+                Ok(Self::GetDevicePowerCycles(
+                    u32::from_be_bytes(bytes[0..=3].try_into()?)
+                ))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DisplayInvert) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetDisplayInvert(bytes[0].try_into()?))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DisplayLevel) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetDisplayLevel(bytes[0]))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::PanInvert) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetPanInvert(bytes[0] == 1))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::TiltInvert) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetTiltInvert(bytes[0] == 1))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::PanTiltSwap) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetPanTiltSwap(bytes[0] == 1))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::RealTimeClock) => {
+                check_msg_len!(bytes, 7);
+                Ok(Self::GetRealTimeClock {
+                    year: u16::from_be_bytes(bytes[0..=1].try_into()?),
+                    month: bytes[2],
+                    day: bytes[3],
+                    hour: bytes[4],
+                    minute: bytes[5],
+                    second: bytes[6],
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::IdentifyDevice) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetIdentifyDevice(bytes[0] == 1))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::PowerState) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetPowerState(bytes[0].try_into()?))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::PerformSelfTest) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetPerformSelfTest(bytes[0] == 1))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::SelfTestDescription) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetSelfTestDescription {
+                    self_test_id: bytes[0].into(),
+                    description: decode_string_bytes(&bytes[1..bytes.len().min(1+32)])?,
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::PresetPlayback) => {
+                check_msg_len!(bytes, 3);
+                Ok(Self::GetPresetPlayback {
+                    mode: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                    level: bytes[2],
+                })
+            }
+            // E1.37-1
+            (CommandClass::GetCommandResponse, ParameterId::IdentifyMode) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetIdentifyMode(
+                    bytes[0].try_into()?
+                ))
+            },
+            (CommandClass::GetCommandResponse, ParameterId::DmxBlockAddress) => {
+                check_msg_len!(bytes, 4);
+                Ok(Self::GetDmxBlockAddress {
+                    total_sub_device_footprint: u16::from_be_bytes(bytes[0..=1].try_into()?),
+                    base_dmx_address: u16::from_be_bytes(bytes[2..=3].try_into()?),
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DmxFailMode) => {
+                check_msg_len!(bytes, 7);
+                Ok(Self::GetDmxFailMode {
+                    scene_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                    loss_of_signal_delay: u16::from_be_bytes(bytes[2..=3].try_into()?).into(),
+                    hold_time: u16::from_be_bytes(bytes[4..=5].try_into()?).into(),
+                    level: bytes[6],
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DmxStartupMode) => {
+                check_msg_len!(bytes, 7);
+                Ok(Self::GetDmxStartupMode {
+                    scene_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                    startup_delay: u16::from_be_bytes(bytes[2..=3].try_into()?).into(),
+                    hold_time: u16::from_be_bytes(bytes[4..=5].try_into()?).into(),
+                    level: bytes[6],
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::PowerOnSelfTest) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetPowerOnSelfTest(bytes[0] == 1))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::LockState) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::GetLockState {
+                    lock_state_id: bytes[0],
+                    lock_state_count: bytes[1],
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::LockStateDescription) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetLockStateDescription {
+                    lock_state_id: bytes[0],
+                    description: decode_string_bytes(&bytes[1..bytes.len().min(1+32)])?,
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::LockPin) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::GetLockPin(
+                    PinCode::try_from(u16::from_be_bytes(bytes[0..=1].try_into()?))?,
+                ))
+            }
+            // E1.37-1 specifies no parameter data in the SET_COMMAND_RESPONSE, any remaining hours
+            // echoed by a non-conformant device are kept as Unsupported
+            (CommandClass::GetCommandResponse, ParameterId::BurnIn) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetBurnIn(bytes[0]))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DimmerInfo) => {
+                check_msg_len!(bytes, 11);
+                Ok(Self::GetDimmerInfo {
+                    minimum_level_lower_limit: u16::from_be_bytes(bytes[0..=1].try_into()?),
+                    minimum_level_upper_limit: u16::from_be_bytes(bytes[2..=3].try_into()?),
+                    maximum_level_lower_limit: u16::from_be_bytes(bytes[4..=5].try_into()?),
+                    maximum_level_upper_limit: u16::from_be_bytes(bytes[6..=7].try_into()?),
+                    number_of_supported_curves: bytes[8],
+                    levels_resolution: bytes[9],
+                    minimum_level_split_levels_supported: bytes[10] == 1,
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::MinimumLevel) => {
+                check_msg_len!(bytes, 5);
+                Ok(Self::GetMinimumLevel {
+                    minimum_level_increasing: u16::from_be_bytes(bytes[0..=1].try_into()?),
+                    minimum_level_decreasing: u16::from_be_bytes(bytes[2..=3].try_into()?),
+                    on_below_minimum: bytes[4] == 1,
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::MaximumLevel) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::GetMaximumLevel(
+                    u16::from_be_bytes(bytes[0..=1].try_into()?)
+                ))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::Curve) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::GetCurve {
+                    curve_id: bytes[0],
+                    curve_count: bytes[1],
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::CurveDescription) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetCurveDescription {
+                    curve_id: bytes[0],
+                    description: decode_string_bytes(&bytes[1..bytes.len().min(1+32)])?,
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::OutputResponseTime) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::GetOutputResponseTime {
+                    response_time_id: bytes[0],
+                    response_time_count: bytes[1],
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::OutputResponseTimeDescription) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetOutputResponseTimeDescription {
+                    response_time_id: bytes[0],
+                    description: decode_string_bytes(&bytes[1..bytes.len().min(1+32)])?,
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::ModulationFrequency) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::GetModulationFrequency {
+                    modulation_frequency_id: bytes[0],
+                    modulation_frequency_count: bytes[1],
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::ModulationFrequencyDescription) => {
+                check_msg_len!(bytes, 5);
+                Ok(Self::GetModulationFrequencyDescription {
+                    modulation_frequency_id: bytes[0],
+                    frequency: u32::from_be_bytes(bytes[1..=4].try_into()?),
+                    description: decode_string_bytes(&bytes[5..bytes.len().min(5+32)])?,
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::PresetInfo) => {
+                check_msg_len!(bytes, 32);
+                Ok(Self::GetPresetInfo {
+                    level_field_supported: bytes[0] == 1,
+                    preset_sequence_supported: bytes[1] == 1,
+                    split_times_supported: bytes[2] == 1,
+                    dmx_fail_infinite_delay_time_supported: bytes[3] == 1,
+                    dmx_fail_infinite_hold_time_supported: bytes[4] == 1,
+                    startup_infinite_hold_time_supported: bytes[5] == 1,
+                    maximum_scene_number: u16::from_be_bytes(bytes[6..=7].try_into()?),
+                    minimum_preset_fade_time_supported: u16::from_be_bytes(
+                        bytes[8..=9].try_into()?,
+                    ),
+                    maximum_preset_fade_time_supported: u16::from_be_bytes(
+                        bytes[10..=11].try_into()?,
+                    ),
+                    minimum_preset_wait_time_supported: u16::from_be_bytes(
+                        bytes[12..=13].try_into()?,
+                    ),
+                    maximum_preset_wait_time_supported: u16::from_be_bytes(
+                        bytes[14..=15].try_into()?,
+                    ),
+                    minimum_dmx_fail_delay_time_supported: SupportedTimes::from(
+                        u16::from_be_bytes(bytes[16..=17].try_into()?),
+                    ),
+                    maximum_dmx_fail_delay_time_supported: SupportedTimes::from(
+                        u16::from_be_bytes(bytes[18..=19].try_into()?),
+                    ),
+                    minimum_dmx_fail_hold_time_supported: SupportedTimes::from(u16::from_be_bytes(
+                        bytes[20..=21].try_into()?,
+                    )),
+                    maximum_dmx_fail_hold_time_supported: SupportedTimes::from(u16::from_be_bytes(
+                        bytes[22..=23].try_into()?,
+                    )),
+                    minimum_startup_delay_time_supported: SupportedTimes::from(u16::from_be_bytes(
+                        bytes[24..=25].try_into()?,
+                    )),
+                    maximum_startup_delay_time_supported: SupportedTimes::from(u16::from_be_bytes(
+                        bytes[26..=27].try_into()?,
+                    )),
+                    minimum_startup_hold_time_supported: SupportedTimes::from(u16::from_be_bytes(
+                        bytes[28..=29].try_into()?,
+                    )),
+                    maximum_startup_hold_time_supported: SupportedTimes::from(u16::from_be_bytes(
+                        bytes[30..=31].try_into()?,
+                    )),
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::PresetStatus) => {
+                check_msg_len!(bytes, 9);
+                Ok(Self::GetPresetStatus {
+                    scene_id: u16::from_be_bytes(bytes[0..=1].try_into()?),
+                    up_fade_time: u16::from_be_bytes(bytes[2..=3].try_into()?),
+                    down_fade_time: u16::from_be_bytes(bytes[4..=5].try_into()?),
+                    wait_time: u16::from_be_bytes(bytes[6..=7].try_into()?),
+                    programmed: PresetProgrammed::try_from(bytes[8])?,
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::PresetMergeMode) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetPresetMergeMode(MergeMode::try_from(bytes[0])?))
+            }
+            // E1.37-2
+            (CommandClass::GetCommandResponse, ParameterId::ListInterfaces) => {
+                check_max_entries!(bytes, 6, 38);
+                check_whole_entries!(bytes, 6);
+                Ok(Self::GetListInterfaces(
+                    #[cfg(feature = "alloc")]
+                    bytes
+                        .chunks(6)
+                        .map(|chunk| {
+                            Ok(NetworkInterface {
+                                interface_id: u32::from_be_bytes(chunk[0..=3].try_into()?),
+                                hardware_type: u16::from_be_bytes(chunk[4..=5].try_into()?).into(),
+                            })
+                        })
+                        .collect::<Result<Vec<NetworkInterface>, RdmError>>()?,
+                    #[cfg(not(feature = "alloc"))]
+                    bytes
+                        .chunks(6)
+                        .map(|chunk| {
+                            Ok(NetworkInterface {
+                                interface_id: u32::from_be_bytes(chunk[0..=3].try_into()?),
+                                hardware_type: u16::from_be_bytes(chunk[4..=5].try_into()?).into(),
+                            })
+                        })
+                        .collect::<Result<Vec<NetworkInterface, 38>, RdmError>>()?,
+                ))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::InterfaceLabel) => {
+                // An interface without a label responds with only the interface id
+                check_msg_len!(bytes, 4);
+                Ok(Self::GetInterfaceLabel {
+                    interface_id: u32::from_be_bytes(bytes[0..=3].try_into()?),
+                    interface_label: decode_string_bytes(&bytes[4..bytes.len().min(4+32)])?,
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::InterfaceHardwareAddressType1) => {
+                check_msg_len!(bytes, 10);
+                Ok(Self::GetInterfaceHardwareAddressType1 {
+                    interface_id: u32::from_be_bytes(bytes[0..=3].try_into()?),
+                    hardware_address: <[u8; 6]>::try_from(&bytes[4..=9])?.into(),
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::IpV4DhcpMode) => {
+                check_msg_len!(bytes, 5);
+                Ok(Self::GetIpV4DhcpMode {
+                    interface_id: u32::from_be_bytes(bytes[0..=3].try_into()?),
+                    dhcp_mode: bytes[4] == 1,
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::IpV4ZeroConfMode) => {
+                check_msg_len!(bytes, 5);
+                Ok(Self::GetIpV4ZeroConfMode {
+                    interface_id: u32::from_be_bytes(bytes[0..=3].try_into()?),
+                    zero_conf_mode: bytes[4] == 1,
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::IpV4CurrentAddress) => {
+                check_msg_len!(bytes, 10);
+                Ok(Self::GetIpV4CurrentAddress {
+                    interface_id: u32::from_be_bytes(bytes[0..=3].try_into()?),
+                    address: <[u8; 4]>::try_from(&bytes[4..=7])?.into(),
+                    netmask: bytes[8],
+                    dhcp_status: DhcpMode::try_from(bytes[9])?,
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::IpV4StaticAddress) => {
+                check_msg_len!(bytes, 9);
+                Ok(Self::GetIpV4StaticAddress {
+                    interface_id: u32::from_be_bytes(bytes[0..=3].try_into()?),
+                    address: <[u8; 4]>::try_from(&bytes[4..=7])?.into(),
+                    netmask: bytes[8],
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::IpV4DefaultRoute) => {
+                check_msg_len!(bytes, 8);
+                Ok(Self::GetIpV4DefaultRoute {
+                    interface_id: u32::from_be_bytes(bytes[0..=3].try_into()?),
+                    address: <[u8; 4]>::try_from(&bytes[4..=7])?.into(),
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DnsIpV4NameServer) => {
+                check_msg_len!(bytes, 5);
+                Ok(Self::GetDnsIpV4NameServer {
+                    name_server_index: bytes[0],
+                    address: <[u8; 4]>::try_from(&bytes[1..=4])?.into(),
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DnsHostName) => Ok(
+                Self::GetDnsHostName(decode_string_bytes(&bytes[..bytes.len().min(63)])?),
+            ),
+            (CommandClass::GetCommandResponse, ParameterId::DnsDomainName) => Ok(
+                Self::GetDnsDomainName(decode_string_bytes(&bytes[..bytes.len().min(231)])?),
+            ),
+            // E1.37-7
+            (CommandClass::GetCommandResponse, ParameterId::EndpointList) => {
+                check_msg_len!(bytes, 4);
+                check_max_entries!(bytes[4..], 3, 75);
+                check_whole_entries!(bytes[4..], 3);
+                Ok(Self::GetEndpointList {
+                    list_change_number: u32::from_be_bytes(bytes[0..=3].try_into()?),
+                    #[cfg(feature = "alloc")]
+                    endpoint_list: bytes[4..]
+                        .chunks(3)
+                        .map(|chunk| {
+                            Ok((
+                                u16::from_be_bytes(chunk[0..=1].try_into()?).into(),
+                                chunk[2].try_into()?,
+                            ))
+                        })
+                        .collect::<Result<Vec<(EndpointId, EndpointType)>, RdmError>>()?,
+                    #[cfg(not(feature = "alloc"))]
+                    endpoint_list: bytes[4..]
+                        .chunks(3)
+                        .map(|chunk| {
+                            Ok((
+                                u16::from_be_bytes(chunk[0..=1].try_into()?).into(),
+                                chunk[2].try_into()?,
+                            ))
+                        })
+                        .collect::<Result<Vec<(EndpointId, EndpointType), 75>, RdmError>>()?,
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::EndpointListChange) => {
+                check_msg_len!(bytes, 4);
+                Ok(Self::GetEndpointListChange {
+                    list_change_number: u32::from_be_bytes(bytes[0..=3].try_into()?),
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::IdentifyEndpoint) => {
+                check_msg_len!(bytes, 3);
+                Ok(Self::GetIdentifyEndpoint {
+                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                    identify: bytes[2] == 1,
+                })
+            }
+            (CommandClass::SetCommandResponse, ParameterId::IdentifyEndpoint) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::SetIdentifyEndpoint {
+                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::EndpointToUniverse) => {
+                check_msg_len!(bytes, 4);
+                Ok(Self::GetEndpointToUniverse {
+                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                    universe: u16::from_be_bytes(bytes[2..=3].try_into()?),
+                })
+            }
+            (CommandClass::SetCommandResponse, ParameterId::EndpointToUniverse) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::SetEndpointToUniverse {
+                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::EndpointMode) => {
+                check_msg_len!(bytes, 3);
+                Ok(Self::GetEndpointMode {
+                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                    mode: bytes[2].try_into()?,
+                })
+            }
+            (CommandClass::SetCommandResponse, ParameterId::EndpointMode) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::SetEndpointMode {
+                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::EndpointLabel) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::GetEndpointLabel {
+                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                    label: decode_string_bytes(&bytes[2..bytes.len().min(1+32)])?,
+                })
+            }
+            (CommandClass::SetCommandResponse, ParameterId::EndpointLabel) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::SetEndpointLabel {
+                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::RdmTrafficEnable) => {
+                check_msg_len!(bytes, 3);
+                Ok(Self::GetRdmTrafficEnable {
+                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                    enable: bytes[2] == 1,
+                })
+            }
+            (CommandClass::SetCommandResponse, ParameterId::RdmTrafficEnable) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::SetRdmTrafficEnable {
+                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::DiscoveryState) => {
+                check_msg_len!(bytes, 5);
+                Ok(Self::GetDiscoveryState {
+                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                    device_count: u16::from_be_bytes(bytes[2..=3].try_into()?).into(),
+                    discovery_state: bytes[4].try_into()?,
+                })
+            }
+            (CommandClass::SetCommandResponse, ParameterId::DiscoveryState) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::SetDiscoveryState {
+                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::BackgroundDiscovery) => {
+                check_msg_len!(bytes, 3);
+                Ok(Self::GetBackgroundDiscovery {
+                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                    enabled: bytes[2] == 1,
+                })
+            }
+            (CommandClass::SetCommandResponse, ParameterId::BackgroundDiscovery) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::SetBackgroundDiscovery {
+                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::EndpointTiming) => {
+                check_msg_len!(bytes, 4);
+                Ok(Self::GetEndpointTiming {
+                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                    current_setting_id: bytes[2],
+                    setting_count: bytes[3],
+                })
+            }
+            (CommandClass::SetCommandResponse, ParameterId::EndpointTiming) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::SetEndpointTiming {
+                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::EndpointTimingDescription) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetEndpointTimingDescription {
+                    setting_id: bytes[0],
+                    description: decode_string_bytes(&bytes[1..bytes.len().min(1+32)])?,
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::EndpointResponders) => {
+                check_msg_len!(bytes, 6);
+                check_max_entries!(bytes[6..], 6, 37);
+                check_whole_entries!(bytes[6..], 6);
+                Ok(Self::GetEndpointResponders {
+                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                    list_change_number: u32::from_be_bytes(bytes[2..=5].try_into()?),
+                    #[cfg(feature = "alloc")]
+                    responders: bytes[6..]
+                        .chunks(6)
+                        .map(|chunk| {
+                            Ok(DeviceUID::new(
+                                u16::from_be_bytes(chunk[0..=1].try_into()?),
+                                u32::from_be_bytes(chunk[2..=5].try_into()?),
+                            ))
+                        })
+                        .collect::<Result<Vec<DeviceUID>, RdmError>>()?,
+                    #[cfg(not(feature = "alloc"))]
+                    responders: bytes[6..]
+                        .chunks(6)
+                        .map(|chunk| {
+                            Ok(DeviceUID::new(
+                                u16::from_be_bytes(chunk[0..=1].try_into()?),
+                                u32::from_be_bytes(chunk[2..=5].try_into()?),
+                            ))
+                        })
+                        .collect::<Result<Vec<DeviceUID, 37>, RdmError>>()?,
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::EndpointResponderListChange) => {
+                check_msg_len!(bytes, 6);
+                Ok(Self::GetEndpointResponderListChange {
+                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                    list_change_number: u32::from_be_bytes(bytes[2..=5].try_into()?),
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::BindingControlFields) => {
+                check_msg_len!(bytes, 16);
+                Ok(Self::GetBindingControlFields {
+                    endpoint_id: u16::from_be_bytes(bytes[0..=1].try_into()?).into(),
+                    uid: DeviceUID::new(
+                        u16::from_be_bytes(bytes[2..=3].try_into()?),
+                        u32::from_be_bytes(bytes[4..=7].try_into()?),
+                    ),
+                    control_field: u16::from_be_bytes(bytes[8..=9].try_into()?),
+                    binding_uid: DeviceUID::new(
+                        u16::from_be_bytes(bytes[10..=11].try_into()?),
+                        u32::from_be_bytes(bytes[12..=15].try_into()?),
+                    ),
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::BackgroundQueuedStatusPolicy) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::GetBackgroundQueuedStatusPolicy {
+                    current_policy_id: bytes[0],
+                    policy_count: bytes[1],
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::BackgroundQueuedStatusPolicyDescription) => {
+                check_msg_len!(bytes, 1);
+                Ok(Self::GetBackgroundQueuedStatusPolicyDescription {
+                    policy_id: bytes[0],
+                    description: decode_string_bytes(&bytes[1..])?,
+                })
+            }
+            // E1.33
+            (CommandClass::GetCommandResponse, ParameterId::ComponentScope) => {
+                check_msg_len!(bytes, 88);
+                Ok(Self::GetComponentScope {
+                    scope_slot: u16::from_be_bytes(bytes[0..=1].try_into()?),
+                    scope_string: decode_string_bytes(&bytes[2..=64])?,
+                    static_config_type: bytes[65].try_into()?,
+                    static_ipv4_address: <[u8; 4]>::try_from(&bytes[66..=69])?.into(),
+                    static_ipv6_address: <[u8; 16]>::try_from(&bytes[70..=85])?.into(),
+                    static_port: u16::from_be_bytes(bytes[86..=87].try_into()?),
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::SearchDomain) => {
+                Ok(Self::GetSearchDomain(decode_string_bytes(&bytes[..bytes.len().min(231)])?))
+            }
+            (CommandClass::GetCommandResponse, ParameterId::TcpCommsStatus) => {
+                check_msg_len!(bytes, 87);
+                Ok(Self::GetTcpCommsStatus {
+                    scope_string: decode_string_bytes(&bytes[0..=62])?,
+                    broker_ipv4_address: <[u8; 4]>::try_from(&bytes[63..=66])?.into(),
+                    broker_ipv6_address: <[u8; 16]>::try_from(&bytes[67..=82])?.into(),
+                    broker_port: u16::from_be_bytes(bytes[83..=84].try_into()?),
+                    unhealthy_tcp_events: u16::from_be_bytes(bytes[85..=86].try_into()?),
+                })
+            }
+            (CommandClass::GetCommandResponse, ParameterId::BrokerStatus) => {
+                check_msg_len!(bytes, 2);
+                Ok(Self::GetBrokerStatus {
+                    is_allowing_set_commands: bytes[0] == 1,
+                    broker_state: bytes[1].try_into()?,
+                })
+            }
+            (_, ParameterId::ManufacturerSpecific(_)) => Ok(Self::ManufacturerSpecific(
+                #[cfg(feature = "alloc")]
+                bytes[..bytes.len().min(231)].to_vec(),
+                #[cfg(not(feature = "alloc"))]
+                Vec::<u8, 231>::from_slice(&bytes[..bytes.len().min(231)]).unwrap(),
+            )),
+            (_, _) => Ok(Self::Unsupported(
+                #[cfg(feature = "alloc")]
+                bytes[..bytes.len().min(231)].to_vec(),
+                #[cfg(not(feature = "alloc"))]
+                Vec::<u8, 231>::from_slice(&bytes[..bytes.len().min(231)]).unwrap(),
+            )),
+        }
+    }
+}
+
+pub type ManufacturerPidDecoder<T> = fn(CommandClass, &[u8]) -> Result<T, RdmError>;
+
+#[cfg(not(feature = "alloc"))]
+pub const MAX_MANUFACTURER_PID_DECODERS: usize = 16;
+
+/// Decoders for manufacturer-specific parameter data, keyed by manufacturer id and parameter id
+#[derive(Clone, Debug)]
+pub struct ManufacturerPidRegistry<T> {
+    #[cfg(feature = "alloc")]
+    decoders: Vec<(u16, u16, ManufacturerPidDecoder<T>)>,
+    #[cfg(not(feature = "alloc"))]
+    decoders: Vec<(u16, u16, ManufacturerPidDecoder<T>), MAX_MANUFACTURER_PID_DECODERS>,
+}
+
+impl<T> ManufacturerPidRegistry<T> {
+    pub fn new() -> Self {
+        Self {
+            decoders: Vec::new(),
+        }
+    }
+
+    pub fn register(
+        &mut self,
+        manufacturer_id: u16,
+        parameter_id: u16,
+        decoder: ManufacturerPidDecoder<T>,
+    ) -> Result<(), RdmError> {
+        if let Some(entry) = self
+            .decoders
+            .iter_mut()
+            .find(|(id, pid, _)| *id == manufacturer_id && *pid == parameter_id)
+        {
+            entry.2 = decoder;
+
+            return Ok(());
+        }
+
+        #[cfg(feature = "alloc")]
+        self.decoders.push((manufacturer_id, parameter_id, decoder));
+        #[cfg(not(feature = "alloc"))]
+        self.decoders
+            .push((manufacturer_id, parameter_id, decoder))
+            .map_err(|_| RdmError::ManufacturerPidRegistryFull)?;
+
+        Ok(())
+    }
+
+    /// Returns `None` when no decoder has been registered for the manufacturer id and parameter id
+    pub fn decode(
+        &self,
+        manufacturer_id: u16,
+        command_class: CommandClass,
+        parameter_id: u16,
+        bytes: &[u8],
+    ) -> Option<Result<T, RdmError>> {
+        self.decoders
+            .iter()
+            .find(|(id, pid, _)| *id == manufacturer_id && *pid == parameter_id)
+            .map(|(_, _, decoder)| decoder(command_class, bytes))
+    }
+}
+
+impl<T> Default for ManufacturerPidRegistry<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Gathers the descriptions from GET SLOT_DESCRIPTION responses keyed by slot id, slot ids are
+/// not required to be sequential
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SlotDescriptionCollector {
+    descriptions: HashMap<u16, String>,
+}
+
+#[cfg(feature = "alloc")]
+impl SlotDescriptionCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the slot description, replacing any previous description for the same slot id.
+    /// Returns `false` if `data` is not a slot description.
+    pub fn ingest(&mut self, data: &ResponseParameterData) -> bool {
+        match data {
+            ResponseParameterData::GetSlotDescription {
+                slot_id,
+                description,
+            } => {
+                self.descriptions.insert(*slot_id, description.clone());
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn get(&self, slot_id: u16) -> Option<&str> {
+        self.descriptions.get(&slot_id).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.descriptions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.descriptions.is_empty()
+    }
+
+    pub fn into_map(self) -> HashMap<u16, String> {
+        self.descriptions
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RdmFrameResponse {
+    pub destination_uid: DeviceUID,
+    pub source_uid: DeviceUID,
+    pub transaction_number: u8,
+    pub response_type: ResponseType,
+    pub message_count: u8,
+    pub sub_device_id: SubDeviceId,
+    pub command_class: CommandClass,
+    pub parameter_id: ParameterId,
+    pub parameter_data: ResponseData,
+}
+
+/// The message length and checksum of the frame a response was decoded from, see
+/// `RdmFrameResponse::decode_with_wire_info`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct WireInfo {
+    /// Message length, excluding the checksum
+    pub message_length: u8,
+    pub checksum: u16,
+}
+
+impl RdmFrameResponse {
+    /// Whether the response is addressed to `uid`, directly or by a broadcast to all devices or
+    /// to all devices of its manufacturer
+    pub fn is_for(&self, uid: DeviceUID) -> bool {
+        self.destination_uid == uid
+            || self.destination_uid == DeviceUID::broadcast_to_all_devices()
+            || self.destination_uid
+                == DeviceUID::broadcast_to_devices_with_manufacturer_id(uid.manufacturer_id)
+    }
+
+    pub fn is_ack(&self) -> bool {
+        self.response_type == ResponseType::Ack
+    }
+
+    pub fn is_ack_overflow(&self) -> bool {
+        self.response_type == ResponseType::AckOverflow
+    }
+
+    pub fn is_ack_timer(&self) -> bool {
+        self.response_type == ResponseType::AckTimer
+    }
+
+    pub fn is_nack(&self) -> bool {
+        self.response_type == ResponseType::NackReason
+    }
+
+    /// An ACK_OVERFLOW response carries partial data, the controller must repeat the request
+    /// until a final ACK is received, see `RdmRequest::overflow_follow_up`
+    pub fn needs_resend(&self) -> bool {
+        self.is_ack_overflow()
+    }
+
+    pub fn nack_reason(&self) -> Option<ResponseNackReasonCode> {
+        match self.parameter_data {
+            ResponseData::NackReason(reason) => Some(reason),
+            _ => None,
+        }
+    }
+
+    /// A non-zero message count means the responder has queued messages waiting to be
+    /// collected with GET QUEUED_MESSAGE
+    pub fn has_queued_messages(&self) -> bool {
+        self.message_count > 0
+    }
+
+    /// Builds the GET QUEUED_MESSAGE request that collects the next queued message from the
+    /// responder, or `None` if it has no queued messages. Queued messages are always requested
+    /// from the root device.
+    pub fn queued_message_request(
+        &self,
+        transaction_number: u8,
+        status_type: StatusType,
+    ) -> Option<RdmRequest> {
+        if !self.has_queued_messages() {
+            return None;
+        }
+
+        Some(
+            RdmRequest::template(self.source_uid, self.destination_uid)
+                .transaction_number(transaction_number)
+                .parameter(RequestParameter::GetQueuedMessage { status_type }),
+        )
+    }
+
+    /// A responder with no queued messages answers GET QUEUED_MESSAGE with an empty
+    /// STATUS_MESSAGES response, as opposed to a list of status messages
+    pub fn is_queue_empty(&self) -> bool {
+        self.is_ack()
+            && self.parameter_id == ParameterId::StatusMessages
+            && match &self.parameter_data {
+                ResponseData::ParameterData(None) => true,
+                ResponseData::ParameterData(Some(ResponseParameterData::GetStatusMessages(
+                    messages,
+                ))) => messages.is_empty(),
+                _ => false,
+            }
+    }
+
+    /// Estimated response time in 10ths of a second (100ms)
+    pub fn ack_timer_estimate(&self) -> Option<u16> {
+        match self.parameter_data {
+            ResponseData::EstimateResponseTime(time) => Some(time),
+            _ => None,
+        }
+    }
+
+    /// Decodes manufacturer-specific parameter data with the decoder registered for the
+    /// source manufacturer id, returns `None` for any other response or if no decoder is registered
+    pub fn decode_with_registry<T>(
+        &self,
+        registry: &ManufacturerPidRegistry<T>,
+    ) -> Option<Result<T, RdmError>> {
+        match (self.parameter_id, &self.parameter_data) {
+            (
+                ParameterId::ManufacturerSpecific(parameter_id),
+                ResponseData::ParameterData(Some(ResponseParameterData::ManufacturerSpecific(
+                    bytes,
+                ))),
+            ) => registry.decode(
+                self.source_uid.manufacturer_id,
+                self.command_class,
+                parameter_id,
+                bytes,
+            ),
+            _ => None,
+        }
+    }
+
+    pub fn encode(&self) -> EncodedFrame {
+        let parameter_data = self.parameter_data.encode();
+
+        let message_length = 24 + parameter_data.len();
+
+        #[cfg(feature = "alloc")]
+        let mut buf = Vec::with_capacity(message_length + 2);
+        #[cfg(not(feature = "alloc"))]
+        let mut buf = Vec::new();
+
+        #[cfg(feature = "alloc")]
+        buf.push(RDM_START_CODE_BYTE);
+        #[cfg(not(feature = "alloc"))]
+        buf.push(RDM_START_CODE_BYTE).unwrap();
+
+        #[cfg(feature = "alloc")]
+        buf.push(RDM_SUB_START_CODE_BYTE);
+        #[cfg(not(feature = "alloc"))]
+        buf.push(RDM_SUB_START_CODE_BYTE).unwrap();
+
+        #[cfg(feature = "alloc")]
+        buf.push(message_length as u8);
+        #[cfg(not(feature = "alloc"))]
+        buf.push(message_length as u8).unwrap();
+
+        buf.extend(<[u8; 6]>::from(self.destination_uid));
+        buf.extend(<[u8; 6]>::from(self.source_uid));
+
+        #[cfg(feature = "alloc")]
+        buf.push(self.transaction_number);
+        #[cfg(not(feature = "alloc"))]
+        buf.push(self.transaction_number).unwrap();
+
+        #[cfg(feature = "alloc")]
+        buf.push(self.response_type as u8);
+        #[cfg(not(feature = "alloc"))]
+        buf.push(self.response_type as u8).unwrap();
+
+        // Message Count shall be set to 0x00 in all controller generated requests
+        #[cfg(feature = "alloc")]
+        buf.push(self.message_count);
+        #[cfg(not(feature = "alloc"))]
+        buf.push(self.message_count).unwrap();
+
+        buf.extend(u16::from(self.sub_device_id).to_be_bytes());
+
+        #[cfg(feature = "alloc")]
+        buf.push(self.command_class as u8);
+        #[cfg(not(feature = "alloc"))]
+        buf.push(self.command_class as u8).unwrap();
+
+        buf.extend(u16::from(self.parameter_id).to_be_bytes());
+
+        #[cfg(feature = "alloc")]
+        buf.push(parameter_data.len() as u8);
+        #[cfg(not(feature = "alloc"))]
+        buf.push(parameter_data.len() as u8).unwrap();
+
+        buf.extend(parameter_data);
+        buf.extend(bsd_16_crc(&buf[..]).to_be_bytes());
+
+        buf
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, RdmError> {
+        let message_length = bytes[2];
+
+        if message_length < 24 {
+            return Err(RdmError::InvalidMessageLength(message_length));
+        }
+
+        if bytes.len() < message_length as usize + 2 {
+            return Err(RdmError::InvalidMessageLength(message_length));
+        }
+
+        let packet_checksum = u16::from_be_bytes(
+            bytes[message_length as usize..=message_length as usize + 1].try_into()?,
+        );
+
+        let decoded_checksum = bsd_16_crc(&bytes[..message_length as usize]);
+
+        if decoded_checksum != packet_checksum {
+            return Err(RdmError::InvalidChecksum(decoded_checksum, packet_checksum));
+        }
+
+        let destination_uid = DeviceUID::from(<[u8; 6]>::try_from(&bytes[3..=8])?);
+
+        let source_uid = DeviceUID::from(<[u8; 6]>::try_from(&bytes[9..=14])?);
+
+        let transaction_number = bytes[15];
+
+        let response_type = ResponseType::try_from(bytes[16])?;
+
+        let message_count = bytes[17];
+
+        let sub_device_id = u16::from_be_bytes(bytes[18..=19].try_into()?).into();
+
+        let command_class = CommandClass::try_from(bytes[20])?;
+
+        let parameter_id = u16::from_be_bytes(bytes[21..=22].try_into()?).into();
+
+        let parameter_data_length = bytes[23];
+
+        if parameter_data_length > 231 {
+            return Err(RdmError::InvalidParameterDataLength(parameter_data_length));
+        }
+
+        // The parameter data must fit within the declared message length, some devices report a
+        // PDL larger than the data sent before the checksum
+        if 24 + parameter_data_length as usize > message_length as usize {
+            return Err(RdmError::InvalidParameterDataLength(parameter_data_length));
+        }
+
+        let parameter_data = ResponseData::decode(
+            response_type,
+            command_class,
+            parameter_data_length,
+            parameter_id,
+            &bytes[24..24 + parameter_data_length as usize],
+        )?;
+
+        Ok(Self {
+            destination_uid,
+            source_uid,
+            transaction_number,
+            response_type,
+            message_count,
+            sub_device_id,
+            command_class,
+            parameter_id,
+            parameter_data,
+        })
+    }
+
+    /// Decodes as `decode`, also returning the message length and checksum of the frame, for
+    /// logging or verifying frames byte-for-byte
+    pub fn decode_with_wire_info(bytes: &[u8]) -> Result<(Self, WireInfo), RdmError> {
+        let response = Self::decode(bytes)?;
+
+        let message_length = bytes[2];
+
+        let checksum = u16::from_be_bytes(
+            bytes[message_length as usize..=message_length as usize + 1].try_into()?,
+        );
+
+        Ok((
+            response,
+            WireInfo {
+                message_length,
+                checksum,
+            },
+        ))
+    }
+
+    /// Decodes as `decode`, but rejects an ACK to a GET with no parameter data where the
+    /// parameter requires it, instead of returning `ResponseData::ParameterData(None)`.
+    ///
+    /// Responses other than NACKs are also rejected when the command class is not defined for
+    /// the parameter id, see `ParameterId::supported_command_classes`, and discovery responses
+    /// are rejected for any parameter other than DISC_MUTE and DISC_UN_MUTE.
+    pub fn decode_strict(bytes: &[u8]) -> Result<Self, RdmError> {
+        let response = Self::decode(bytes)?;
+
+        if response.command_class == CommandClass::DiscoveryCommandResponse
+            && !matches!(
+                response.parameter_id,
+                ParameterId::DiscMute | ParameterId::DiscUnMute
+            )
+        {
+            return Err(RdmError::UnexpectedDiscoveryResponse(
+                response.parameter_id.into(),
+            ));
+        }
+
+        let request_command_class = match response.command_class {
+            CommandClass::DiscoveryCommandResponse => CommandClass::DiscoveryCommand,
+            CommandClass::GetCommandResponse => CommandClass::GetCommand,
+            CommandClass::SetCommandResponse => CommandClass::SetCommand,
+            command_class => command_class,
+        };
+
+        if !response.is_nack()
+            && response
+                .parameter_id
+                .supported_command_classes()
+                .is_some_and(|command_classes| !command_classes.contains(&request_command_class))
+        {
+            return Err(RdmError::InvalidCommandClassForParameter {
+                command_class: response.command_class as u8,
+                parameter_id: response.parameter_id.into(),
+            });
+        }
+
+        if response.is_ack()
+            && response.command_class == CommandClass::GetCommandResponse
+            && response.parameter_data == ResponseData::ParameterData(None)
+            && response.parameter_id.requires_get_response_data()
+        {
+            return Err(RdmError::MissingParameterData {
+                parameter_id: response.parameter_id.into(),
+            });
+        }
+
+        Ok(response)
+    }
+}
+
+impl TryFrom<&[u8]> for RdmFrameResponse {
+    type Error = RdmError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        RdmFrameResponse::decode(bytes)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DiscoveryUniqueBranchFrameResponse(pub DeviceUID);
+
+impl DiscoveryUniqueBranchFrameResponse {
+    pub fn encode(&self) -> EncodedFrame {
+        #[cfg(feature = "alloc")]
+        let mut buf = Vec::with_capacity(24);
+        #[cfg(not(feature = "alloc"))]
+        let mut buf = Vec::new();
+
+        buf.extend(iter::repeat(DISCOVERY_UNIQUE_BRANCH_PREAMBLE_BYTE).take(7));
+
+        #[cfg(feature = "alloc")]
+        buf.push(DISCOVERY_UNIQUE_BRANCH_PREAMBLE_SEPARATOR_BYTE);
+        #[cfg(not(feature = "alloc"))]
+        buf.push(DISCOVERY_UNIQUE_BRANCH_PREAMBLE_SEPARATOR_BYTE)
+            .unwrap();
+
+        buf.extend(encode_euid(self.0));
+
+        buf
+    }
+
+    /// The number of corrupted encoded bytes at which `decode` reports a checksum failure as a
+    /// collision, a single responder always sends the masked bits high
+    pub const DEFAULT_COLLISION_THRESHOLD: usize = 1;
+
+    /// Decodes a response with `DEFAULT_COLLISION_THRESHOLD`, see
+    /// `decode_with_collision_threshold`
+    pub fn decode(bytes: &[u8]) -> Result<Self, RdmError> {
+        Self::decode_with_collision_threshold(bytes, Self::DEFAULT_COLLISION_THRESHOLD)
+    }
+
+    /// Decodes a response, reporting a checksum failure as `RdmError::DiscoveryCollision` when at
+    /// least `threshold` of the 16 encoded bytes are missing the bits forced high by the 0xaa and
+    /// 0x55 masks, and as `RdmError::InvalidChecksum` otherwise.
+    ///
+    /// A collision means more than one responder is in the branch, so it should be subdivided.
+    pub fn decode_with_collision_threshold(
+        bytes: &[u8],
+        threshold: usize,
+    ) -> Result<Self, RdmError> {
+        let Some(frame_start_index) = bytes.iter().position(|&x| x == 0xaa) else {
+            return Err(RdmError::InvalidDiscoveryUniqueBranchPreamble);
+        };
+
+        if frame_start_index + 16 >= bytes.len() {
+            return Err(RdmError::InvalidFrameLength(bytes.len() as u8));
+        }
+
+        let euid: &[u8; 16] =
+            bytes[(frame_start_index + 1)..=(frame_start_index + 16)].try_into()?;
+
+        match decode_euid(euid) {
+            Ok(uid) => Ok(Self(uid)),
+            Err(RdmError::InvalidChecksum(..)) if corrupted_euid_byte_count(euid) >= threshold => {
+                Err(RdmError::DiscoveryCollision)
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Decodes every response in a capture of back-to-back DISC_UNIQUE_BRANCH responses, a
+    /// truncated final response is reported as `RdmError::InvalidFrameLength`
+    #[cfg(feature = "alloc")]
+    pub fn decode_all(bytes: &[u8]) -> Vec<Result<Self, RdmError>> {
+        let mut responses = Vec::new();
+
+        let mut remaining = bytes;
+
+        while let Some(separator_index) = remaining
+            .iter()
+            .position(|&byte| byte == DISCOVERY_UNIQUE_BRANCH_PREAMBLE_SEPARATOR_BYTE)
+        {
+            let frame = &remaining[separator_index..];
+
+            if frame.len() < 17 {
+                responses.push(Err(RdmError::InvalidFrameLength(frame.len() as u8)));
+
+                break;
+            }
+
+            responses.push(Self::decode(&frame[..17]));
+
+            remaining = &frame[17..];
+        }
+
+        responses
+    }
+}
+
+// Each EUID byte is sent OR'd with 0xaa then 0x55, overlapping responders corrupt those bits
+fn corrupted_euid_byte_count(euid: &[u8; 16]) -> usize {
+    euid.chunks_exact(2)
+        .map(|pair| (pair[0] & 0xaa != 0xaa) as usize + (pair[1] & 0x55 != 0x55) as usize)
+        .sum()
+}
+
+impl TryFrom<&[u8]> for DiscoveryUniqueBranchFrameResponse {
+    type Error = RdmError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        DiscoveryUniqueBranchFrameResponse::decode(bytes)
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum RdmResponse {
+    RdmFrame(RdmFrameResponse),
+    DiscoveryUniqueBranchFrame(DiscoveryUniqueBranchFrameResponse),
+}
+
+impl RdmResponse {
+    /// Writes a one-line, human-readable summary of the frame, e.g.
+    /// `GET_COMMAND_RESPONSE IDENTIFY_DEVICE 0605:04030201 -> 0102:03040506 ACK true`
+    ///
+    /// The value is only included for strings and single-value parameter data, lists and
+    /// structures are left to `ResponseParameterData::fields`.
+    pub fn summary_into<W: core::fmt::Write>(&self, buf: &mut W) -> core::fmt::Result {
+        match self {
+            RdmResponse::RdmFrame(frame) => {
+                write!(
+                    buf,
+                    "{} {} {} -> {} {}",
+                    frame.command_class,
+                    frame.parameter_id,
+                    frame.source_uid,
+                    frame.destination_uid,
+                    frame.response_type
+                )?;
+
+                match &frame.parameter_data {
+                    ResponseData::ParameterData(Some(data)) => {
+                        if let Some(value) = data.as_str() {
+                            return write!(buf, " {}", value);
+                        }
+
+                        let mut fields = data.fields();
+
+                        match (fields.next(), fields.next()) {
+                            (Some((_, value)), None) if !matches!(value, FieldValue::Other(_)) => {
+                                write!(buf, " {}", value)
+                            }
+                            _ => Ok(()),
+                        }
+                    }
+                    ResponseData::ParameterData(None) => Ok(()),
+                    ResponseData::EstimateResponseTime(time) => write!(buf, " {}", time),
+                    ResponseData::NackReason(reason) => write!(buf, " {}", reason),
+                }
+            }
+            RdmResponse::DiscoveryUniqueBranchFrame(frame) => {
+                write!(buf, "DISC_UNIQUE_BRANCH {}", frame.0)
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn summary(&self) -> String {
+        let mut summary = String::new();
+
+        // Writing to a String cannot fail
+        self.summary_into(&mut summary).unwrap();
+
+        summary
+    }
+
+    pub fn encode(&self) -> EncodedFrame {
+        match self {
+            RdmResponse::RdmFrame(frame) => frame.encode(),
+            RdmResponse::DiscoveryUniqueBranchFrame(frame) => frame.encode(),
+        }
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, RdmError> {
+        if has_rdm_start(bytes) {
+            if bytes.len() < 25 {
+                return Err(RdmError::InvalidFrameLength(bytes.len() as u8));
+            }
+
+            let response = RdmFrameResponse::decode(bytes)?;
+
+            #[cfg(feature = "tracing")]
+            tracing::trace!(
+                parameter_id = ?response.parameter_id,
+                command_class = ?response.command_class,
+                length = bytes.len(),
+                "decoded rdm response"
+            );
+
+            return Ok(RdmResponse::RdmFrame(response));
+        }
+
+        if has_discovery_start(bytes) {
+            if bytes.len() < 17 {
+                return Err(RdmError::InvalidFrameLength(bytes.len() as u8));
+            }
+
+            let response = DiscoveryUniqueBranchFrameResponse::decode(bytes)?;
+
+            #[cfg(feature = "tracing")]
+            tracing::trace!(
+                length = bytes.len(),
+                "decoded discovery unique branch response"
+            );
+
+            return Ok(RdmResponse::DiscoveryUniqueBranchFrame(response));
+        }
+
+        match bytes.first() {
+            Some(&start_code) => Err(RdmError::InvalidStartCode(start_code)),
+            None => Err(RdmError::InvalidFrameLength(0)),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for RdmResponse {
+    type Error = RdmError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        RdmResponse::decode(bytes)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<Vec<u8>> for RdmResponse {
+    type Error = RdmError;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        RdmResponse::decode(&bytes)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<RdmResponse> for Vec<u8> {
+    fn from(response: RdmResponse) -> Self {
+        response.encode()
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+impl From<RdmResponse> for Vec<u8, 257> {
+    fn from(response: RdmResponse) -> Self {
+        response.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_decode_valid_rdm_ack_response() {
+        let decoded = RdmResponse::decode(&[
+            0xcc, // Start Code
+            0x01, // Sub Start Code
+            25,   // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x00, // Response Type = Ack
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
+            0x21, // Command Class = GetCommandResponse
+            0x10, 0x00, // Parameter ID = Identify Device
+            0x01, // PDL
+            0x01, // Identifying = true
+            0x01, 0x43, // Checksum
+        ]);
+
+        let expected = Ok(RdmResponse::RdmFrame(RdmFrameResponse {
+            destination_uid: DeviceUID::new(0x0102, 0x03040506),
+            source_uid: DeviceUID::new(0x0605, 0x04030201),
+            transaction_number: 0x00,
+            response_type: ResponseType::Ack,
+            message_count: 0x00,
+            sub_device_id: SubDeviceId::RootDevice,
+            command_class: CommandClass::GetCommandResponse,
+            parameter_id: ParameterId::IdentifyDevice,
+            parameter_data: ResponseData::ParameterData(Some(
+                ResponseParameterData::GetIdentifyDevice(true),
             )),
-        }
+        }));
+
+        assert_eq!(decoded, expected);
     }
-}
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct RdmFrameResponse {
-    pub destination_uid: DeviceUID,
-    pub source_uid: DeviceUID,
-    pub transaction_number: u8,
-    pub response_type: ResponseType,
-    pub message_count: u8,
-    pub sub_device_id: SubDeviceId,
-    pub command_class: CommandClass,
-    pub parameter_id: ParameterId,
-    pub parameter_data: ResponseData,
-}
+    #[test]
+    fn should_decode_response_with_wire_info() {
+        let mut bytes = [
+            0xcc, // Start Code
+            0x01, // Sub Start Code
+            25,   // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x00, // Response Type = Ack
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
+            0x21, // Command Class = GetCommandResponse
+            0x10, 0x00, // Parameter ID = Identify Device
+            0x01, // PDL
+            0x01, // Identifying = true
+            0x01, 0x43, // Checksum
+        ];
 
-impl RdmFrameResponse {
-    pub fn encode(&self) -> EncodedFrame {
-        let parameter_data = self.parameter_data.encode();
+        let (response, wire_info) = RdmFrameResponse::decode_with_wire_info(&bytes).unwrap();
 
-        let message_length = 24 + parameter_data.len();
+        assert_eq!(Ok(response.clone()), RdmFrameResponse::decode(&bytes));
+        assert_eq!(
+            wire_info,
+            WireInfo {
+                message_length: 25,
+                checksum: 0x0143,
+            }
+        );
 
-        #[cfg(feature = "alloc")]
-        let mut buf = Vec::with_capacity(message_length + 2);
-        #[cfg(not(feature = "alloc"))]
-        let mut buf = Vec::new();
+        bytes[24] = 0x00; // Identifying = false
+        bytes[26] = 0x42; // Checksum
 
-        #[cfg(feature = "alloc")]
-        buf.push(RDM_START_CODE_BYTE);
-        #[cfg(not(feature = "alloc"))]
-        buf.push(RDM_START_CODE_BYTE).unwrap();
+        let (other, wire_info) = RdmFrameResponse::decode_with_wire_info(&bytes).unwrap();
 
-        #[cfg(feature = "alloc")]
-        buf.push(RDM_SUB_START_CODE_BYTE);
-        #[cfg(not(feature = "alloc"))]
-        buf.push(RDM_SUB_START_CODE_BYTE).unwrap();
+        assert_eq!(wire_info.checksum, 0x0142);
+        assert_ne!(response, other);
+    }
 
-        #[cfg(feature = "alloc")]
-        buf.push(message_length as u8);
-        #[cfg(not(feature = "alloc"))]
-        buf.push(message_length as u8).unwrap();
+    #[test]
+    fn should_encode_valid_rdm_ack_response() {
+        let encoded = RdmResponse::RdmFrame(RdmFrameResponse {
+            destination_uid: DeviceUID::new(0x0102, 0x03040506),
+            source_uid: DeviceUID::new(0x0605, 0x04030201),
+            transaction_number: 0x00,
+            response_type: ResponseType::Ack,
+            message_count: 0x00,
+            sub_device_id: SubDeviceId::RootDevice,
+            command_class: CommandClass::GetCommandResponse,
+            parameter_id: ParameterId::IdentifyDevice,
+            parameter_data: ResponseData::ParameterData(Some(
+                ResponseParameterData::GetIdentifyDevice(true),
+            )),
+        })
+        .encode();
 
-        buf.extend(self.destination_uid.manufacturer_id.to_be_bytes());
-        buf.extend(self.destination_uid.device_id.to_be_bytes());
-        buf.extend(self.source_uid.manufacturer_id.to_be_bytes());
-        buf.extend(self.source_uid.device_id.to_be_bytes());
+        let expected = &[
+            0xcc, // Start Code
+            0x01, // Sub Start Code
+            25,   // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x00, // Response Type = Ack
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
+            0x21, // Command Class = GetCommandResponse
+            0x10, 0x00, // Parameter ID = Identify Device
+            0x01, // PDL
+            0x01, // Identifying = true
+            0x01, 0x43, // Checksum
+        ];
 
-        #[cfg(feature = "alloc")]
-        buf.push(self.transaction_number);
-        #[cfg(not(feature = "alloc"))]
-        buf.push(self.transaction_number).unwrap();
+        assert_eq!(encoded, expected);
+    }
 
-        #[cfg(feature = "alloc")]
-        buf.push(self.response_type as u8);
-        #[cfg(not(feature = "alloc"))]
-        buf.push(self.response_type as u8).unwrap();
+    #[test]
+    fn should_decode_valid_rdm_ack_manufacturer_specific_response() {
+        let decoded = RdmResponse::decode(&[
+            0xcc, // Start Code
+            0x01, // Sub Start Code
+            28,   // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x00, // Response Type = Ack
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
+            0x31, // Command Class = SetCommandResponse
+            0x80, 0x80, // Parameter ID = Identify Device
+            0x04, // PDL
+            0x04, 0x03, 0x02, 0x01, // Arbitrary manufacturer specific data
+            0x02, 0x52, // Checksum
+        ]);
 
-        // Message Count shall be set to 0x00 in all controller generated requests
-        #[cfg(feature = "alloc")]
-        buf.push(self.message_count);
-        #[cfg(not(feature = "alloc"))]
-        buf.push(self.message_count).unwrap();
+        let expected = Ok(RdmResponse::RdmFrame(RdmFrameResponse {
+            destination_uid: DeviceUID::new(0x0102, 0x03040506),
+            source_uid: DeviceUID::new(0x0605, 0x04030201),
+            transaction_number: 0x00,
+            response_type: ResponseType::Ack,
+            message_count: 0x00,
+            sub_device_id: SubDeviceId::RootDevice,
+            command_class: CommandClass::SetCommandResponse,
+            parameter_id: ParameterId::ManufacturerSpecific(0x8080),
+            #[cfg(feature = "alloc")]
+            parameter_data: ResponseData::ParameterData(Some(
+                ResponseParameterData::ManufacturerSpecific(vec![0x04, 0x03, 0x02, 0x01]),
+            )),
+            #[cfg(not(feature = "alloc"))]
+            parameter_data: ResponseData::ParameterData(Some(
+                ResponseParameterData::ManufacturerSpecific(
+                    Vec::<u8, 231>::from_slice(&[0x04, 0x03, 0x02, 0x01]).unwrap(),
+                ),
+            )),
+        }));
 
-        buf.extend(u16::from(self.sub_device_id).to_be_bytes());
+        assert_eq!(decoded, expected);
+    }
+
+    #[test]
+    fn should_decode_manufacturer_specific_response_with_registry() {
+        let mut registry = ManufacturerPidRegistry::new();
+
+        registry
+            .register(0x0605, 0x8080, |_, bytes| {
+                Ok(u32::from_be_bytes(bytes[0..=3].try_into()?))
+            })
+            .unwrap();
+
+        let RdmResponse::RdmFrame(response) = RdmResponse::decode(&[
+            0xcc, // Start Code
+            0x01, // Sub Start Code
+            28,   // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x00, // Response Type = Ack
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
+            0x31, // Command Class = SetCommandResponse
+            0x80, 0x80, // Parameter ID = Identify Device
+            0x04, // PDL
+            0x04, 0x03, 0x02, 0x01, // Arbitrary manufacturer specific data
+            0x02, 0x52, // Checksum
+        ])
+        .unwrap() else {
+            panic!("Expected RdmFrame response");
+        };
+
+        assert_eq!(
+            response.decode_with_registry(&registry),
+            Some(Ok(0x04030201))
+        );
+        assert_eq!(
+            response.decode_with_registry(&ManufacturerPidRegistry::<u32>::new()),
+            None
+        );
+    }
+
+    #[test]
+    fn should_encode_valid_rdm_ack_manufacturer_specific_response() {
+        let encoded = RdmResponse::RdmFrame(RdmFrameResponse {
+            destination_uid: DeviceUID::new(0x0102, 0x03040506),
+            source_uid: DeviceUID::new(0x0605, 0x04030201),
+            transaction_number: 0x00,
+            response_type: ResponseType::Ack,
+            message_count: 0x00,
+            sub_device_id: SubDeviceId::RootDevice,
+            command_class: CommandClass::SetCommandResponse,
+            parameter_id: ParameterId::ManufacturerSpecific(0x8080),
+            #[cfg(feature = "alloc")]
+            parameter_data: ResponseData::ParameterData(Some(
+                ResponseParameterData::ManufacturerSpecific(vec![0x04, 0x03, 0x02, 0x01]),
+            )),
+            #[cfg(not(feature = "alloc"))]
+            parameter_data: ResponseData::ParameterData(Some(
+                ResponseParameterData::ManufacturerSpecific(
+                    Vec::<u8, 231>::from_slice(&[0x04, 0x03, 0x02, 0x01]).unwrap(),
+                ),
+            )),
+        })
+        .encode();
+
+        let expected = &[
+            0xcc, // Start Code
+            0x01, // Sub Start Code
+            28,   // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x00, // Response Type = Ack
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
+            0x31, // Command Class = SetCommandResponse
+            0x80, 0x80, // Parameter ID = Identify Device
+            0x04, // PDL
+            0x04, 0x03, 0x02, 0x01, // Arbitrary manufacturer specific data
+            0x02, 0x52, // Checksum
+        ];
+
+        assert_eq!(encoded, expected);
+    }
+
+    #[test]
+    fn should_decode_valid_rdm_ack_timer_response() {
+        let decoded = RdmResponse::decode(&[
+            0xcc, // Start Code
+            0x01, // Sub Start Code
+            26,   // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x01, // Response Type = AckTimer
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
+            0x21, // Command Class = GetCommandResponse
+            0x10, 0x00, // Parameter ID = Identify Device
+            0x02, // PDL
+            0x00, 0x0a, // Estimated Response Time = 10x 100ms = 1 second
+            0x01, 0x4f, // Checksum
+        ]);
 
-        #[cfg(feature = "alloc")]
-        buf.push(self.command_class as u8);
-        #[cfg(not(feature = "alloc"))]
-        buf.push(self.command_class as u8).unwrap();
+        let expected = Ok(RdmResponse::RdmFrame(RdmFrameResponse {
+            destination_uid: DeviceUID::new(0x0102, 0x03040506),
+            source_uid: DeviceUID::new(0x0605, 0x04030201),
+            transaction_number: 0x00,
+            response_type: ResponseType::AckTimer,
+            message_count: 0x00,
+            sub_device_id: SubDeviceId::RootDevice,
+            command_class: CommandClass::GetCommandResponse,
+            parameter_id: ParameterId::IdentifyDevice,
+            parameter_data: ResponseData::EstimateResponseTime(0x0a),
+        }));
 
-        buf.extend(u16::from(self.parameter_id).to_be_bytes());
+        assert_eq!(decoded, expected);
+    }
 
-        #[cfg(feature = "alloc")]
-        buf.push(parameter_data.len() as u8);
-        #[cfg(not(feature = "alloc"))]
-        buf.push(parameter_data.len() as u8).unwrap();
+    #[test]
+    fn should_encode_valid_rdm_ack_timer_response() {
+        let encoded = RdmResponse::RdmFrame(RdmFrameResponse {
+            destination_uid: DeviceUID::new(0x0102, 0x03040506),
+            source_uid: DeviceUID::new(0x0605, 0x04030201),
+            transaction_number: 0x00,
+            response_type: ResponseType::AckTimer,
+            message_count: 0x00,
+            sub_device_id: SubDeviceId::RootDevice,
+            command_class: CommandClass::GetCommandResponse,
+            parameter_id: ParameterId::IdentifyDevice,
+            parameter_data: ResponseData::EstimateResponseTime(0x0a),
+        })
+        .encode();
 
-        buf.extend(parameter_data);
-        buf.extend(bsd_16_crc(&buf[..]).to_be_bytes());
+        let expected = &[
+            0xcc, // Start Code
+            0x01, // Sub Start Code
+            26,   // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x01, // Response Type = AckTimer
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
+            0x21, // Command Class = GetCommandResponse
+            0x10, 0x00, // Parameter ID = Identify Device
+            0x02, // PDL
+            0x00, 0x0a, // Estimated Response Time = 10x 100ms = 1 second
+            0x01, 0x4f, // Checksum
+        ];
 
-        buf
+        assert_eq!(encoded, expected);
     }
 
-    pub fn decode(bytes: &[u8]) -> Result<Self, RdmError> {
-        let message_length = bytes[2];
-
-        if message_length < 24 {
-            return Err(RdmError::InvalidMessageLength(message_length));
-        }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_round_trip_response_through_vec() {
+        let response = RdmResponse::RdmFrame(RdmFrameResponse {
+            destination_uid: DeviceUID::new(0x0102, 0x03040506),
+            source_uid: DeviceUID::new(0x0605, 0x04030201),
+            transaction_number: 0x00,
+            response_type: ResponseType::Ack,
+            message_count: 0x00,
+            sub_device_id: SubDeviceId::RootDevice,
+            command_class: CommandClass::GetCommandResponse,
+            parameter_id: ParameterId::IdentifyDevice,
+            parameter_data: ResponseData::ParameterData(Some(
+                ResponseParameterData::GetIdentifyDevice(true),
+            )),
+        });
 
-        if bytes.len() < message_length as usize + 2 {
-            return Err(RdmError::InvalidMessageLength(message_length));
-        }
+        let bytes = Vec::from(response.clone());
 
-        let packet_checksum = u16::from_be_bytes(
-            bytes[message_length as usize..=message_length as usize + 1].try_into()?,
+        assert_eq!(bytes, response.encode());
+        assert_eq!(RdmResponse::try_from(bytes), Ok(response));
+        assert_eq!(
+            RdmResponse::try_from(vec![0x00]),
+            Err(RdmError::InvalidStartCode(0x00))
         );
+    }
 
-        let decoded_checksum = bsd_16_crc(&bytes[..message_length as usize]);
-
-        if decoded_checksum != packet_checksum {
-            return Err(RdmError::InvalidChecksum(decoded_checksum, packet_checksum));
-        }
-
-        let destination_uid = DeviceUID::from(<[u8; 6]>::try_from(&bytes[3..=8])?);
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_collect_non_sequential_slot_descriptions() {
+        let mut collector = SlotDescriptionCollector::new();
+
+        assert!(
+            collector.ingest(&ResponseParameterData::GetSlotDescription {
+                slot_id: 0x000a,
+                description: "Pan".to_string(),
+            })
+        );
+        assert!(
+            collector.ingest(&ResponseParameterData::GetSlotDescription {
+                slot_id: 0x0002,
+                description: "Tilt".to_string(),
+            })
+        );
+        assert!(!collector.ingest(&ResponseParameterData::GetIdentifyDevice(true)));
+
+        assert_eq!(collector.len(), 2);
+        assert_eq!(collector.get(0x000a), Some("Pan"));
+        assert_eq!(collector.get(0x0001), None);
+        assert_eq!(
+            collector.into_map(),
+            HashMap::from([(0x0002, "Tilt".to_string()), (0x000a, "Pan".to_string())])
+        );
+    }
 
-        let source_uid = DeviceUID::from(<[u8; 6]>::try_from(&bytes[9..=14])?);
+    #[test]
+    fn should_return_parameter_data() {
+        assert_eq!(
+            ResponseData::ParameterData(Some(ResponseParameterData::GetIdentifyDevice(true)))
+                .parameter_data(),
+            Some(&ResponseParameterData::GetIdentifyDevice(true))
+        );
+        assert_eq!(ResponseData::ParameterData(None).parameter_data(), None);
+        assert_eq!(
+            ResponseData::EstimateResponseTime(10).parameter_data(),
+            None
+        );
+        assert_eq!(
+            ResponseData::NackReason(ResponseNackReasonCode::UnknownPid).parameter_data(),
+            None
+        );
+    }
 
-        let transaction_number = bytes[15];
+    #[test]
+    fn should_not_decode_short_ack_timer_or_nack_reason() {
+        assert_eq!(
+            ResponseData::decode(
+                ResponseType::AckTimer,
+                CommandClass::GetCommandResponse,
+                1,
+                ParameterId::IdentifyDevice,
+                &[0x0a], // Partial Estimated Response Time
+            ),
+            Err(RdmError::InvalidMessageLength(1))
+        );
+        assert_eq!(
+            ResponseData::decode(
+                ResponseType::NackReason,
+                CommandClass::GetCommandResponse,
+                0,
+                ParameterId::IdentifyDevice,
+                &[],
+            ),
+            Err(RdmError::InvalidMessageLength(0))
+        );
+    }
 
-        let response_type = ResponseType::try_from(bytes[16])?;
+    #[test]
+    fn should_decode_valid_rdm_nack_reason_response() {
+        let decoded = RdmResponse::decode(&[
+            0xcc, // Start Code
+            0x01, // Sub Start Code
+            26,   // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x02, // Response Type = Nack_Reason
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
+            0x21, // Command Class = GetCommandResponse
+            0x10, 0x00, // Parameter ID = Identify Device
+            0x02, // PDL
+            0x00, 0x01, // Nack Reason = FormatError
+            0x01, 0x47, // Checksum
+        ]);
 
-        let message_count = bytes[17];
+        let expected = Ok(RdmResponse::RdmFrame(RdmFrameResponse {
+            destination_uid: DeviceUID::new(0x0102, 0x03040506),
+            source_uid: DeviceUID::new(0x0605, 0x04030201),
+            transaction_number: 0x00,
+            response_type: ResponseType::NackReason,
+            message_count: 0x00,
+            sub_device_id: SubDeviceId::RootDevice,
+            command_class: CommandClass::GetCommandResponse,
+            parameter_id: ParameterId::IdentifyDevice,
+            parameter_data: ResponseData::NackReason(ResponseNackReasonCode::FormatError),
+        }));
 
-        let sub_device_id = u16::from_be_bytes(bytes[18..=19].try_into()?).into();
+        assert_eq!(decoded, expected);
+    }
 
-        let command_class = CommandClass::try_from(bytes[20])?;
+    #[test]
+    fn should_encode_valid_rdm_nack_reason_response() {
+        let encoded = RdmResponse::RdmFrame(RdmFrameResponse {
+            destination_uid: DeviceUID::new(0x0102, 0x03040506),
+            source_uid: DeviceUID::new(0x0605, 0x04030201),
+            transaction_number: 0x00,
+            response_type: ResponseType::NackReason,
+            message_count: 0x00,
+            sub_device_id: SubDeviceId::RootDevice,
+            command_class: CommandClass::GetCommandResponse,
+            parameter_id: ParameterId::IdentifyDevice,
+            parameter_data: ResponseData::NackReason(ResponseNackReasonCode::FormatError),
+        })
+        .encode();
 
-        let parameter_id = u16::from_be_bytes(bytes[21..=22].try_into()?).into();
+        let expected = &[
+            0xcc, // Start Code
+            0x01, // Sub Start Code
+            26,   // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x02, // Response Type = Nack_Reason
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
+            0x21, // Command Class = GetCommandResponse
+            0x10, 0x00, // Parameter ID = Identify Device
+            0x02, // PDL
+            0x00, 0x01, // Nack Reason = FormatError
+            0x01, 0x47, // Checksum
+        ];
 
-        let parameter_data_length = bytes[23];
+        assert_eq!(encoded, expected);
+    }
 
-        if parameter_data_length > 231 {
-            return Err(RdmError::InvalidParameterDataLength(parameter_data_length));
+    #[test]
+    fn should_round_trip_interface_hardware_address_type1_for_multiple_interfaces() {
+        for (interface_id, hardware_address) in [
+            (
+                0x00000001,
+                MacAddr6::new(0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e),
+            ),
+            (
+                0x00000002,
+                MacAddr6::new(0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5f),
+            ),
+        ] {
+            let response = RdmResponse::RdmFrame(RdmFrameResponse {
+                destination_uid: DeviceUID::new(0x0102, 0x03040506),
+                source_uid: DeviceUID::new(0x0605, 0x04030201),
+                transaction_number: 0x00,
+                response_type: ResponseType::Ack,
+                message_count: 0x00,
+                sub_device_id: SubDeviceId::RootDevice,
+                command_class: CommandClass::GetCommandResponse,
+                parameter_id: ParameterId::InterfaceHardwareAddressType1,
+                parameter_data: ResponseData::ParameterData(Some(
+                    ResponseParameterData::GetInterfaceHardwareAddressType1 {
+                        interface_id,
+                        hardware_address,
+                    },
+                )),
+            });
+
+            let encoded = response.encode();
+
+            assert_eq!(&encoded[24..28], &interface_id.to_be_bytes());
+            assert_eq!(RdmResponse::decode(&encoded), Ok(response));
         }
-
-        let parameter_data = ResponseData::decode(
-            response_type,
-            command_class,
-            parameter_data_length,
-            parameter_id,
-            &bytes[24..=(24 + parameter_data_length as usize - 1)],
-        )?;
-
-        Ok(Self {
-            destination_uid,
-            source_uid,
-            transaction_number,
-            response_type,
-            message_count,
-            sub_device_id,
-            command_class,
-            parameter_id,
-            parameter_data,
-        })
     }
-}
 
-impl TryFrom<&[u8]> for RdmFrameResponse {
-    type Error = RdmError;
+    #[test]
+    fn should_round_trip_get_preset_info_response() {
+        let parameter_data = ResponseParameterData::GetPresetInfo {
+            level_field_supported: true,
+            preset_sequence_supported: false,
+            split_times_supported: true,
+            dmx_fail_infinite_delay_time_supported: true,
+            dmx_fail_infinite_hold_time_supported: false,
+            startup_infinite_hold_time_supported: true,
+            maximum_scene_number: 0x00ff,
+            minimum_preset_fade_time_supported: 0x0000,
+            maximum_preset_fade_time_supported: 0x0258,
+            minimum_preset_wait_time_supported: 0x0001,
+            maximum_preset_wait_time_supported: 0xfffe,
+            minimum_dmx_fail_delay_time_supported: SupportedTimes::Time(0x0000),
+            maximum_dmx_fail_delay_time_supported: SupportedTimes::NotSupported,
+            minimum_dmx_fail_hold_time_supported: SupportedTimes::Time(0x000a),
+            maximum_dmx_fail_hold_time_supported: SupportedTimes::Time(0xfffe),
+            minimum_startup_delay_time_supported: SupportedTimes::NotSupported,
+            maximum_startup_delay_time_supported: SupportedTimes::NotSupported,
+            minimum_startup_hold_time_supported: SupportedTimes::Time(0x0001),
+            maximum_startup_hold_time_supported: SupportedTimes::NotSupported,
+        };
 
-    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
-        RdmFrameResponse::decode(bytes)
+        let encoded = parameter_data.encode();
+
+        assert_eq!(
+            &encoded[..],
+            &[
+                0x01, 0x00, 0x01, 0x01, 0x00, 0x01, // Supported flags
+                0x00, 0xff, // Maximum Scene Number
+                0x00, 0x00, 0x02, 0x58, // Preset Fade Time Range
+                0x00, 0x01, 0xff, 0xfe, // Preset Wait Time Range
+                0x00, 0x00, 0xff, 0xff, // DMX Fail Delay Time Range
+                0x00, 0x0a, 0xff, 0xfe, // DMX Fail Hold Time Range
+                0xff, 0xff, 0xff, 0xff, // Startup Delay Time Range
+                0x00, 0x01, 0xff, 0xff, // Startup Hold Time Range
+            ]
+        );
+        assert_eq!(
+            ResponseParameterData::decode(
+                CommandClass::GetCommandResponse,
+                ParameterId::PresetInfo,
+                &encoded
+            ),
+            Ok(parameter_data)
+        );
     }
-}
-
-#[derive(Copy, Clone, Debug, PartialEq)]
-pub struct DiscoveryUniqueBranchFrameResponse(pub DeviceUID);
 
-impl DiscoveryUniqueBranchFrameResponse {
-    pub fn encode(&self) -> EncodedFrame {
-        #[cfg(feature = "alloc")]
-        let mut buf = Vec::with_capacity(24);
-        #[cfg(not(feature = "alloc"))]
-        let mut buf = Vec::new();
+    #[test]
+    fn should_round_trip_get_identify_mode_response() {
+        for (identify_mode, byte) in [(IdentifyMode::Quiet, 0x00), (IdentifyMode::Loud, 0xff)] {
+            let response = RdmResponse::RdmFrame(RdmFrameResponse {
+                destination_uid: DeviceUID::new(0x0102, 0x03040506),
+                source_uid: DeviceUID::new(0x0605, 0x04030201),
+                transaction_number: 0x00,
+                response_type: ResponseType::Ack,
+                message_count: 0x00,
+                sub_device_id: SubDeviceId::RootDevice,
+                command_class: CommandClass::GetCommandResponse,
+                parameter_id: ParameterId::IdentifyMode,
+                parameter_data: ResponseData::ParameterData(Some(
+                    ResponseParameterData::GetIdentifyMode(identify_mode),
+                )),
+            });
+
+            let encoded = response.encode();
+
+            assert_eq!(encoded[24], byte);
+            assert_eq!(RdmResponse::decode(&encoded), Ok(response));
+        }
+    }
 
-        buf.extend(iter::repeat(DISCOVERY_UNIQUE_BRANCH_PREAMBLE_BYTE).take(7));
+    #[test]
+    fn should_round_trip_comms_status_responses() {
+        let response = RdmResponse::RdmFrame(RdmFrameResponse {
+            destination_uid: DeviceUID::new(0x0102, 0x03040506),
+            source_uid: DeviceUID::new(0x0605, 0x04030201),
+            transaction_number: 0x00,
+            response_type: ResponseType::Ack,
+            message_count: 0x00,
+            sub_device_id: SubDeviceId::RootDevice,
+            command_class: CommandClass::GetCommandResponse,
+            parameter_id: ParameterId::CommsStatus,
+            parameter_data: ResponseData::ParameterData(Some(
+                ResponseParameterData::GetCommsStatus {
+                    short_message: 0x0001,
+                    length_mismatch: 0xfffe,
+                    checksum_fail: 0xffff,
+                },
+            )),
+        });
 
-        #[cfg(feature = "alloc")]
-        buf.push(DISCOVERY_UNIQUE_BRANCH_PREAMBLE_SEPARATOR_BYTE);
-        #[cfg(not(feature = "alloc"))]
-        buf.push(DISCOVERY_UNIQUE_BRANCH_PREAMBLE_SEPARATOR_BYTE)
-            .unwrap();
+        let encoded = response.encode();
 
-        let [manufacturer_id1, manufacturer_id0] = self.0.manufacturer_id.to_be_bytes();
+        assert_eq!(&encoded[24..30], &[0x00, 0x01, 0xff, 0xfe, 0xff, 0xff]);
+        assert_eq!(RdmResponse::decode(&encoded), Ok(response.clone()));
 
-        buf.extend([
-            manufacturer_id1 | 0xaa,
-            manufacturer_id1 | 0x55,
-            manufacturer_id0 | 0xaa,
-            manufacturer_id0 | 0x55,
-        ]);
+        let RdmResponse::RdmFrame(frame) = response else {
+            unreachable!()
+        };
+        let ResponseData::ParameterData(Some(parameter_data)) = frame.parameter_data else {
+            unreachable!()
+        };
+        let comms_status = parameter_data.comms_status().unwrap();
 
-        let [device_id3, device_id2, device_id1, device_id0] = self.0.device_id.to_be_bytes();
-
-        buf.extend([
-            device_id3 | 0xaa,
-            device_id3 | 0x55,
-            device_id2 | 0xaa,
-            device_id2 | 0x55,
-            device_id1 | 0xaa,
-            device_id1 | 0x55,
-            device_id0 | 0xaa,
-            device_id0 | 0x55,
-        ]);
+        assert_eq!(comms_status, CommsStatus::new(0x0001, 0xfffe, 0xffff));
+        assert_eq!(comms_status.total_errors(), 0x0001_fffe);
 
-        let [checksum1, checksum0] = bsd_16_crc(&buf[8..]).to_be_bytes();
+        let cleared = RdmResponse::RdmFrame(RdmFrameResponse {
+            destination_uid: DeviceUID::new(0x0102, 0x03040506),
+            source_uid: DeviceUID::new(0x0605, 0x04030201),
+            transaction_number: 0x01,
+            response_type: ResponseType::Ack,
+            message_count: 0x00,
+            sub_device_id: SubDeviceId::RootDevice,
+            command_class: CommandClass::SetCommandResponse,
+            parameter_id: ParameterId::CommsStatus,
+            parameter_data: ResponseData::ParameterData(None),
+        });
 
-        buf.extend([
-            checksum1 | 0xaa,
-            checksum1 | 0x55,
-            checksum0 | 0xaa,
-            checksum0 | 0x55,
-        ]);
+        let encoded = cleared.encode();
 
-        buf
+        assert_eq!(encoded[23], 0x00);
+        assert_eq!(RdmResponse::decode(&encoded), Ok(cleared));
+        assert!(RdmFrameResponse::decode_strict(&encoded).is_ok());
     }
 
-    pub fn decode(bytes: &[u8]) -> Result<Self, RdmError> {
-        let Some(frame_start_index) = bytes.iter().position(|&x| x == 0xaa) else {
-            return Err(RdmError::InvalidDiscoveryUniqueBranchPreamble);
-        };
-
-        let euid = &bytes[(frame_start_index + 1)..=(frame_start_index + 12)];
-
-        let ecs = &bytes[(frame_start_index + 13)..=(frame_start_index + 16)];
-
-        let decoded_checksum = bsd_16_crc(euid);
-
-        let checksum = u16::from_be_bytes([ecs[0] & ecs[1], ecs[2] & ecs[3]]);
+    #[test]
+    fn should_not_decode_get_identify_mode_response_with_invalid_mode() {
+        assert_eq!(
+            ResponseParameterData::decode(
+                CommandClass::GetCommandResponse,
+                ParameterId::IdentifyMode,
+                &[0x01]
+            ),
+            Err(RdmError::InvalidIdentifyMode(0x01))
+        );
+    }
 
-        if checksum != decoded_checksum {
-            return Err(RdmError::InvalidChecksum(decoded_checksum, checksum));
+    #[test]
+    fn should_round_trip_ipv4_current_address_for_each_dhcp_mode() {
+        for dhcp_status in [DhcpMode::Inactive, DhcpMode::Active, DhcpMode::Unknown] {
+            let response = RdmResponse::RdmFrame(RdmFrameResponse {
+                destination_uid: DeviceUID::new(0x0102, 0x03040506),
+                source_uid: DeviceUID::new(0x0605, 0x04030201),
+                transaction_number: 0x00,
+                response_type: ResponseType::Ack,
+                message_count: 0x00,
+                sub_device_id: SubDeviceId::RootDevice,
+                command_class: CommandClass::GetCommandResponse,
+                parameter_id: ParameterId::IpV4CurrentAddress,
+                parameter_data: ResponseData::ParameterData(Some(
+                    ResponseParameterData::GetIpV4CurrentAddress {
+                        interface_id: 0x00000001,
+                        address: core::net::Ipv4Addr::new(192, 168, 0, 10).into(),
+                        netmask: 24,
+                        dhcp_status,
+                    },
+                )),
+            });
+
+            let encoded = response.encode();
+
+            assert_eq!(encoded[33], u8::from(dhcp_status));
+            assert_eq!(RdmResponse::decode(&encoded), Ok(response));
         }
-
-        let manufacturer_id = u16::from_be_bytes([euid[0] & euid[1], euid[2] & euid[3]]);
-
-        let device_id = u32::from_be_bytes([
-            euid[4] & euid[5],
-            euid[6] & euid[7],
-            euid[8] & euid[9],
-            euid[10] & euid[11],
-        ]);
-
-        Ok(Self(DeviceUID::new(manufacturer_id, device_id)))
     }
-}
 
-impl TryFrom<&[u8]> for DiscoveryUniqueBranchFrameResponse {
-    type Error = RdmError;
+    #[test]
+    fn should_not_decode_ipv4_current_address_with_invalid_dhcp_mode() {
+        let mut packet = [
+            0xcc, // Start Code
+            0x01, // Sub Start Code
+            34,   // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x00, // Response Type = Ack
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
+            0x21, // Command Class = GetCommandResponse
+            0x07, 0x05, // Parameter ID = IpV4CurrentAddress
+            0x0a, // PDL
+            0x00, 0x00, 0x00, 0x01, // Interface ID
+            192, 168, 0, 10,   // Address
+            24,   // Netmask
+            0x03, // DHCP Status = undefined
+            0x00, 0x00, // Checksum
+        ];
+        let [checksum1, checksum0] = bsd_16_crc(&packet[..34]).to_be_bytes();
+        packet[34] = checksum1;
+        packet[35] = checksum0;
 
-    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
-        DiscoveryUniqueBranchFrameResponse::decode(bytes)
+        assert_eq!(
+            RdmResponse::decode(&packet),
+            Err(RdmError::InvalidDhcpMode(0x03))
+        );
     }
-}
-
-#[allow(clippy::large_enum_variant)]
-#[derive(Clone, Debug, PartialEq)]
-pub enum RdmResponse {
-    RdmFrame(RdmFrameResponse),
-    DiscoveryUniqueBranchFrame(DiscoveryUniqueBranchFrameResponse),
-}
 
-impl RdmResponse {
-    pub fn encode(&self) -> EncodedFrame {
-        match self {
-            RdmResponse::RdmFrame(frame) => frame.encode(),
-            RdmResponse::DiscoveryUniqueBranchFrame(frame) => frame.encode(),
-        }
+    #[test]
+    fn should_limit_decoded_proxied_devices_to_maximum_entries() {
+        assert!(matches!(
+            ResponseParameterData::decode(
+                CommandClass::GetCommandResponse,
+                ParameterId::ProxiedDevices,
+                &[0x01; 38 * 6]
+            ),
+            Ok(ResponseParameterData::GetProxiedDevices(devices)) if devices.len() == 38
+        ));
+        assert_eq!(
+            ResponseParameterData::decode(
+                CommandClass::GetCommandResponse,
+                ParameterId::ProxiedDevices,
+                &[0x01; 39 * 6]
+            ),
+            Err(RdmError::InvalidParameterDataLength(234))
+        );
     }
 
-    pub fn decode(bytes: &[u8]) -> Result<Self, RdmError> {
-        if bytes[0] == RDM_START_CODE_BYTE && bytes[1] == RDM_SUB_START_CODE_BYTE {
-            if bytes.len() < 25 {
-                return Err(RdmError::InvalidFrameLength(bytes.len() as u8));
-            }
+    #[test]
+    fn should_not_decode_partial_list_entries() {
+        assert_eq!(
+            ResponseParameterData::decode(
+                CommandClass::GetCommandResponse,
+                ParameterId::ProxiedDevices,
+                &[
+                    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Device UID
+                    0x06, 0x05, 0x04, // Partial Device UID
+                ],
+            ),
+            Err(RdmError::InvalidParameterDataLength(9))
+        );
+        assert_eq!(
+            ResponseParameterData::decode(
+                CommandClass::GetCommandResponse,
+                ParameterId::SlotInfo,
+                &[
+                    0x00, 0x01, 0x00, 0x00, 0x01, // Slot Info
+                    0x00, 0x02, // Partial Slot Info
+                ],
+            ),
+            Err(RdmError::InvalidParameterDataLength(7))
+        );
+        assert_eq!(
+            ResponseParameterData::decode(
+                CommandClass::GetCommandResponse,
+                ParameterId::EndpointResponders,
+                &[
+                    0x00, 0x01, // Endpoint ID
+                    0x00, 0x00, 0x00, 0x01, // List Change Number
+                    0x01, 0x02, // Partial Device UID
+                ],
+            ),
+            Err(RdmError::InvalidParameterDataLength(2))
+        );
+    }
 
-            return RdmFrameResponse::decode(bytes).map(RdmResponse::RdmFrame);
-        }
+    #[test]
+    fn should_round_trip_endpoint_list() {
+        let bytes = [
+            0x00, 0x00, 0x00, 0x05, // List Change Number
+            0x00, 0x01, // Endpoint ID
+            0x01, // Endpoint Type = Physical
+            0x01, 0x02, // Endpoint ID
+            0x00, // Endpoint Type = Virtual
+        ];
 
-        if bytes[0] == DISCOVERY_UNIQUE_BRANCH_PREAMBLE_BYTE
-            || bytes[0] == DISCOVERY_UNIQUE_BRANCH_PREAMBLE_SEPARATOR_BYTE
-        {
-            if bytes.len() < 17 {
-                return Err(RdmError::InvalidFrameLength(bytes.len() as u8));
+        let decoded = ResponseParameterData::decode(
+            CommandClass::GetCommandResponse,
+            ParameterId::EndpointList,
+            &bytes,
+        )
+        .unwrap();
+
+        assert_eq!(
+            decoded,
+            ResponseParameterData::GetEndpointList {
+                list_change_number: 0x00000005,
+                endpoint_list: [
+                    (EndpointId::Device(0x0001), EndpointType::Physical),
+                    (EndpointId::Device(0x0102), EndpointType::Virtual),
+                ]
+                .into_iter()
+                .collect(),
             }
+        );
+        assert_eq!(&decoded.encode()[..], &bytes);
+    }
 
-            return DiscoveryUniqueBranchFrameResponse::decode(bytes)
-                .map(RdmResponse::DiscoveryUniqueBranchFrame);
-        }
+    #[test]
+    fn should_round_trip_component_scope() {
+        let mut bytes = [0; 88];
+        bytes[0..=1].copy_from_slice(&[0x00, 0x01]); // Scope Slot
+        bytes[2..9].copy_from_slice(b"default"); // Scope String, null padded to 63 bytes
+        bytes[65] = 0x01; // Static Config Type = IPv4
+        bytes[66..=69].copy_from_slice(&[192, 168, 0, 1]); // Static IPv4 Address
+        bytes[86..=87].copy_from_slice(&[0x1f, 0x90]); // Static Port = 8080
+
+        let decoded = ResponseParameterData::decode(
+            CommandClass::GetCommandResponse,
+            ParameterId::ComponentScope,
+            &bytes,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            decoded,
+            ResponseParameterData::GetComponentScope {
+                scope_slot: 0x0001,
+                static_config_type: StaticConfigType::StaticConfigIpv4,
+                static_ipv6_address: Ipv6Address::Unconfigured,
+                static_port: 8080,
+                ref scope_string,
+                ..
+            } if scope_string.as_str() == "default"
+        ));
+        assert_eq!(&decoded.encode()[..], &bytes);
+        assert_eq!(
+            ResponseParameterData::decode(
+                CommandClass::GetCommandResponse,
+                ParameterId::ComponentScope,
+                &bytes[..86],
+            ),
+            Err(RdmError::InvalidMessageLength(86))
+        );
+    }
 
-        Err(RdmError::InvalidStartCode)
+    #[test]
+    fn should_round_trip_tcp_comms_status() {
+        let mut bytes = [0; 87];
+        bytes[0..7].copy_from_slice(b"default"); // Scope String, null padded to 63 bytes
+        bytes[63..=66].copy_from_slice(&[10, 0, 0, 1]); // Broker IPv4 Address
+        bytes[83..=84].copy_from_slice(&[0x15, 0xb3]); // Broker Port = 5555
+        bytes[85..=86].copy_from_slice(&[0x00, 0x02]); // Unhealthy TCP Events
+
+        let decoded = ResponseParameterData::decode(
+            CommandClass::GetCommandResponse,
+            ParameterId::TcpCommsStatus,
+            &bytes,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            decoded,
+            ResponseParameterData::GetTcpCommsStatus {
+                broker_ipv6_address: Ipv6Address::Unconfigured,
+                broker_port: 5555,
+                unhealthy_tcp_events: 0x0002,
+                ref scope_string,
+                ..
+            } if scope_string.as_str() == "default"
+        ));
+        assert_eq!(&decoded.encode()[..], &bytes);
     }
-}
 
-impl TryFrom<&[u8]> for RdmResponse {
-    type Error = RdmError;
+    #[test]
+    fn should_round_trip_sensor_definition() {
+        let bytes = [
+            0x01, // Sensor ID
+            0x00, // Sensor Type = Temperature
+            0x01, // Sensor Unit = Centigrade
+            0x00, // Sensor Unit Prefix = None
+            0xff, 0xf6, // Range Minimum Value = -10
+            0x00, 0x64, // Range Maximum Value = 100
+            0x00, 0x00, // Normal Minimum Value = 0
+            0x00, 0x50, // Normal Maximum Value = 80
+            0x02, // Recorded Value Support = Lowest/Highest Detected Values
+            b'A', b'm', b'b', b'i', b'e', b'n', b't', // Description
+        ];
 
-    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
-        RdmResponse::decode(bytes)
+        let decoded = ResponseParameterData::decode(
+            CommandClass::GetCommandResponse,
+            ParameterId::SensorDefinition,
+            &bytes,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            decoded,
+            ResponseParameterData::GetSensorDefinition(SensorDefinition {
+                id: 0x01,
+                range_minimum_value: -10,
+                normal_maximum_value: 80,
+                is_lowest_highest_detected_value_supported: true,
+                is_recorded_value_supported: false,
+                ..
+            })
+        ));
+        assert_eq!(&decoded.encode()[..], &bytes);
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
     #[test]
-    fn should_decode_valid_rdm_ack_response() {
+    fn should_not_decode_response_with_parameter_data_length_exceeding_message_length() {
         let decoded = RdmResponse::decode(&[
             0xcc, // Start Code
             0x01, // Sub Start Code
@@ -3378,49 +6241,122 @@ mod tests {
             0x00, 0x00, // Sub-Device ID = Root Device
             0x21, // Command Class = GetCommandResponse
             0x10, 0x00, // Parameter ID = Identify Device
+            0xe7, // PDL = 231
+            0x01, // Identifying = true
+            0x02, 0x29, // Checksum
+        ]);
+
+        assert_eq!(decoded, Err(RdmError::InvalidParameterDataLength(0xe7)));
+    }
+
+    #[test]
+    fn should_not_decode_response_with_invalid_start_code() {
+        let decoded = RdmResponse::decode(&[
+            0x17, // Start Code = Text Packet
+            0x01, // Sub Start Code
+            25,   // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x00, // Response Type = Ack
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
+            0x21, // Command Class = GetCommandResponse
+            0x10, 0x00, // Parameter ID = Identify Device
             0x01, // PDL
             0x01, // Identifying = true
             0x01, 0x43, // Checksum
         ]);
 
-        let expected = Ok(RdmResponse::RdmFrame(RdmFrameResponse {
-            destination_uid: DeviceUID::new(0x0102, 0x03040506),
-            source_uid: DeviceUID::new(0x0605, 0x04030201),
-            transaction_number: 0x00,
-            response_type: ResponseType::Ack,
-            message_count: 0x00,
-            sub_device_id: SubDeviceId::RootDevice,
-            command_class: CommandClass::GetCommandResponse,
-            parameter_id: ParameterId::IdentifyDevice,
-            parameter_data: ResponseData::ParameterData(Some(
-                ResponseParameterData::GetIdentifyDevice(true),
-            )),
-        }));
+        assert_eq!(decoded, Err(RdmError::InvalidStartCode(0x17)));
+    }
 
-        assert_eq!(decoded, expected);
+    #[test]
+    fn should_detect_empty_queued_message_response() {
+        let decoded = RdmResponse::decode(&[
+            0xcc, // Start Code
+            0x01, // Sub Start Code
+            24,   // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x00, // Response Type = Ack
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
+            0x21, // Command Class = GetCommandResponse
+            0x00, 0x30, // Parameter ID = Status Messages
+            0x00, // PDL
+            0x01, 0x60, // Checksum
+        ]);
+
+        let Ok(RdmResponse::RdmFrame(mut response)) = decoded else {
+            panic!("expected rdm frame, got {:?}", decoded);
+        };
+
+        assert!(response.is_queue_empty());
+
+        response.parameter_data =
+            ResponseData::ParameterData(Some(ResponseParameterData::GetStatusMessages(
+                [StatusMessage::new(
+                    SubDeviceId::RootDevice,
+                    StatusType::Error,
+                    0x8001,
+                    0x0000,
+                    0x0000,
+                )]
+                .into_iter()
+                .collect(),
+            )));
+
+        assert!(!response.is_queue_empty());
     }
 
     #[test]
-    fn should_encode_valid_rdm_ack_response() {
-        let encoded = RdmResponse::RdmFrame(RdmFrameResponse {
+    fn should_build_queued_message_request_from_message_count() {
+        let mut response = RdmFrameResponse {
             destination_uid: DeviceUID::new(0x0102, 0x03040506),
             source_uid: DeviceUID::new(0x0605, 0x04030201),
-            transaction_number: 0x00,
+            transaction_number: 0x04,
             response_type: ResponseType::Ack,
             message_count: 0x00,
-            sub_device_id: SubDeviceId::RootDevice,
+            sub_device_id: SubDeviceId::Id(0x0001),
             command_class: CommandClass::GetCommandResponse,
             parameter_id: ParameterId::IdentifyDevice,
             parameter_data: ResponseData::ParameterData(Some(
                 ResponseParameterData::GetIdentifyDevice(true),
             )),
-        })
-        .encode();
+        };
 
-        let expected = &[
+        assert!(!response.has_queued_messages());
+        assert_eq!(
+            response.queued_message_request(0x05, StatusType::Advisory),
+            None
+        );
+
+        response.message_count = 0x03;
+
+        assert!(response.has_queued_messages());
+        assert_eq!(
+            response.queued_message_request(0x05, StatusType::Advisory),
+            Some(RdmRequest::new(
+                DeviceUID::new(0x0605, 0x04030201),
+                DeviceUID::new(0x0102, 0x03040506),
+                0x05,
+                0x01,
+                SubDeviceId::RootDevice,
+                RequestParameter::GetQueuedMessage {
+                    status_type: StatusType::Advisory,
+                },
+            ))
+        );
+    }
+
+    #[test]
+    fn should_not_strictly_decode_get_response_missing_required_parameter_data() {
+        let bytes = [
             0xcc, // Start Code
             0x01, // Sub Start Code
-            25,   // Message Length
+            24,   // Message Length
             0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
             0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
             0x00, // Transaction Number
@@ -3428,208 +6364,505 @@ mod tests {
             0x00, // Message Count
             0x00, 0x00, // Sub-Device ID = Root Device
             0x21, // Command Class = GetCommandResponse
-            0x10, 0x00, // Parameter ID = Identify Device
-            0x01, // PDL
-            0x01, // Identifying = true
-            0x01, 0x43, // Checksum
+            0x00, 0x60, // Parameter ID = Device Info
+            0x00, // PDL
+            0x01, 0x90, // Checksum
         ];
 
-        assert_eq!(encoded, expected);
+        assert_eq!(
+            RdmFrameResponse::decode(&bytes).map(|response| response.parameter_data),
+            Ok(ResponseData::ParameterData(None))
+        );
+        assert_eq!(
+            RdmFrameResponse::decode_strict(&bytes),
+            Err(RdmError::MissingParameterData {
+                parameter_id: 0x0060
+            })
+        );
     }
 
     #[test]
-    fn should_decode_valid_rdm_ack_manufacturer_specific_response() {
-        let decoded = RdmResponse::decode(&[
+    fn should_not_strictly_decode_get_factory_defaults_response_missing_parameter_data() {
+        let bytes = [
             0xcc, // Start Code
             0x01, // Sub Start Code
-            28,   // Message Length
+            24,   // Message Length
             0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
             0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
             0x00, // Transaction Number
             0x00, // Response Type = Ack
             0x00, // Message Count
             0x00, 0x00, // Sub-Device ID = Root Device
+            0x21, // Command Class = GetCommandResponse
+            0x00, 0x90, // Parameter ID = Factory Defaults
+            0x00, // PDL
+            0x01, 0xc0, // Checksum
+        ];
+
+        assert_eq!(
+            RdmFrameResponse::decode(&bytes).map(|response| response.parameter_data),
+            Ok(ResponseData::ParameterData(None))
+        );
+        assert_eq!(
+            RdmFrameResponse::decode_strict(&bytes),
+            Err(RdmError::MissingParameterData {
+                parameter_id: 0x0090
+            })
+        );
+    }
+
+    #[test]
+    fn should_not_strictly_decode_response_with_unsupported_command_class() {
+        assert_eq!(
+            RdmFrameResponse::decode_strict(&[
+                0xcc, // Start Code
+                0x01, // Sub Start Code
+                24,   // Message Length
+                0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+                0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+                0x00, // Transaction Number
+                0x00, // Response Type = Ack
+                0x00, // Message Count
+                0x00, 0x00, // Sub-Device ID = Root Device
+                0x21, // Command Class = GetCommandResponse
+                0x10, 0x01, // Parameter ID = Reset Device
+                0x00, // PDL
+                0x01, 0x41, // Checksum
+            ]),
+            Err(RdmError::InvalidCommandClassForParameter {
+                command_class: 0x21,
+                parameter_id: 0x1001
+            })
+        );
+
+        let decoded = RdmFrameResponse::decode_strict(&[
+            0xcc, // Start Code
+            0x01, // Sub Start Code
+            26,   // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x02, // Response Type = Nack Reason
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
             0x31, // Command Class = SetCommandResponse
-            0x80, 0x80, // Parameter ID = Identify Device
-            0x04, // PDL
-            0x04, 0x03, 0x02, 0x01, // Arbitrary manufacturer specific data
-            0x02, 0x52, // Checksum
-        ]);
+            0x00, 0x60, // Parameter ID = Device Info
+            0x02, // PDL
+            0x00, 0x05, // Nack Reason = Unsupported Command Class
+            0x01, 0xab, // Checksum
+        ])
+        .map(|response| response.nack_reason());
+
+        assert_eq!(
+            decoded,
+            Ok(Some(ResponseNackReasonCode::UnsupportedCommandClass))
+        );
+    }
 
-        let expected = Ok(RdmResponse::RdmFrame(RdmFrameResponse {
-            destination_uid: DeviceUID::new(0x0102, 0x03040506),
-            source_uid: DeviceUID::new(0x0605, 0x04030201),
-            transaction_number: 0x00,
-            response_type: ResponseType::Ack,
-            message_count: 0x00,
-            sub_device_id: SubDeviceId::RootDevice,
-            command_class: CommandClass::SetCommandResponse,
-            parameter_id: ParameterId::ManufacturerSpecific(0x8080),
-            #[cfg(feature = "alloc")]
-            parameter_data: ResponseData::ParameterData(Some(
-                ResponseParameterData::ManufacturerSpecific(vec![0x04, 0x03, 0x02, 0x01]),
-            )),
-            #[cfg(not(feature = "alloc"))]
-            parameter_data: ResponseData::ParameterData(Some(
-                ResponseParameterData::ManufacturerSpecific(
-                    Vec::<u8, 231>::from_slice(&[0x04, 0x03, 0x02, 0x01]).unwrap(),
-                ),
-            )),
-        }));
+    #[test]
+    fn should_not_strictly_decode_unexpected_discovery_response() {
+        let mut bytes = [
+            0xcc, // Start Code
+            0x01, // Sub Start Code
+            24,   // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x00, // Response Type = Ack
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
+            0x11, // Command Class = DiscoveryCommandResponse
+            0x80, 0x00, // Parameter ID = Manufacturer Specific
+            0x00, // PDL
+            0x01, 0xa0, // Checksum
+        ];
 
-        assert_eq!(decoded, expected);
+        assert!(RdmFrameResponse::decode(&bytes).is_ok());
+        assert_eq!(
+            RdmFrameResponse::decode_strict(&bytes),
+            Err(RdmError::UnexpectedDiscoveryResponse(0x8000))
+        );
+
+        bytes[21..23].copy_from_slice(&[0x00, 0x03]); // Parameter ID = Disc Un Mute
+        bytes[24..26].copy_from_slice(&[0x01, 0x23]); // Checksum
+
+        assert!(RdmFrameResponse::decode_strict(&bytes).is_ok());
+    }
+
+    #[test]
+    fn should_strictly_decode_get_response_with_optional_parameter_data() {
+        let decoded = RdmFrameResponse::decode_strict(&[
+            0xcc, // Start Code
+            0x01, // Sub Start Code
+            24,   // Message Length
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
+            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
+            0x00, // Transaction Number
+            0x00, // Response Type = Ack
+            0x00, // Message Count
+            0x00, 0x00, // Sub-Device ID = Root Device
+            0x21, // Command Class = GetCommandResponse
+            0x00, 0x30, // Parameter ID = Status Messages
+            0x00, // PDL
+            0x01, 0x60, // Checksum
+        ])
+        .map(|response| response.parameter_data);
+
+        assert_eq!(decoded, Ok(ResponseData::ParameterData(None)));
+    }
+
+    #[test]
+    fn should_compute_dmx_address_range_from_device_info() {
+        let device_info = |footprint, start_address| ResponseParameterData::GetDeviceInfo {
+            protocol_version: ProtocolVersion::new(1, 0),
+            model_id: 0x0001,
+            product_category: ProductCategory::Fixture,
+            software_version_id: 0x00000001,
+            footprint,
+            current_personality: 1,
+            personality_count: 1,
+            start_address,
+            sub_device_count: 0,
+            sensor_count: 0,
+        };
+
+        assert_eq!(device_info(4, 1).dmx_address_range(), Some(1..=4));
+        assert_eq!(device_info(16, 505).dmx_address_range(), Some(505..=520));
+        assert_eq!(device_info(0, 1).dmx_address_range(), None);
+        assert_eq!(device_info(0, 0xffff).dmx_address_range(), None);
+        assert_eq!(
+            ResponseParameterData::GetIdentifyDevice(true).dmx_address_range(),
+            None
+        );
+    }
+
+    #[test]
+    fn should_validate_sub_device_count_from_device_info() {
+        let device_info = |sub_device_count| ResponseParameterData::GetDeviceInfo {
+            protocol_version: ProtocolVersion::new(1, 0),
+            model_id: 0x0001,
+            product_category: ProductCategory::Fixture,
+            software_version_id: 0x00000001,
+            footprint: 0,
+            current_personality: 1,
+            personality_count: 1,
+            start_address: 0xffff,
+            sub_device_count,
+            sensor_count: 0,
+        };
+
+        assert!(device_info(0).valid_sub_device_count());
+        assert!(device_info(SubDeviceId::MAX_SUB_DEVICES).valid_sub_device_count());
+        assert!(!device_info(0x0201).valid_sub_device_count());
+        assert!(!device_info(0xffff).valid_sub_device_count());
+        assert!(!ResponseParameterData::GetIdentifyDevice(true).valid_sub_device_count());
+    }
+
+    #[test]
+    fn should_decode_response_of_expected_kind() {
+        let decoded = ResponseParameterData::decode(
+            CommandClass::GetCommandResponse,
+            ParameterId::DnsDomainName,
+            b"example.com",
+        )
+        .unwrap();
+
+        assert_eq!(decoded.kind(), ResponseKind::GetDnsDomainName);
+        assert_eq!(decoded.as_str(), Some("example.com"));
+        assert_eq!(
+            ResponseKind::expected(CommandClass::GetCommandResponse, ParameterId::DnsDomainName),
+            Some(ResponseKind::GetDnsDomainName)
+        );
+        assert_eq!(
+            ResponseKind::expected(CommandClass::SetCommandResponse, ParameterId::DeviceLabel),
+            None
+        );
+    }
+
+    #[test]
+    fn should_expect_the_kind_of_every_decodable_response() {
+        for command_class in [
+            CommandClass::DiscoveryCommandResponse,
+            CommandClass::GetCommandResponse,
+            CommandClass::SetCommandResponse,
+        ] {
+            for parameter_id in (0..=u16::MAX).map(ParameterId::from) {
+                let expected = ResponseKind::expected(command_class, parameter_id);
+
+                // Unhandled pairs fall through to Unsupported whatever the data
+                if let Ok(ResponseParameterData::Unsupported(_)) =
+                    ResponseParameterData::decode(command_class, parameter_id, &[])
+                {
+                    assert_eq!(expected, None, "{command_class:?} {parameter_id:?}");
+                    continue;
+                }
+
+                let decoded = [0x01, 0x00]
+                    .into_iter()
+                    .flat_map(|fill| (0..=231).map(move |len| (fill, len)))
+                    .find_map(|(fill, len)| {
+                        ResponseParameterData::decode(
+                            command_class,
+                            parameter_id,
+                            &[fill; 231][..len],
+                        )
+                        .ok()
+                    });
+
+                match decoded {
+                    Some(decoded) => assert_eq!(
+                        expected,
+                        Some(decoded.kind()),
+                        "{command_class:?} {parameter_id:?}"
+                    ),
+                    None => panic!("{command_class:?} {parameter_id:?} never decodes"),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn should_round_trip_interface_label() {
+        let bytes = b"\x00\x00\x01\x02eth0";
+
+        let decoded = ResponseParameterData::decode(
+            CommandClass::GetCommandResponse,
+            ParameterId::InterfaceLabel,
+            bytes,
+        )
+        .unwrap();
+
+        let ResponseParameterData::GetInterfaceLabel {
+            interface_id,
+            interface_label,
+        } = &decoded
+        else {
+            panic!("expected interface label");
+        };
+
+        assert_eq!(*interface_id, 0x0102);
+        assert_eq!(interface_label.as_str(), "eth0");
+        assert_eq!(&decoded.encode()[..], bytes);
+
+        let decoded = ResponseParameterData::decode(
+            CommandClass::GetCommandResponse,
+            ParameterId::InterfaceLabel,
+            &[0x00, 0x00, 0x00, 0x01],
+        )
+        .unwrap();
+
+        assert!(matches!(
+            decoded,
+            ResponseParameterData::GetInterfaceLabel {
+                interface_id: 0x01,
+                ref interface_label,
+            } if interface_label.is_empty()
+        ));
+    }
+
+    #[test]
+    fn should_return_personality_from_description() {
+        let personality = ResponseParameterData::decode(
+            CommandClass::GetCommandResponse,
+            ParameterId::DmxPersonalityDescription,
+            b"\x02\x00\x04RGBW",
+        )
+        .unwrap()
+        .personality()
+        .unwrap();
+
+        assert_eq!(personality.id, 0x02);
+        assert_eq!(personality.dmx_slots_required, 4);
+        assert_eq!(personality.description.as_str(), "RGBW");
+        assert_eq!(
+            ResponseParameterData::GetDmxStartAddress(1).personality(),
+            None
+        );
     }
 
     #[test]
-    fn should_encode_valid_rdm_ack_manufacturer_specific_response() {
-        let encoded = RdmResponse::RdmFrame(RdmFrameResponse {
-            destination_uid: DeviceUID::new(0x0102, 0x03040506),
-            source_uid: DeviceUID::new(0x0605, 0x04030201),
-            transaction_number: 0x00,
-            response_type: ResponseType::Ack,
-            message_count: 0x00,
-            sub_device_id: SubDeviceId::RootDevice,
-            command_class: CommandClass::SetCommandResponse,
-            parameter_id: ParameterId::ManufacturerSpecific(0x8080),
-            #[cfg(feature = "alloc")]
-            parameter_data: ResponseData::ParameterData(Some(
-                ResponseParameterData::ManufacturerSpecific(vec![0x04, 0x03, 0x02, 0x01]),
-            )),
-            #[cfg(not(feature = "alloc"))]
-            parameter_data: ResponseData::ParameterData(Some(
-                ResponseParameterData::ManufacturerSpecific(
-                    Vec::<u8, 231>::from_slice(&[0x04, 0x03, 0x02, 0x01]).unwrap(),
-                ),
-            )),
-        })
-        .encode();
+    fn should_iterate_response_parameter_data_fields() {
+        let data = ResponseParameterData::decode(
+            CommandClass::GetCommandResponse,
+            ParameterId::DmxPersonalityDescription,
+            b"\x02\x00\x04RGBW",
+        )
+        .unwrap();
+
+        let mut fields = data.fields();
+
+        assert!(matches!(fields.next(), Some(("id", FieldValue::U8(0x02)))));
+        assert!(matches!(
+            fields.next(),
+            Some(("dmx_slots_required", FieldValue::U16(4)))
+        ));
+        assert!(matches!(
+            fields.next(),
+            Some(("description", FieldValue::Str("RGBW")))
+        ));
+        assert!(fields.next().is_none());
 
-        let expected = &[
-            0xcc, // Start Code
-            0x01, // Sub Start Code
-            28,   // Message Length
-            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
-            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
-            0x00, // Transaction Number
-            0x00, // Response Type = Ack
-            0x00, // Message Count
-            0x00, 0x00, // Sub-Device ID = Root Device
-            0x31, // Command Class = SetCommandResponse
-            0x80, 0x80, // Parameter ID = Identify Device
-            0x04, // PDL
-            0x04, 0x03, 0x02, 0x01, // Arbitrary manufacturer specific data
-            0x02, 0x52, // Checksum
-        ];
+        let data = ResponseParameterData::GetLampState(LampState::LampOn);
 
-        assert_eq!(encoded, expected);
+        let Some(("lamp_state", value)) = data.fields().next() else {
+            panic!("expected lamp_state field");
+        };
+
+        #[cfg(feature = "alloc")]
+        assert_eq!(value.to_string(), "LampOn");
+        #[cfg(not(feature = "alloc"))]
+        assert!(matches!(value, FieldValue::Other(_)));
     }
 
     #[test]
-    fn should_decode_valid_rdm_ack_timer_response() {
-        let decoded = RdmResponse::decode(&[
+    fn should_decode_set_dmx_start_address_ack_without_parameter_data() {
+        let bytes = [
             0xcc, // Start Code
             0x01, // Sub Start Code
-            26,   // Message Length
+            24,   // Message Length
             0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
             0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
             0x00, // Transaction Number
-            0x01, // Response Type = AckTimer
+            0x00, // Response Type = Ack
             0x00, // Message Count
             0x00, 0x00, // Sub-Device ID = Root Device
-            0x21, // Command Class = GetCommandResponse
-            0x10, 0x00, // Parameter ID = Identify Device
-            0x02, // PDL
-            0x00, 0x0a, // Estimated Response Time = 10x 100ms = 1 second
-            0x01, 0x4f, // Checksum
-        ]);
+            0x31, // Command Class = SetCommandResponse
+            0x00, 0xf0, // Parameter ID = DMX Start Address
+            0x00, // PDL
+            0x02, 0x30, // Checksum
+        ];
 
-        let expected = Ok(RdmResponse::RdmFrame(RdmFrameResponse {
+        let expected = RdmFrameResponse {
             destination_uid: DeviceUID::new(0x0102, 0x03040506),
             source_uid: DeviceUID::new(0x0605, 0x04030201),
             transaction_number: 0x00,
-            response_type: ResponseType::AckTimer,
+            response_type: ResponseType::Ack,
             message_count: 0x00,
             sub_device_id: SubDeviceId::RootDevice,
-            command_class: CommandClass::GetCommandResponse,
-            parameter_id: ParameterId::IdentifyDevice,
-            parameter_data: ResponseData::EstimateResponseTime(0x0a),
-        }));
+            command_class: CommandClass::SetCommandResponse,
+            parameter_id: ParameterId::DmxStartAddress,
+            parameter_data: ResponseData::ParameterData(None),
+        };
 
-        assert_eq!(decoded, expected);
+        assert_eq!(
+            RdmResponse::decode(&bytes),
+            Ok(RdmResponse::RdmFrame(expected.clone()))
+        );
+        assert_eq!(RdmFrameResponse::decode_strict(&bytes), Ok(expected));
     }
 
     #[test]
-    fn should_encode_valid_rdm_ack_timer_response() {
-        let encoded = RdmResponse::RdmFrame(RdmFrameResponse {
-            destination_uid: DeviceUID::new(0x0102, 0x03040506),
-            source_uid: DeviceUID::new(0x0605, 0x04030201),
-            transaction_number: 0x00,
-            response_type: ResponseType::AckTimer,
-            message_count: 0x00,
-            sub_device_id: SubDeviceId::RootDevice,
-            command_class: CommandClass::GetCommandResponse,
-            parameter_id: ParameterId::IdentifyDevice,
-            parameter_data: ResponseData::EstimateResponseTime(0x0a),
-        })
-        .encode();
+    fn should_decode_set_burn_in_response_without_echo() {
+        assert_eq!(
+            ResponseData::decode(
+                ResponseType::Ack,
+                CommandClass::SetCommandResponse,
+                0,
+                ParameterId::BurnIn,
+                &[],
+            ),
+            Ok(ResponseData::ParameterData(None))
+        );
 
-        let expected = &[
-            0xcc, // Start Code
-            0x01, // Sub Start Code
-            26,   // Message Length
-            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
-            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
-            0x00, // Transaction Number
-            0x01, // Response Type = AckTimer
-            0x00, // Message Count
-            0x00, 0x00, // Sub-Device ID = Root Device
-            0x21, // Command Class = GetCommandResponse
-            0x10, 0x00, // Parameter ID = Identify Device
-            0x02, // PDL
-            0x00, 0x0a, // Estimated Response Time = 10x 100ms = 1 second
-            0x01, 0x4f, // Checksum
+        let Ok(ResponseData::ParameterData(Some(ResponseParameterData::Unsupported(data)))) =
+            ResponseData::decode(
+                ResponseType::Ack,
+                CommandClass::SetCommandResponse,
+                1,
+                ParameterId::BurnIn,
+                &[0x10],
+            )
+        else {
+            panic!("expected echoed burn in hours to be unsupported");
+        };
+
+        assert_eq!(&data[..], &[0x10]);
+    }
+
+    #[test]
+    fn should_return_proxied_devices() {
+        let devices = [
+            DeviceUID::new(0x0102, 0x03040506),
+            DeviceUID::new_dynamic(0x0102, 0x03040507),
         ];
 
-        assert_eq!(encoded, expected);
+        assert_eq!(
+            ResponseParameterData::GetProxiedDevices(devices.into_iter().collect())
+                .proxied_devices(),
+            Some(&devices[..])
+        );
+        assert_eq!(
+            ResponseParameterData::GetProxiedDeviceCount {
+                device_count: 2,
+                list_change: false,
+            }
+            .proxied_devices(),
+            None
+        );
     }
 
     #[test]
-    fn should_decode_valid_rdm_nack_reason_response() {
-        let decoded = RdmResponse::decode(&[
-            0xcc, // Start Code
-            0x01, // Sub Start Code
-            26,   // Message Length
-            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
-            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
-            0x00, // Transaction Number
-            0x02, // Response Type = Nack_Reason
-            0x00, // Message Count
-            0x00, 0x00, // Sub-Device ID = Root Device
-            0x21, // Command Class = GetCommandResponse
-            0x10, 0x00, // Parameter ID = Identify Device
-            0x02, // PDL
-            0x00, 0x01, // Nack Reason = FormatError
-            0x01, 0x47, // Checksum
-        ]);
+    fn should_return_label_as_str() {
+        assert_eq!(
+            ResponseParameterData::decode(
+                CommandClass::GetCommandResponse,
+                ParameterId::DeviceLabel,
+                b"Stage Left\0"
+            )
+            .unwrap()
+            .as_str(),
+            Some("Stage Left")
+        );
+        assert_eq!(
+            ResponseParameterData::decode(
+                CommandClass::GetCommandResponse,
+                ParameterId::DmxPersonalityDescription,
+                b"\x01\x00\x04RGBW"
+            )
+            .unwrap()
+            .as_str(),
+            Some("RGBW")
+        );
+        assert_eq!(
+            ResponseParameterData::GetIdentifyDevice(true).as_str(),
+            None
+        );
+    }
 
-        let expected = Ok(RdmResponse::RdmFrame(RdmFrameResponse {
-            destination_uid: DeviceUID::new(0x0102, 0x03040506),
+    #[test]
+    fn should_check_response_destination() {
+        let controller_uid = DeviceUID::new(0x0102, 0x03040506);
+
+        let mut response = RdmFrameResponse {
+            destination_uid: controller_uid,
             source_uid: DeviceUID::new(0x0605, 0x04030201),
             transaction_number: 0x00,
-            response_type: ResponseType::NackReason,
+            response_type: ResponseType::Ack,
             message_count: 0x00,
             sub_device_id: SubDeviceId::RootDevice,
             command_class: CommandClass::GetCommandResponse,
             parameter_id: ParameterId::IdentifyDevice,
-            parameter_data: ResponseData::NackReason(ResponseNackReasonCode::FormatError),
-        }));
+            parameter_data: ResponseData::ParameterData(Some(
+                ResponseParameterData::GetIdentifyDevice(true),
+            )),
+        };
 
-        assert_eq!(decoded, expected);
+        assert!(response.is_for(controller_uid));
+        assert!(!response.is_for(DeviceUID::new(0x0102, 0x03040507)));
+
+        response.destination_uid = DeviceUID::broadcast_to_devices_with_manufacturer_id(0x0102);
+
+        assert!(response.is_for(controller_uid));
+        assert!(!response.is_for(DeviceUID::new(0x0103, 0x03040506)));
+
+        response.destination_uid = DeviceUID::broadcast_to_all_devices();
+
+        assert!(response.is_for(DeviceUID::new(0x0103, 0x03040506)));
     }
 
     #[test]
-    fn should_encode_valid_rdm_nack_reason_response() {
-        let encoded = RdmResponse::RdmFrame(RdmFrameResponse {
+    fn should_classify_rdm_frame_response_type() {
+        let mut response = RdmFrameResponse {
             destination_uid: DeviceUID::new(0x0102, 0x03040506),
             source_uid: DeviceUID::new(0x0605, 0x04030201),
             transaction_number: 0x00,
@@ -3639,27 +6872,38 @@ mod tests {
             command_class: CommandClass::GetCommandResponse,
             parameter_id: ParameterId::IdentifyDevice,
             parameter_data: ResponseData::NackReason(ResponseNackReasonCode::FormatError),
-        })
-        .encode();
+        };
 
-        let expected = &[
-            0xcc, // Start Code
-            0x01, // Sub Start Code
-            26,   // Message Length
-            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Destination UID
-            0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // Source UID
-            0x00, // Transaction Number
-            0x02, // Response Type = Nack_Reason
-            0x00, // Message Count
-            0x00, 0x00, // Sub-Device ID = Root Device
-            0x21, // Command Class = GetCommandResponse
-            0x10, 0x00, // Parameter ID = Identify Device
-            0x02, // PDL
-            0x00, 0x01, // Nack Reason = FormatError
-            0x01, 0x47, // Checksum
-        ];
+        assert!(response.is_nack());
+        assert!(!response.is_ack());
+        assert_eq!(
+            response.nack_reason(),
+            Some(ResponseNackReasonCode::FormatError)
+        );
+        assert_eq!(response.ack_timer_estimate(), None);
 
-        assert_eq!(encoded, expected);
+        response.response_type = ResponseType::AckTimer;
+        response.parameter_data = ResponseData::EstimateResponseTime(0x0a);
+
+        assert!(response.is_ack_timer());
+        assert!(!response.is_nack());
+        assert_eq!(response.nack_reason(), None);
+        assert_eq!(response.ack_timer_estimate(), Some(0x0a));
+
+        response.response_type = ResponseType::Ack;
+        response.parameter_data =
+            ResponseData::ParameterData(Some(ResponseParameterData::GetIdentifyDevice(true)));
+
+        assert!(response.is_ack());
+        assert!(!response.is_ack_overflow());
+        assert!(!response.needs_resend());
+        assert_eq!(response.nack_reason(), None);
+        assert_eq!(response.ack_timer_estimate(), None);
+
+        response.response_type = ResponseType::AckOverflow;
+
+        assert!(response.is_ack_overflow());
+        assert!(response.needs_resend());
     }
 
     #[test]
@@ -3799,6 +7043,90 @@ mod tests {
         assert_eq!(decoded, expected);
     }
 
+    #[test]
+    fn should_detect_discovery_unique_branch_collision() {
+        let mut bytes =
+            DiscoveryUniqueBranchFrameResponse(DeviceUID::new(0x0102, 0x03040506)).encode();
+
+        // a bit flip in a data bit keeps the encoding intact
+        bytes[22] = 0xae;
+
+        assert_eq!(
+            DiscoveryUniqueBranchFrameResponse::decode_with_collision_threshold(&bytes, 0),
+            Err(RdmError::DiscoveryCollision)
+        );
+
+        // overlapping responders corrupt the masked bits
+        bytes[8] = 0x0b;
+
+        assert_eq!(
+            DiscoveryUniqueBranchFrameResponse::decode(&bytes),
+            Err(RdmError::DiscoveryCollision)
+        );
+        assert!(matches!(
+            DiscoveryUniqueBranchFrameResponse::decode_with_collision_threshold(&bytes, 2),
+            Err(RdmError::InvalidChecksum(..))
+        ));
+    }
+
+    #[test]
+    fn should_report_invalid_checksum_of_single_discovery_unique_branch_response() {
+        let mut bytes =
+            DiscoveryUniqueBranchFrameResponse(DeviceUID::new(0x0102, 0x03040506)).encode();
+
+        bytes[22] = 0xae; // ecs 1 = Checksum0 (LSB), data bit flipped
+
+        assert!(matches!(
+            DiscoveryUniqueBranchFrameResponse::decode(&bytes),
+            Err(RdmError::InvalidChecksum(..))
+        ));
+        assert!(matches!(
+            RdmResponse::decode(&bytes),
+            Err(RdmError::InvalidChecksum(..))
+        ));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_decode_all_concatenated_discovery_unique_branch_responses() {
+        let first = DeviceUID::new(0x0102, 0x03040506);
+        let second = DeviceUID::new(0x0000, 0x00000000);
+
+        let mut bytes = DiscoveryUniqueBranchFrameResponse(first).encode();
+        bytes.extend(DiscoveryUniqueBranchFrameResponse(second).encode());
+
+        let mut collided = DiscoveryUniqueBranchFrameResponse(first).encode();
+        collided[8] = 0x0b;
+        bytes.extend(collided);
+
+        bytes.extend([DISCOVERY_UNIQUE_BRANCH_PREAMBLE_SEPARATOR_BYTE, 0xab, 0x55]);
+
+        assert_eq!(
+            DiscoveryUniqueBranchFrameResponse::decode_all(&bytes),
+            vec![
+                Ok(DiscoveryUniqueBranchFrameResponse(first)),
+                Ok(DiscoveryUniqueBranchFrameResponse(second)),
+                Err(RdmError::DiscoveryCollision),
+                Err(RdmError::InvalidFrameLength(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_not_decode_truncated_discovery_unique_branch_response() {
+        let encoded =
+            DiscoveryUniqueBranchFrameResponse(DeviceUID::new(0x0102, 0x03040506)).encode();
+
+        assert_eq!(
+            DiscoveryUniqueBranchFrameResponse::decode(&encoded[..23]),
+            Err(RdmError::InvalidFrameLength(23))
+        );
+        assert_eq!(
+            DiscoveryUniqueBranchFrameResponse::decode(&encoded[7..8]),
+            Err(RdmError::InvalidFrameLength(1))
+        );
+    }
+
     #[test]
     fn should_encode_valid_discovery_unique_branch_response() {
         let encoded = RdmResponse::DiscoveryUniqueBranchFrame(DiscoveryUniqueBranchFrameResponse(
@@ -3835,4 +7163,88 @@ mod tests {
 
         assert_eq!(encoded, expected);
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_summarise_rdm_response() {
+        let response = RdmResponse::RdmFrame(RdmFrameResponse {
+            destination_uid: DeviceUID::new(0x0102, 0x03040506),
+            source_uid: DeviceUID::new(0x0605, 0x04030201),
+            transaction_number: 0x00,
+            response_type: ResponseType::Ack,
+            message_count: 0x00,
+            sub_device_id: SubDeviceId::RootDevice,
+            command_class: CommandClass::GetCommandResponse,
+            parameter_id: ParameterId::IdentifyDevice,
+            parameter_data: ResponseData::ParameterData(Some(
+                ResponseParameterData::GetIdentifyDevice(true),
+            )),
+        });
+
+        assert_eq!(
+            response.summary(),
+            "GET_COMMAND_RESPONSE IDENTIFY_DEVICE 0605:04030201 -> 0102:03040506 ACK true"
+        );
+
+        let response = RdmResponse::RdmFrame(RdmFrameResponse {
+            destination_uid: DeviceUID::new(0x0102, 0x03040506),
+            source_uid: DeviceUID::new(0x0605, 0x04030201),
+            transaction_number: 0x00,
+            response_type: ResponseType::Ack,
+            message_count: 0x00,
+            sub_device_id: SubDeviceId::RootDevice,
+            command_class: CommandClass::GetCommandResponse,
+            parameter_id: ParameterId::DeviceLabel,
+            parameter_data: ResponseData::ParameterData(Some(
+                ResponseParameterData::GetDeviceLabel("Stage Left".into()),
+            )),
+        });
+
+        assert_eq!(
+            response.summary(),
+            "GET_COMMAND_RESPONSE DEVICE_LABEL 0605:04030201 -> 0102:03040506 ACK Stage Left"
+        );
+
+        let response = RdmResponse::RdmFrame(RdmFrameResponse {
+            destination_uid: DeviceUID::new(0x0102, 0x03040506),
+            source_uid: DeviceUID::new(0x0605, 0x04030201),
+            transaction_number: 0x00,
+            response_type: ResponseType::Ack,
+            message_count: 0x00,
+            sub_device_id: SubDeviceId::RootDevice,
+            command_class: CommandClass::GetCommandResponse,
+            parameter_id: ParameterId::SupportedParameters,
+            parameter_data: ResponseData::ParameterData(Some(
+                ResponseParameterData::GetSupportedParameters(vec![0x0200, 0x0201]),
+            )),
+        });
+
+        assert_eq!(
+            response.summary(),
+            "GET_COMMAND_RESPONSE SUPPORTED_PARAMETERS 0605:04030201 -> 0102:03040506 ACK"
+        );
+
+        let response = RdmResponse::RdmFrame(RdmFrameResponse {
+            destination_uid: DeviceUID::new(0x0102, 0x03040506),
+            source_uid: DeviceUID::new(0x0605, 0x04030201),
+            transaction_number: 0x00,
+            response_type: ResponseType::NackReason,
+            message_count: 0x00,
+            sub_device_id: SubDeviceId::RootDevice,
+            command_class: CommandClass::SetCommandResponse,
+            parameter_id: ParameterId::DeviceLabel,
+            parameter_data: ResponseData::NackReason(ResponseNackReasonCode::WriteProtect),
+        });
+
+        assert_eq!(
+            response.summary(),
+            "SET_COMMAND_RESPONSE DEVICE_LABEL 0605:04030201 -> 0102:03040506 NACK_REASON Command normally allowed but being blocked currently."
+        );
+
+        let response = RdmResponse::DiscoveryUniqueBranchFrame(DiscoveryUniqueBranchFrameResponse(
+            DeviceUID::new(0x0102, 0x03040506),
+        ));
+
+        assert_eq!(response.summary(), "DISC_UNIQUE_BRANCH 0102:03040506");
+    }
 }
@@ -0,0 +1,122 @@
+//! `proptest` strategies for property testing RDM decoders
+//!
+//! Generated frames have a valid start code, sub start code, message length and checksum, with
+//! arbitrary addressing, command class, parameter id and parameter data, so decoders can be
+//! checked for panics and round trips past the framing checks.
+//!
+//! ```rust
+//! use dmx512_rdm_protocol::rdm::{decode_frame, strategy::rdm_frame};
+//! use proptest::{prop_assert, proptest};
+//!
+//! proptest!(|(bytes in rdm_frame())| {
+//!     prop_assert!(bytes.len() >= 26);
+//!
+//!     let _ = decode_frame(&bytes);
+//! });
+//! ```
+
+use super::{
+    bsd_16_crc, CommandClass, MAX_RDM_PARAMETER_DATA_LENGTH, RDM_START_CODE_BYTE,
+    RDM_SUB_START_CODE_BYTE,
+};
+use proptest::{collection, prelude::*, sample::select};
+
+/// Valid command classes, encoded as their byte value
+pub fn command_class() -> impl Strategy<Value = u8> {
+    select(vec![
+        CommandClass::DiscoveryCommand as u8,
+        CommandClass::DiscoveryCommandResponse as u8,
+        CommandClass::GetCommand as u8,
+        CommandClass::GetCommandResponse as u8,
+        CommandClass::SetCommand as u8,
+        CommandClass::SetCommandResponse as u8,
+    ])
+}
+
+/// Parameter ids, weighted towards the range defined by E1.20 and E1.37
+pub fn parameter_id() -> impl Strategy<Value = u16> {
+    prop_oneof![
+        3 => 0x0000u16..=0x0fff,
+        1 => any::<u16>(),
+    ]
+}
+
+/// Encoded RDM frames with a valid message length and checksum, and arbitrary parameter data
+pub fn rdm_frame() -> impl Strategy<Value = Vec<u8>> {
+    rdm_frame_with(collection::vec(
+        any::<u8>(),
+        0..=MAX_RDM_PARAMETER_DATA_LENGTH,
+    ))
+}
+
+/// Encoded RDM frames with a valid message length and checksum, wrapping parameter data from
+/// the given strategy. Parameter data longer than `MAX_RDM_PARAMETER_DATA_LENGTH` is truncated.
+pub fn rdm_frame_with(
+    parameter_data: impl Strategy<Value = Vec<u8>>,
+) -> impl Strategy<Value = Vec<u8>> {
+    (
+        any::<[u8; 12]>(),
+        any::<[u8; 3]>(),
+        any::<u16>(),
+        command_class(),
+        parameter_id(),
+        parameter_data,
+    )
+        .prop_map(
+            |(uids, header, sub_device_id, command_class, parameter_id, mut parameter_data)| {
+                parameter_data.truncate(MAX_RDM_PARAMETER_DATA_LENGTH);
+
+                let mut frame = Vec::with_capacity(parameter_data.len() + 26);
+
+                frame.push(RDM_START_CODE_BYTE);
+                frame.push(RDM_SUB_START_CODE_BYTE);
+                frame.push(parameter_data.len() as u8 + 24);
+                frame.extend(uids);
+                frame.extend(header);
+                frame.extend(sub_device_id.to_be_bytes());
+                frame.push(command_class);
+                frame.extend(parameter_id.to_be_bytes());
+                frame.push(parameter_data.len() as u8);
+                frame.extend(parameter_data);
+
+                let checksum = bsd_16_crc(&frame);
+
+                frame.extend(checksum.to_be_bytes());
+
+                frame
+            },
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rdm::{decode_frame, RdmFrame};
+
+    fn encode_frame(frame: RdmFrame) -> Vec<u8> {
+        match frame {
+            RdmFrame::Request(request) => request.encode(),
+            RdmFrame::Response(response) => response.encode(),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn should_decode_generated_frames_without_panicking(bytes in rdm_frame()) {
+            let _ = decode_frame(&bytes);
+        }
+
+        // Strings are NUL terminated and decoded lossily, so only frames produced by `encode`
+        // are expected to survive a round trip byte for byte
+        #[test]
+        fn should_round_trip_encoded_frames(
+            bytes in rdm_frame_with(collection::vec(0x00u8..0x80, 0..=MAX_RDM_PARAMETER_DATA_LENGTH))
+        ) {
+            if let Ok(frame) = decode_frame(&bytes) {
+                let encoded = encode_frame(frame);
+
+                prop_assert_eq!(decode_frame(&encoded).map(encode_frame), Ok(encoded));
+            }
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Correlation of outgoing RDM requests with the responses they produce
+//!
+//! Time is measured in caller-defined ticks (e.g. milliseconds from a monotonic clock), so the
+//! tracker can be driven from both std and no_std environments.
+//!
+//! ```rust
+//! use dmx512_rdm_protocol::rdm::{
+//!     request::{RdmRequest, RequestParameter},
+//!     transaction::TransactionTracker,
+//!     DeviceUID, SubDeviceId,
+//! };
+//!
+//! let mut tracker = TransactionTracker::new(20);
+//!
+//! let request = RdmRequest::new(
+//!     DeviceUID::new(0x0102, 0x03040506),
+//!     DeviceUID::new(0x0605, 0x04030201),
+//!     0x00,
+//!     0x01,
+//!     SubDeviceId::RootDevice,
+//!     RequestParameter::GetIdentifyDevice,
+//! );
+//!
+//! tracker.record(&request, 0).unwrap();
+//!
+//! assert_eq!(tracker.len(), 1);
+//! ```
+
+use super::{
+    error::RdmError, parameter::ParameterId, request::RdmRequest, response::RdmFrameResponse,
+    DeviceUID,
+};
+
+#[cfg(not(feature = "alloc"))]
+use heapless::Vec;
+
+#[cfg(not(feature = "alloc"))]
+pub const MAX_OUTSTANDING_TRANSACTIONS: usize = 16;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OutstandingTransaction {
+    pub destination_uid: DeviceUID,
+    pub transaction_number: u8,
+    pub parameter_id: ParameterId,
+    pub sent_at: u64,
+}
+
+impl OutstandingTransaction {
+    fn is_expired(&self, now: u64, timeout: u64) -> bool {
+        now.saturating_sub(self.sent_at) > timeout
+    }
+
+    fn matches(&self, response: &RdmFrameResponse) -> bool {
+        self.destination_uid == response.source_uid
+            && self.transaction_number == response.transaction_number
+            && self.parameter_id == response.parameter_id
+    }
+}
+
+/// Tracks requests awaiting a response, keyed by destination uid and transaction number
+#[derive(Clone, Debug)]
+pub struct TransactionTracker {
+    timeout: u64,
+    #[cfg(feature = "alloc")]
+    transactions: Vec<OutstandingTransaction>,
+    #[cfg(not(feature = "alloc"))]
+    transactions: Vec<OutstandingTransaction, MAX_OUTSTANDING_TRANSACTIONS>,
+}
+
+impl TransactionTracker {
+    pub fn new(timeout: u64) -> Self {
+        Self {
+            timeout,
+            transactions: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+
+    /// Records an outgoing request, replacing any outstanding transaction with the same
+    /// destination uid and transaction number
+    pub fn record(&mut self, request: &RdmRequest, now: u64) -> Result<(), RdmError> {
+        let transaction = OutstandingTransaction {
+            destination_uid: request.destination_uid,
+            transaction_number: request.transaction_number,
+            parameter_id: request.parameter_id(),
+            sent_at: now,
+        };
+
+        if let Some(entry) = self.transactions.iter_mut().find(|entry| {
+            entry.destination_uid == transaction.destination_uid
+                && entry.transaction_number == transaction.transaction_number
+        }) {
+            *entry = transaction;
+
+            return Ok(());
+        }
+
+        #[cfg(feature = "alloc")]
+        self.transactions.push(transaction);
+        #[cfg(not(feature = "alloc"))]
+        self.transactions
+            .push(transaction)
+            .map_err(|_| RdmError::TransactionTrackerFull)?;
+
+        Ok(())
+    }
+
+    /// Removes and returns the outstanding transaction that produced `response`.
+    ///
+    /// Returns `None` when no transaction matches, or when the matching transaction has timed out.
+    pub fn resolve(
+        &mut self,
+        response: &RdmFrameResponse,
+        now: u64,
+    ) -> Option<OutstandingTransaction> {
+        let index = self
+            .transactions
+            .iter()
+            .position(|entry| entry.matches(response))?;
+
+        let transaction = self.transactions.swap_remove(index);
+
+        if transaction.is_expired(now, self.timeout) {
+            return None;
+        }
+
+        Some(transaction)
+    }
+
+    /// Removes all transactions that have timed out, returning how many were removed
+    pub fn expire(&mut self, now: u64) -> usize {
+        let timeout = self.timeout;
+        let len = self.transactions.len();
+
+        self.transactions
+            .retain(|entry| !entry.is_expired(now, timeout));
+
+        len - self.transactions.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rdm::{
+        request::RequestParameter,
+        response::{ResponseData, ResponseParameterData, ResponseType},
+        CommandClass, SubDeviceId,
+    };
+
+    fn request(destination_uid: DeviceUID, transaction_number: u8) -> RdmRequest {
+        RdmRequest::new(
+            destination_uid,
+            DeviceUID::new(0x0605, 0x04030201),
+            transaction_number,
+            0x01,
+            SubDeviceId::RootDevice,
+            RequestParameter::GetIdentifyDevice,
+        )
+    }
+
+    fn response(source_uid: DeviceUID, transaction_number: u8) -> RdmFrameResponse {
+        RdmFrameResponse {
+            destination_uid: DeviceUID::new(0x0605, 0x04030201),
+            source_uid,
+            transaction_number,
+            response_type: ResponseType::Ack,
+            message_count: 0x00,
+            sub_device_id: SubDeviceId::RootDevice,
+            command_class: CommandClass::GetCommandResponse,
+            parameter_id: ParameterId::IdentifyDevice,
+            parameter_data: ResponseData::ParameterData(Some(
+                ResponseParameterData::GetIdentifyDevice(true),
+            )),
+        }
+    }
+
+    #[test]
+    fn should_resolve_response_to_outstanding_request() {
+        let first = DeviceUID::new(0x0102, 0x03040506);
+        let second = DeviceUID::new(0x0102, 0x03040507);
+
+        let mut tracker = TransactionTracker::new(20);
+
+        tracker.record(&request(first, 0x00), 0).unwrap();
+        tracker.record(&request(second, 0x01), 5).unwrap();
+
+        assert_eq!(tracker.resolve(&response(first, 0x01), 10), None);
+        assert_eq!(
+            tracker.resolve(&response(second, 0x01), 10),
+            Some(OutstandingTransaction {
+                destination_uid: second,
+                transaction_number: 0x01,
+                parameter_id: ParameterId::IdentifyDevice,
+                sent_at: 5,
+            })
+        );
+        assert_eq!(tracker.resolve(&response(second, 0x01), 10), None);
+        assert_eq!(tracker.len(), 1);
+    }
+
+    #[test]
+    fn should_not_resolve_timed_out_transaction() {
+        let uid = DeviceUID::new(0x0102, 0x03040506);
+
+        let mut tracker = TransactionTracker::new(20);
+
+        tracker.record(&request(uid, 0x00), 0).unwrap();
+
+        assert_eq!(tracker.resolve(&response(uid, 0x00), 21), None);
+        assert!(tracker.is_empty());
+    }
+
+    #[test]
+    fn should_expire_timed_out_transactions() {
+        let mut tracker = TransactionTracker::new(20);
+
+        tracker
+            .record(&request(DeviceUID::new(0x0102, 0x03040506), 0x00), 0)
+            .unwrap();
+        tracker
+            .record(&request(DeviceUID::new(0x0102, 0x03040507), 0x01), 10)
+            .unwrap();
+
+        assert_eq!(tracker.expire(25), 1);
+        assert_eq!(tracker.len(), 1);
+    }
+}
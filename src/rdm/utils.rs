@@ -7,3 +7,21 @@ macro_rules! check_msg_len {
         }
     };
 }
+
+#[macro_export]
+macro_rules! check_whole_entries {
+    ($msg:expr, $entry_len:literal) => {
+        if $msg.len() % $entry_len != 0 {
+            return Err(RdmError::InvalidParameterDataLength($msg.len() as u8));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! check_max_entries {
+    ($msg:expr, $entry_len:literal, $max_entries:literal) => {
+        if $msg.len().div_ceil($entry_len) > $max_entries {
+            return Err(RdmError::InvalidParameterDataLength($msg.len() as u8));
+        }
+    };
+}